@@ -18,14 +18,23 @@ use criterion::{Criterion, Throughput, criterion_group, criterion_main};
 use criterion_cycles_per_byte::CyclesPerByte;
 use rand_core::{RngCore, SeedableRng};
 
-use rand_hc::Hc128Rng;
+use rand_aes::AesCtrRng;
+use rand_hc::{Hc128Rng, RabbitRng, Salsa12Rng, SosemanukRng};
 use rand_isaac::{Isaac64Rng, IsaacRng};
-use rand_sfc::{Sfc32, Sfc64};
-use rand_xorshift::XorShiftRng;
+use rand_mersenne::{Dsfmt19937, Mt19937, Mt19937_64, Well512a, Well1024a};
+use rand_pcg::{Pcg32, Pcg64, Pcg64Dxsm, Pcg64Mcg};
+use rand_philox::{Philox4x32Rng, Philox4x64Rng};
+use rand_ranlux::{Ranlux24, Ranlux48, Ranluxpp, RanluxppRoot};
+use rand_romu::{RomuDuo, RomuDuoJr, RomuQuad, RomuQuad32, RomuTrio, RomuTrio32};
+use rand_sfc::{Gjrand32, Gjrand64, Jsf32, Jsf32Rot3, Jsf64, Sfc32, Sfc64, Tyche, TycheI};
+use rand_squares::{Squares32Rng, Squares64Rng};
+use rand_threefry::{Threefry2x64Rng, Threefry4x64Rng};
+use rand_xorshift::{Kiss64, Kiss99, RanArray, XorShiftRng};
 use rand_xoshiro::{
-    SplitMix64, Xoroshiro64Star, Xoroshiro64StarStar, Xoroshiro128Plus, Xoroshiro128StarStar,
-    Xoshiro128Plus, Xoshiro128PlusPlus, Xoshiro128StarStar, Xoshiro256Plus, Xoshiro256PlusPlus,
-    Xoshiro256StarStar,
+    L64X128Mix, L64X256Mix, L128X256Mix, Lcg128, Mcg128, Mwc128, Mwc192, Mwc256, SplitMix32,
+    SplitMix64, SplittableMix64, WyRand, Xoroshiro64Star, Xoroshiro64StarStar, Xoroshiro128Plus,
+    Xoroshiro128StarStar, Xoshiro128Plus, Xoshiro128PlusPlus, Xoshiro128StarStar, Xoshiro256Plus,
+    Xoshiro256PlusPlus, Xoshiro256StarStar,
 };
 
 const RAND_BENCH_N: u64 = 1000;
@@ -82,11 +91,61 @@ fn gen_bytes(c: &mut Criterion<CyclesPerByte>) {
     );
     gen_bytes!("xoroshiro64star", Xoroshiro64Star::from_rng(&mut master));
     gen_bytes!("splitmix64", SplitMix64::from_rng(&mut master));
+    gen_bytes!("wyrand", WyRand::from_rng(&mut master));
+    gen_bytes!("mcg128", Mcg128::from_rng(&mut master));
+    gen_bytes!("lcg128", Lcg128::from_rng(&mut master));
+    gen_bytes!("aes_ctr", AesCtrRng::from_rng(&mut master));
     gen_bytes!("hc128", Hc128Rng::from_rng(&mut master));
     gen_bytes!("isaac", IsaacRng::from_rng(&mut master));
     gen_bytes!("isaac64", Isaac64Rng::from_rng(&mut master));
     gen_bytes!("sfc32", Sfc32::from_rng(&mut master));
     gen_bytes!("sfc64", Sfc64::from_rng(&mut master));
+    gen_bytes!("philox4x32", Philox4x32Rng::from_rng(&mut master));
+    gen_bytes!("philox4x64", Philox4x64Rng::from_rng(&mut master));
+    gen_bytes!("threefry2x64", Threefry2x64Rng::<20>::from_rng(&mut master));
+    gen_bytes!("threefry4x64", Threefry4x64Rng::<20>::from_rng(&mut master));
+    gen_bytes!("pcg32", Pcg32::from_rng(&mut master));
+    gen_bytes!("pcg64", Pcg64::from_rng(&mut master));
+    gen_bytes!("pcg64dxsm", Pcg64Dxsm::from_rng(&mut master));
+    gen_bytes!("pcg64mcg", Pcg64Mcg::from_rng(&mut master));
+    gen_bytes!("mt19937", Mt19937::from_rng(&mut master));
+    gen_bytes!("mt19937_64", Mt19937_64::from_rng(&mut master));
+    gen_bytes!("dsfmt19937", Dsfmt19937::from_rng(&mut master));
+    gen_bytes!("well512a", Well512a::from_rng(&mut master));
+    gen_bytes!("well1024a", Well1024a::from_rng(&mut master));
+    gen_bytes!("mwc128", Mwc128::from_rng(&mut master));
+    gen_bytes!("mwc192", Mwc192::from_rng(&mut master));
+    gen_bytes!("mwc256", Mwc256::from_rng(&mut master));
+    gen_bytes!("l64x128mix", L64X128Mix::from_rng(&mut master));
+    gen_bytes!("l64x256mix", L64X256Mix::from_rng(&mut master));
+    gen_bytes!("l128x256mix", L128X256Mix::from_rng(&mut master));
+    gen_bytes!("romuquad", RomuQuad::from_rng(&mut master));
+    gen_bytes!("romutrio", RomuTrio::from_rng(&mut master));
+    gen_bytes!("romuduo", RomuDuo::from_rng(&mut master));
+    gen_bytes!("romuduojr", RomuDuoJr::from_rng(&mut master));
+    gen_bytes!("romuquad32", RomuQuad32::from_rng(&mut master));
+    gen_bytes!("romutrio32", RomuTrio32::from_rng(&mut master));
+    gen_bytes!("jsf32", Jsf32::from_rng(&mut master));
+    gen_bytes!("jsf32rot3", Jsf32Rot3::from_rng(&mut master));
+    gen_bytes!("jsf64", Jsf64::from_rng(&mut master));
+    gen_bytes!("gjrand32", Gjrand32::from_rng(&mut master));
+    gen_bytes!("gjrand64", Gjrand64::from_rng(&mut master));
+    gen_bytes!("tyche", Tyche::from_rng(&mut master));
+    gen_bytes!("tychei", TycheI::from_rng(&mut master));
+    gen_bytes!("squares32", Squares32Rng::from_rng(&mut master));
+    gen_bytes!("squares64", Squares64Rng::from_rng(&mut master));
+    gen_bytes!("splitmix32", SplitMix32::from_rng(&mut master));
+    gen_bytes!("splittablemix64", SplittableMix64::from_rng(&mut master));
+    gen_bytes!("rabbit", RabbitRng::from_rng(&mut master));
+    gen_bytes!("salsa12", Salsa12Rng::from_rng(&mut master));
+    gen_bytes!("sosemanuk", SosemanukRng::from_rng(&mut master));
+    gen_bytes!("kiss99", Kiss99::from_rng(&mut master));
+    gen_bytes!("kiss64", Kiss64::from_rng(&mut master));
+    gen_bytes!("ran_array", RanArray::from_rng(&mut master));
+    gen_bytes!("ranlux24", Ranlux24::from_rng(&mut master));
+    gen_bytes!("ranlux48", Ranlux48::from_rng(&mut master));
+    gen_bytes!("ranluxpp", Ranluxpp::from_rng(&mut master));
+    gen_bytes!("ranluxpp_root", RanluxppRoot::from_rng(&mut master));
 }
 
 // Save a dependency on Rand:
@@ -192,11 +251,76 @@ fn gen_uint(c: &mut Criterion<CyclesPerByte>) {
             Xoroshiro64Star::from_rng(&mut master)
         );
         gen_uint!(g, "splitmix64", u32, SplitMix64::from_rng(&mut master));
+        gen_uint!(g, "wyrand", u32, WyRand::from_rng(&mut master));
+        gen_uint!(g, "mcg128", u32, Mcg128::from_rng(&mut master));
+        gen_uint!(g, "lcg128", u32, Lcg128::from_rng(&mut master));
+        gen_uint!(g, "aes_ctr", u32, AesCtrRng::from_rng(&mut master));
         gen_uint!(g, "hc128", u32, Hc128Rng::from_rng(&mut master));
         gen_uint!(g, "isaac", u32, IsaacRng::from_rng(&mut master));
         gen_uint!(g, "isaac64", u32, Isaac64Rng::from_rng(&mut master));
         gen_uint!(g, "sfc32", u32, Sfc32::from_rng(&mut master));
         gen_uint!(g, "sfc64", u32, Sfc64::from_rng(&mut master));
+        gen_uint!(g, "philox4x32", u32, Philox4x32Rng::from_rng(&mut master));
+        gen_uint!(g, "philox4x64", u32, Philox4x64Rng::from_rng(&mut master));
+        gen_uint!(
+            g,
+            "threefry2x64",
+            u32,
+            Threefry2x64Rng::<20>::from_rng(&mut master)
+        );
+        gen_uint!(
+            g,
+            "threefry4x64",
+            u32,
+            Threefry4x64Rng::<20>::from_rng(&mut master)
+        );
+        gen_uint!(g, "pcg32", u32, Pcg32::from_rng(&mut master));
+        gen_uint!(g, "pcg64", u32, Pcg64::from_rng(&mut master));
+        gen_uint!(g, "pcg64dxsm", u32, Pcg64Dxsm::from_rng(&mut master));
+        gen_uint!(g, "pcg64mcg", u32, Pcg64Mcg::from_rng(&mut master));
+        gen_uint!(g, "mt19937", u32, Mt19937::from_rng(&mut master));
+        gen_uint!(g, "mt19937_64", u32, Mt19937_64::from_rng(&mut master));
+        gen_uint!(g, "dsfmt19937", u32, Dsfmt19937::from_rng(&mut master));
+        gen_uint!(g, "well512a", u32, Well512a::from_rng(&mut master));
+        gen_uint!(g, "well1024a", u32, Well1024a::from_rng(&mut master));
+        gen_uint!(g, "mwc128", u32, Mwc128::from_rng(&mut master));
+        gen_uint!(g, "mwc192", u32, Mwc192::from_rng(&mut master));
+        gen_uint!(g, "mwc256", u32, Mwc256::from_rng(&mut master));
+        gen_uint!(g, "l64x128mix", u32, L64X128Mix::from_rng(&mut master));
+        gen_uint!(g, "l64x256mix", u32, L64X256Mix::from_rng(&mut master));
+        gen_uint!(g, "l128x256mix", u32, L128X256Mix::from_rng(&mut master));
+        gen_uint!(g, "romuquad", u32, RomuQuad::from_rng(&mut master));
+        gen_uint!(g, "romutrio", u32, RomuTrio::from_rng(&mut master));
+        gen_uint!(g, "romuduo", u32, RomuDuo::from_rng(&mut master));
+        gen_uint!(g, "romuduojr", u32, RomuDuoJr::from_rng(&mut master));
+        gen_uint!(g, "romuquad32", u32, RomuQuad32::from_rng(&mut master));
+        gen_uint!(g, "romutrio32", u32, RomuTrio32::from_rng(&mut master));
+        gen_uint!(g, "jsf32", u32, Jsf32::from_rng(&mut master));
+        gen_uint!(g, "jsf32rot3", u32, Jsf32Rot3::from_rng(&mut master));
+        gen_uint!(g, "jsf64", u32, Jsf64::from_rng(&mut master));
+        gen_uint!(g, "gjrand32", u32, Gjrand32::from_rng(&mut master));
+        gen_uint!(g, "gjrand64", u32, Gjrand64::from_rng(&mut master));
+        gen_uint!(g, "tyche", u32, Tyche::from_rng(&mut master));
+        gen_uint!(g, "tychei", u32, TycheI::from_rng(&mut master));
+        gen_uint!(g, "squares32", u32, Squares32Rng::from_rng(&mut master));
+        gen_uint!(g, "squares64", u32, Squares64Rng::from_rng(&mut master));
+        gen_uint!(g, "splitmix32", u32, SplitMix32::from_rng(&mut master));
+        gen_uint!(
+            g,
+            "splittablemix64",
+            u32,
+            SplittableMix64::from_rng(&mut master)
+        );
+        gen_uint!(g, "rabbit", u32, RabbitRng::from_rng(&mut master));
+        gen_uint!(g, "salsa12", u32, Salsa12Rng::from_rng(&mut master));
+        gen_uint!(g, "sosemanuk", u32, SosemanukRng::from_rng(&mut master));
+        gen_uint!(g, "kiss99", u32, Kiss99::from_rng(&mut master));
+        gen_uint!(g, "kiss64", u32, Kiss64::from_rng(&mut master));
+        gen_uint!(g, "ran_array", u32, RanArray::from_rng(&mut master));
+        gen_uint!(g, "ranlux24", u32, Ranlux24::from_rng(&mut master));
+        gen_uint!(g, "ranlux48", u32, Ranlux48::from_rng(&mut master));
+        gen_uint!(g, "ranluxpp", u32, Ranluxpp::from_rng(&mut master));
+        gen_uint!(g, "ranluxpp_root", u32, RanluxppRoot::from_rng(&mut master));
     }
 
     {
@@ -265,11 +389,76 @@ fn gen_uint(c: &mut Criterion<CyclesPerByte>) {
             Xoroshiro64Star::from_rng(&mut master)
         );
         gen_uint!(g, "splitmix64", u64, SplitMix64::from_rng(&mut master));
+        gen_uint!(g, "wyrand", u64, WyRand::from_rng(&mut master));
+        gen_uint!(g, "mcg128", u64, Mcg128::from_rng(&mut master));
+        gen_uint!(g, "lcg128", u64, Lcg128::from_rng(&mut master));
+        gen_uint!(g, "aes_ctr", u64, AesCtrRng::from_rng(&mut master));
         gen_uint!(g, "hc128", u64, Hc128Rng::from_rng(&mut master));
         gen_uint!(g, "isaac", u64, IsaacRng::from_rng(&mut master));
         gen_uint!(g, "isaac64", u64, Isaac64Rng::from_rng(&mut master));
         gen_uint!(g, "sfc32", u64, Sfc32::from_rng(&mut master));
         gen_uint!(g, "sfc64", u64, Sfc64::from_rng(&mut master));
+        gen_uint!(g, "philox4x32", u64, Philox4x32Rng::from_rng(&mut master));
+        gen_uint!(g, "philox4x64", u64, Philox4x64Rng::from_rng(&mut master));
+        gen_uint!(
+            g,
+            "threefry2x64",
+            u64,
+            Threefry2x64Rng::<20>::from_rng(&mut master)
+        );
+        gen_uint!(
+            g,
+            "threefry4x64",
+            u64,
+            Threefry4x64Rng::<20>::from_rng(&mut master)
+        );
+        gen_uint!(g, "pcg32", u64, Pcg32::from_rng(&mut master));
+        gen_uint!(g, "pcg64", u64, Pcg64::from_rng(&mut master));
+        gen_uint!(g, "pcg64dxsm", u64, Pcg64Dxsm::from_rng(&mut master));
+        gen_uint!(g, "pcg64mcg", u64, Pcg64Mcg::from_rng(&mut master));
+        gen_uint!(g, "mt19937", u64, Mt19937::from_rng(&mut master));
+        gen_uint!(g, "mt19937_64", u64, Mt19937_64::from_rng(&mut master));
+        gen_uint!(g, "dsfmt19937", u64, Dsfmt19937::from_rng(&mut master));
+        gen_uint!(g, "well512a", u64, Well512a::from_rng(&mut master));
+        gen_uint!(g, "well1024a", u64, Well1024a::from_rng(&mut master));
+        gen_uint!(g, "mwc128", u64, Mwc128::from_rng(&mut master));
+        gen_uint!(g, "mwc192", u64, Mwc192::from_rng(&mut master));
+        gen_uint!(g, "mwc256", u64, Mwc256::from_rng(&mut master));
+        gen_uint!(g, "l64x128mix", u64, L64X128Mix::from_rng(&mut master));
+        gen_uint!(g, "l64x256mix", u64, L64X256Mix::from_rng(&mut master));
+        gen_uint!(g, "l128x256mix", u64, L128X256Mix::from_rng(&mut master));
+        gen_uint!(g, "romuquad", u64, RomuQuad::from_rng(&mut master));
+        gen_uint!(g, "romutrio", u64, RomuTrio::from_rng(&mut master));
+        gen_uint!(g, "romuduo", u64, RomuDuo::from_rng(&mut master));
+        gen_uint!(g, "romuduojr", u64, RomuDuoJr::from_rng(&mut master));
+        gen_uint!(g, "romuquad32", u64, RomuQuad32::from_rng(&mut master));
+        gen_uint!(g, "romutrio32", u64, RomuTrio32::from_rng(&mut master));
+        gen_uint!(g, "jsf32", u64, Jsf32::from_rng(&mut master));
+        gen_uint!(g, "jsf32rot3", u64, Jsf32Rot3::from_rng(&mut master));
+        gen_uint!(g, "jsf64", u64, Jsf64::from_rng(&mut master));
+        gen_uint!(g, "gjrand32", u64, Gjrand32::from_rng(&mut master));
+        gen_uint!(g, "gjrand64", u64, Gjrand64::from_rng(&mut master));
+        gen_uint!(g, "tyche", u64, Tyche::from_rng(&mut master));
+        gen_uint!(g, "tychei", u64, TycheI::from_rng(&mut master));
+        gen_uint!(g, "squares32", u64, Squares32Rng::from_rng(&mut master));
+        gen_uint!(g, "squares64", u64, Squares64Rng::from_rng(&mut master));
+        gen_uint!(g, "splitmix32", u64, SplitMix32::from_rng(&mut master));
+        gen_uint!(
+            g,
+            "splittablemix64",
+            u64,
+            SplittableMix64::from_rng(&mut master)
+        );
+        gen_uint!(g, "rabbit", u64, RabbitRng::from_rng(&mut master));
+        gen_uint!(g, "salsa12", u64, Salsa12Rng::from_rng(&mut master));
+        gen_uint!(g, "sosemanuk", u64, SosemanukRng::from_rng(&mut master));
+        gen_uint!(g, "kiss99", u64, Kiss99::from_rng(&mut master));
+        gen_uint!(g, "kiss64", u64, Kiss64::from_rng(&mut master));
+        gen_uint!(g, "ran_array", u64, RanArray::from_rng(&mut master));
+        gen_uint!(g, "ranlux24", u64, Ranlux24::from_rng(&mut master));
+        gen_uint!(g, "ranlux48", u64, Ranlux48::from_rng(&mut master));
+        gen_uint!(g, "ranluxpp", u64, Ranluxpp::from_rng(&mut master));
+        gen_uint!(g, "ranluxpp_root", u64, RanluxppRoot::from_rng(&mut master));
     }
 }
 
@@ -278,10 +467,10 @@ fn init(c: &mut Criterion) {
     let mut master = Hc128Rng::seed_from_u64(2);
 
     macro_rules! init_gen {
-        ($fnn:expr, $gen:ident) => {
+        ($fnn:expr, $gen:ty) => {
             g.bench_function($fnn, |b| {
                 let mut rng = XorShiftRng::from_rng(&mut master);
-                b.iter(|| $gen::from_rng(black_box(&mut rng)))
+                b.iter(|| <$gen>::from_rng(black_box(&mut rng)))
             });
         };
     }
@@ -298,11 +487,61 @@ fn init(c: &mut Criterion) {
     init_gen!("xoroshiro64starstar", Xoroshiro64StarStar);
     init_gen!("xoroshiro64star", Xoroshiro64Star);
     init_gen!("splitmix64", SplitMix64);
+    init_gen!("wyrand", WyRand);
+    init_gen!("mcg128", Mcg128);
+    init_gen!("lcg128", Lcg128);
+    init_gen!("aes_ctr", AesCtrRng);
     init_gen!("hc128", Hc128Rng);
     init_gen!("isaac", IsaacRng);
     init_gen!("isaac64", Isaac64Rng);
     init_gen!("sfc32", Sfc32);
     init_gen!("sfc64", Sfc64);
+    init_gen!("philox4x32", Philox4x32Rng);
+    init_gen!("philox4x64", Philox4x64Rng);
+    init_gen!("threefry2x64", Threefry2x64Rng<20>);
+    init_gen!("threefry4x64", Threefry4x64Rng<20>);
+    init_gen!("pcg32", Pcg32);
+    init_gen!("pcg64", Pcg64);
+    init_gen!("pcg64dxsm", Pcg64Dxsm);
+    init_gen!("pcg64mcg", Pcg64Mcg);
+    init_gen!("mt19937", Mt19937);
+    init_gen!("mt19937_64", Mt19937_64);
+    init_gen!("dsfmt19937", Dsfmt19937);
+    init_gen!("well512a", Well512a);
+    init_gen!("well1024a", Well1024a);
+    init_gen!("mwc128", Mwc128);
+    init_gen!("mwc192", Mwc192);
+    init_gen!("mwc256", Mwc256);
+    init_gen!("l64x128mix", L64X128Mix);
+    init_gen!("l64x256mix", L64X256Mix);
+    init_gen!("l128x256mix", L128X256Mix);
+    init_gen!("romuquad", RomuQuad);
+    init_gen!("romutrio", RomuTrio);
+    init_gen!("romuduo", RomuDuo);
+    init_gen!("romuduojr", RomuDuoJr);
+    init_gen!("romuquad32", RomuQuad32);
+    init_gen!("romutrio32", RomuTrio32);
+    init_gen!("jsf32", Jsf32);
+    init_gen!("jsf32rot3", Jsf32Rot3);
+    init_gen!("jsf64", Jsf64);
+    init_gen!("gjrand32", Gjrand32);
+    init_gen!("gjrand64", Gjrand64);
+    init_gen!("tyche", Tyche);
+    init_gen!("tychei", TycheI);
+    init_gen!("squares32", Squares32Rng);
+    init_gen!("squares64", Squares64Rng);
+    init_gen!("splitmix32", SplitMix32);
+    init_gen!("splittablemix64", SplittableMix64);
+    init_gen!("rabbit", RabbitRng);
+    init_gen!("salsa12", Salsa12Rng);
+    init_gen!("sosemanuk", SosemanukRng);
+    init_gen!("kiss99", Kiss99);
+    init_gen!("kiss64", Kiss64);
+    init_gen!("ran_array", RanArray);
+    init_gen!("ranlux24", Ranlux24);
+    init_gen!("ranlux48", Ranlux48);
+    init_gen!("ranluxpp", Ranluxpp);
+    init_gen!("ranluxpp_root", RanluxppRoot);
 }
 
 criterion_group! {
@@ -0,0 +1,487 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A constant-time bitsliced implementation of AES.
+//!
+//! This follows the 64-bit bitsliced implementation `aes_ct64` of BearSSL by
+//! Thomas Pornin: four blocks are encrypted in parallel, with the bits of
+//! their 64 bytes spread over eight `u64` words, such that word `i` contains
+//! bit `i` of every byte. The S-box is evaluated as a boolean circuit, by
+//! Boyar and Peralta, on all bytes at once. There are no table lookups and no
+//! branches that depend on the key or the data, so the timing does not
+//! depend on them.
+
+use core::fmt;
+
+/// The number of blocks encrypted in parallel.
+pub(crate) const PARALLEL_BLOCKS: usize = 4;
+
+/// Bitsliced state of four blocks.
+type State = [u64; 8];
+
+/// The AES block cipher, with a 128-bit or 256-bit key.
+///
+/// This is the block cipher used by [`AesCtrRng`], for constructions that
+/// need the cipher itself, such as the CTR_DRBG of NIST SP 800-90A. Only
+/// encryption is supported. The key is expanded once, on construction, and
+/// blocks are encrypted four at a time, in constant time.
+///
+/// [`AesCtrRng`]: crate::AesCtrRng
+#[derive(Clone, PartialEq, Eq)]
+pub struct Aes {
+    round_keys: [State; 15],
+    rounds: usize,
+}
+
+// Custom Debug implementation that does not expose the internal state
+impl fmt::Debug for Aes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Aes {{}}")
+    }
+}
+
+impl Aes {
+    /// Create an AES-128 cipher from a 128-bit key.
+    pub fn new_128(key: &[u8; 16]) -> Self {
+        Self::new(key)
+    }
+
+    /// Create an AES-256 cipher from a 256-bit key.
+    pub fn new_256(key: &[u8; 32]) -> Self {
+        Self::new(key)
+    }
+
+    fn new(key: &[u8]) -> Self {
+        let nk = key.len() / 4;
+        let rounds = nk + 6;
+        let n = 4 * (rounds + 1);
+
+        let mut w = [0u32; 60];
+        for (w, chunk) in w.iter_mut().zip(key.chunks_exact(4)) {
+            *w = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        let mut rcon = 1u32;
+        for i in nk..n {
+            let mut tmp = w[i - 1];
+            if i % nk == 0 {
+                tmp = sub_word(tmp.rotate_right(8)) ^ rcon;
+                rcon = xtime(rcon);
+            } else if nk > 6 && i % nk == 4 {
+                tmp = sub_word(tmp);
+            }
+            w[i] = w[i - nk] ^ tmp;
+        }
+
+        let mut round_keys = [[0; 8]; 15];
+        for (rk, w) in round_keys.iter_mut().zip(w[..n].chunks_exact(4)) {
+            // The same round key is used for all blocks.
+            let mut words = [0; 4 * PARALLEL_BLOCKS];
+            for block in words.chunks_exact_mut(4) {
+                block.copy_from_slice(w);
+            }
+            *rk = load(&words);
+        }
+        Aes { round_keys, rounds }
+    }
+
+    /// Encrypt four blocks in place.
+    ///
+    /// Encrypting fewer blocks takes as long: fill the others with any value
+    /// and ignore their result.
+    pub fn encrypt_blocks(&self, blocks: &mut [[u8; 16]; PARALLEL_BLOCKS]) {
+        let mut words = [0; 4 * PARALLEL_BLOCKS];
+        for (w, chunk) in words.iter_mut().zip(blocks.as_flattened().chunks_exact(4)) {
+            *w = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        let words = self.encrypt(&words);
+        for (chunk, w) in blocks.as_flattened_mut().chunks_exact_mut(4).zip(words) {
+            chunk.copy_from_slice(&w.to_le_bytes());
+        }
+    }
+
+    /// Encrypt four blocks, given and returned as little-endian words.
+    pub(crate) fn encrypt(
+        &self,
+        blocks: &[u32; 4 * PARALLEL_BLOCKS],
+    ) -> [u32; 4 * PARALLEL_BLOCKS] {
+        let mut q = load(blocks);
+        add_round_key(&mut q, &self.round_keys[0]);
+        for rk in &self.round_keys[1..self.rounds] {
+            sbox(&mut q);
+            shift_rows(&mut q);
+            mix_columns(&mut q);
+            add_round_key(&mut q, rk);
+        }
+        sbox(&mut q);
+        shift_rows(&mut q);
+        add_round_key(&mut q, &self.round_keys[self.rounds]);
+        store(&q)
+    }
+}
+
+/// Multiply by x in GF(2^8), for the round constants.
+fn xtime(x: u32) -> u32 {
+    let x = x << 1;
+    x ^ (0x11b & 0u32.wrapping_sub(x >> 8))
+}
+
+/// Apply the S-box to the four bytes of `x`.
+fn sub_word(x: u32) -> u32 {
+    let mut q = [0; 8];
+    q[0] = u64::from(x);
+    ortho(&mut q);
+    sbox(&mut q);
+    ortho(&mut q);
+    q[0] as u32
+}
+
+/// Convert four blocks of little-endian words to bitsliced form.
+fn load(blocks: &[u32; 4 * PARALLEL_BLOCKS]) -> State {
+    let mut q = [0; 8];
+    for i in 0..PARALLEL_BLOCKS {
+        let (lo, hi) = interleave_in(&blocks[4 * i..4 * i + 4]);
+        q[i] = lo;
+        q[i + 4] = hi;
+    }
+    ortho(&mut q);
+    q
+}
+
+/// Convert four blocks from bitsliced form to little-endian words.
+fn store(q: &State) -> [u32; 4 * PARALLEL_BLOCKS] {
+    let mut q = *q;
+    ortho(&mut q);
+    let mut blocks = [0; 4 * PARALLEL_BLOCKS];
+    for i in 0..PARALLEL_BLOCKS {
+        interleave_out(&mut blocks[4 * i..4 * i + 4], q[i], q[i + 4]);
+    }
+    blocks
+}
+
+/// Spread the bytes of a block over two words, such that the columns end up
+/// in the right places after `ortho`.
+fn interleave_in(w: &[u32]) -> (u64, u64) {
+    let mut x = [0u64; 4];
+    for (x, &w) in x.iter_mut().zip(w) {
+        *x = u64::from(w);
+        *x |= *x << 16;
+        *x &= 0x0000_ffff_0000_ffff;
+        *x |= *x << 8;
+        *x &= 0x00ff_00ff_00ff_00ff;
+    }
+    (x[0] | (x[2] << 8), x[1] | (x[3] << 8))
+}
+
+/// The inverse of `interleave_in`.
+fn interleave_out(w: &mut [u32], q0: u64, q1: u64) {
+    let mut x = [
+        q0 & 0x00ff_00ff_00ff_00ff,
+        q1 & 0x00ff_00ff_00ff_00ff,
+        (q0 >> 8) & 0x00ff_00ff_00ff_00ff,
+        (q1 >> 8) & 0x00ff_00ff_00ff_00ff,
+    ];
+    for (w, x) in w.iter_mut().zip(x.iter_mut()) {
+        *x |= *x >> 8;
+        *x &= 0x0000_ffff_0000_ffff;
+        *w = (*x as u32) | ((*x >> 16) as u32);
+    }
+}
+
+/// Transpose the 8x8 bit matrices in the state, which converts between bytes
+/// and bit planes. This is an involution.
+fn ortho(q: &mut State) {
+    #[inline(always)]
+    fn swap(q: &mut State, i: usize, j: usize, lo: u64, s: u32) {
+        let hi = lo << s;
+        let (a, b) = (q[i], q[j]);
+        q[i] = (a & lo) | ((b & lo) << s);
+        q[j] = ((a & hi) >> s) | (b & hi);
+    }
+
+    for i in (0..8).step_by(2) {
+        swap(q, i, i + 1, 0x5555_5555_5555_5555, 1);
+    }
+    for i in [0, 1, 4, 5] {
+        swap(q, i, i + 2, 0x3333_3333_3333_3333, 2);
+    }
+    for i in 0..4 {
+        swap(q, i, i + 4, 0x0f0f_0f0f_0f0f_0f0f, 4);
+    }
+}
+
+fn add_round_key(q: &mut State, rk: &State) {
+    for (q, k) in q.iter_mut().zip(rk) {
+        *q ^= k;
+    }
+}
+
+fn shift_rows(q: &mut State) {
+    for x in q.iter_mut() {
+        *x = (*x & 0x0000_0000_0000_ffff)
+            | ((*x & 0x0000_0000_fff0_0000) >> 4)
+            | ((*x & 0x0000_0000_000f_0000) << 12)
+            | ((*x & 0x0000_ff00_0000_0000) >> 8)
+            | ((*x & 0x0000_00ff_0000_0000) << 8)
+            | ((*x & 0xf000_0000_0000_0000) >> 12)
+            | ((*x & 0x0fff_0000_0000_0000) << 4);
+    }
+}
+
+fn mix_columns(q: &mut State) {
+    let [q0, q1, q2, q3, q4, q5, q6, q7] = *q;
+    let [r0, r1, r2, r3, r4, r5, r6, r7] = q.map(|x| x.rotate_right(16));
+    let rotr32 = |x: u64| x.rotate_right(32);
+
+    q[0] = q7 ^ r7 ^ r0 ^ rotr32(q0 ^ r0);
+    q[1] = q0 ^ r0 ^ q7 ^ r7 ^ r1 ^ rotr32(q1 ^ r1);
+    q[2] = q1 ^ r1 ^ r2 ^ rotr32(q2 ^ r2);
+    q[3] = q2 ^ r2 ^ q7 ^ r7 ^ r3 ^ rotr32(q3 ^ r3);
+    q[4] = q3 ^ r3 ^ q7 ^ r7 ^ r4 ^ rotr32(q4 ^ r4);
+    q[5] = q4 ^ r4 ^ r5 ^ rotr32(q5 ^ r5);
+    q[6] = q5 ^ r5 ^ r6 ^ rotr32(q6 ^ r6);
+    q[7] = q6 ^ r6 ^ r7 ^ rotr32(q7 ^ r7);
+}
+
+/// The AES S-box as a circuit of 113 gates, by Joan Boyar and René Peralta.
+fn sbox(q: &mut State) {
+    let x0 = q[7];
+    let x1 = q[6];
+    let x2 = q[5];
+    let x3 = q[4];
+    let x4 = q[3];
+    let x5 = q[2];
+    let x6 = q[1];
+    let x7 = q[0];
+
+    // Top linear transformation
+    let y14 = x3 ^ x5;
+    let y13 = x0 ^ x6;
+    let y9 = x0 ^ x3;
+    let y8 = x0 ^ x5;
+    let t0 = x1 ^ x2;
+    let y1 = t0 ^ x7;
+    let y4 = y1 ^ x3;
+    let y12 = y13 ^ y14;
+    let y2 = y1 ^ x0;
+    let y5 = y1 ^ x6;
+    let y3 = y5 ^ y8;
+    let t1 = x4 ^ y12;
+    let y15 = t1 ^ x5;
+    let y20 = t1 ^ x1;
+    let y6 = y15 ^ x7;
+    let y10 = y15 ^ t0;
+    let y11 = y20 ^ y9;
+    let y7 = x7 ^ y11;
+    let y17 = y10 ^ y11;
+    let y19 = y10 ^ y8;
+    let y16 = t0 ^ y11;
+    let y21 = y13 ^ y16;
+    let y18 = x0 ^ y16;
+
+    // Non-linear section
+    let t2 = y12 & y15;
+    let t3 = y3 & y6;
+    let t4 = t3 ^ t2;
+    let t5 = y4 & x7;
+    let t6 = t5 ^ t2;
+    let t7 = y13 & y16;
+    let t8 = y5 & y1;
+    let t9 = t8 ^ t7;
+    let t10 = y2 & y7;
+    let t11 = t10 ^ t7;
+    let t12 = y9 & y11;
+    let t13 = y14 & y17;
+    let t14 = t13 ^ t12;
+    let t15 = y8 & y10;
+    let t16 = t15 ^ t12;
+    let t17 = t4 ^ t14;
+    let t18 = t6 ^ t16;
+    let t19 = t9 ^ t14;
+    let t20 = t11 ^ t16;
+    let t21 = t17 ^ y20;
+    let t22 = t18 ^ y19;
+    let t23 = t19 ^ y21;
+    let t24 = t20 ^ y18;
+
+    let t25 = t21 ^ t22;
+    let t26 = t21 & t23;
+    let t27 = t24 ^ t26;
+    let t28 = t25 & t27;
+    let t29 = t28 ^ t22;
+    let t30 = t23 ^ t24;
+    let t31 = t22 ^ t26;
+    let t32 = t31 & t30;
+    let t33 = t32 ^ t24;
+    let t34 = t23 ^ t33;
+    let t35 = t27 ^ t33;
+    let t36 = t24 & t35;
+    let t37 = t36 ^ t34;
+    let t38 = t27 ^ t36;
+    let t39 = t29 & t38;
+    let t40 = t25 ^ t39;
+
+    let t41 = t40 ^ t37;
+    let t42 = t29 ^ t33;
+    let t43 = t29 ^ t40;
+    let t44 = t33 ^ t37;
+    let t45 = t42 ^ t41;
+    let z0 = t44 & y15;
+    let z1 = t37 & y6;
+    let z2 = t33 & x7;
+    let z3 = t43 & y16;
+    let z4 = t40 & y1;
+    let z5 = t29 & y7;
+    let z6 = t42 & y11;
+    let z7 = t45 & y17;
+    let z8 = t41 & y10;
+    let z9 = t44 & y12;
+    let z10 = t37 & y3;
+    let z11 = t33 & y4;
+    let z12 = t43 & y13;
+    let z13 = t40 & y5;
+    let z14 = t29 & y2;
+    let z15 = t42 & y9;
+    let z16 = t45 & y14;
+    let z17 = t41 & y8;
+
+    // Bottom linear transformation
+    let t46 = z15 ^ z16;
+    let t47 = z10 ^ z11;
+    let t48 = z5 ^ z13;
+    let t49 = z9 ^ z10;
+    let t50 = z2 ^ z12;
+    let t51 = z2 ^ z5;
+    let t52 = z7 ^ z8;
+    let t53 = z0 ^ z3;
+    let t54 = z6 ^ z7;
+    let t55 = z16 ^ z17;
+    let t56 = z12 ^ t48;
+    let t57 = t50 ^ t53;
+    let t58 = z4 ^ t46;
+    let t59 = z3 ^ t54;
+    let t60 = t46 ^ t57;
+    let t61 = z14 ^ t57;
+    let t62 = t52 ^ t58;
+    let t63 = t49 ^ t58;
+    let t64 = z4 ^ t59;
+    let t65 = t61 ^ t62;
+    let t66 = z1 ^ t63;
+    let s0 = t59 ^ t63;
+    let s6 = t56 ^ !t62;
+    let s7 = t48 ^ !t60;
+    let t67 = t64 ^ t65;
+    let s3 = t53 ^ t66;
+    let s4 = t51 ^ t66;
+    let s5 = t47 ^ t65;
+    let s1 = t64 ^ !s3;
+    let s2 = t55 ^ !t67;
+
+    *q = [s7, s6, s5, s4, s3, s2, s1, s0];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_hex<const N: usize>(hex: &str) -> [u8; N] {
+        let mut bytes = [0u8; N];
+        for (b, i) in bytes.iter_mut().zip((0..hex.len()).step_by(2)) {
+            *b = u8::from_str_radix(&hex[i..i + 2], 16).unwrap();
+        }
+        bytes
+    }
+
+    fn encrypt_block(aes: &Aes, block: [u8; 16]) -> [u8; 16] {
+        let mut words = [0; 16];
+        for (w, chunk) in words.iter_mut().zip(block.chunks_exact(4)) {
+            *w = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        // Encrypt the block in the second of the four positions.
+        let words: [u32; 16] = core::array::from_fn(|i| if i / 4 == 1 { words[i % 4] } else { 0 });
+        let out = aes.encrypt(&words);
+        let mut result = [0; 16];
+        for (r, w) in result.chunks_exact_mut(4).zip(&out[4..8]) {
+            r.copy_from_slice(&w.to_le_bytes());
+        }
+        result
+    }
+
+    #[test]
+    fn sbox_table() {
+        // The S-box is the inverse in GF(2^8), followed by an affine map.
+        fn mul(mut a: u8, mut b: u8) -> u8 {
+            let mut p = 0;
+            while b != 0 {
+                if b & 1 != 0 {
+                    p ^= a;
+                }
+                a = (a << 1) ^ if a & 0x80 != 0 { 0x1b } else { 0 };
+                b >>= 1;
+            }
+            p
+        }
+        let table: [u8; 256] = core::array::from_fn(|x| {
+            let inv = (1..=255u8).find(|&y| mul(x as u8, y) == 1).unwrap_or(0);
+            inv ^ inv.rotate_left(1)
+                ^ inv.rotate_left(2)
+                ^ inv.rotate_left(3)
+                ^ inv.rotate_left(4)
+                ^ 0x63
+        });
+        assert_eq!(table[0x53], 0xed);
+        for x in 0..=255u8 {
+            let bytes = [x, x ^ 0x55, x ^ 0xaa, x ^ 0xff];
+            let expected = bytes.map(|b| table[usize::from(b)]);
+            assert_eq!(
+                sub_word(u32::from_le_bytes(bytes)),
+                u32::from_le_bytes(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn fips_197() {
+        // The examples of appendix C of FIPS 197
+        let plaintext = from_hex("00112233445566778899aabbccddeeff");
+
+        let aes = Aes::new_128(&from_hex("000102030405060708090a0b0c0d0e0f"));
+        let expected: [u8; 16] = from_hex("69c4e0d86a7b0430d8cdb78070b4c55a");
+        assert_eq!(encrypt_block(&aes, plaintext), expected);
+
+        let aes = Aes::new_256(&from_hex(
+            "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+        ));
+        let expected: [u8; 16] = from_hex("8ea2b7ca516745bfeafc49904b496089");
+        assert_eq!(encrypt_block(&aes, plaintext), expected);
+    }
+
+    #[test]
+    fn encrypt_blocks() {
+        let aes = Aes::new_128(&from_hex("000102030405060708090a0b0c0d0e0f"));
+        let plaintext = from_hex("00112233445566778899aabbccddeeff");
+        let mut blocks = [[0; 16], plaintext, [0xff; 16], plaintext];
+        aes.encrypt_blocks(&mut blocks);
+        let expected: [u8; 16] = from_hex("69c4e0d86a7b0430d8cdb78070b4c55a");
+        assert_eq!(blocks[1], expected);
+        assert_eq!(blocks[3], expected);
+        assert_eq!(blocks[0], encrypt_block(&aes, [0; 16]));
+    }
+
+    #[test]
+    fn parallel_blocks() {
+        // Every block is encrypted independently.
+        let aes = Aes::new_128(&[7; 16]);
+        let blocks: [u32; 16] = core::array::from_fn(|i| (i as u32).wrapping_mul(0x9e3779b9));
+        let out = aes.encrypt(&blocks);
+        for i in 0..PARALLEL_BLOCKS {
+            let mut single = [0; 16];
+            single[..4].copy_from_slice(&blocks[4 * i..4 * i + 4]);
+            assert_eq!(aes.encrypt(&single)[..4], out[4 * i..4 * i + 4]);
+        }
+    }
+}
@@ -0,0 +1,301 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The AES-CTR random number generator.
+
+use crate::aes::{Aes, PARALLEL_BLOCKS};
+use core::fmt;
+use rand_core::block::{BlockRng, CryptoGenerator, Generator};
+use rand_core::{CryptoRng, RngCore, SeedableRng};
+
+const BLOCK_WORDS: usize = 4;
+const BUF_WORDS: usize = BLOCK_WORDS * PARALLEL_BLOCKS;
+
+/// A cryptographically secure random number generator that uses AES in
+/// counter mode.
+///
+/// The output is the keystream of AES-128 or AES-256 in CTR mode, as
+/// specified by NIST SP 800-38A: the encryption of a 128-bit counter block,
+/// which is incremented as a big-endian number for every block. The initial
+/// counter block (the IV) is chosen on construction. A key and IV give a
+/// stream of 2<sup>128</sup> blocks; outputs only repeat after the counter
+/// wraps around.
+///
+/// AES is implemented in software, bitsliced to process four blocks at once,
+/// without table lookups or data-dependent branches, so that it runs in
+/// constant time on any platform. It does not use the AES instructions of
+/// the CPU, which makes it slower than hardware AES, but gives the same
+/// output everywhere.
+///
+/// As every block depends only on the key and its counter, the generator can
+/// jump to any position in the stream in constant time with
+/// [`set_word_pos`].
+///
+/// The seed of [`SeedableRng`] is a 256-bit key for AES-256, and the IV is
+/// zero. Use [`AesCtrRng::new_128`] or [`AesCtrRng::new_256`] to give an IV
+/// as well.
+///
+/// This implementation uses an output buffer of four blocks, sixteen `u32`
+/// words, and uses [`BlockRng`] to implement the [`RngCore`] methods. The
+/// words are read as little-endian from the keystream, so that `fill_bytes`
+/// returns the keystream bytes in order.
+///
+/// [`set_word_pos`]: AesCtrRng::set_word_pos
+#[derive(Clone, Debug)]
+pub struct AesCtrRng(BlockRng<AesCtrCore>);
+
+impl AesCtrRng {
+    /// Create a generator with AES-128, from a 128-bit `key` and the
+    /// big-endian initial counter block `iv`.
+    pub fn new_128(key: [u8; 16], iv: [u8; 16]) -> Self {
+        AesCtrRng(BlockRng::new(AesCtrCore::init(Aes::new_128(&key), iv)))
+    }
+
+    /// Create a generator with AES-256, from a 256-bit `key` and the
+    /// big-endian initial counter block `iv`.
+    pub fn new_256(key: [u8; 32], iv: [u8; 16]) -> Self {
+        AesCtrRng(BlockRng::new(AesCtrCore::init(Aes::new_256(&key), iv)))
+    }
+
+    /// Get the offset from the start of the stream, in 32-bit words.
+    ///
+    /// The stream is 2<sup>130</sup> words long; the position wraps around at
+    /// 2<sup>128</sup>.
+    pub fn get_word_pos(&self) -> u128 {
+        let block = self.0.core.block_pos().wrapping_mul(BLOCK_WORDS as u128);
+        let buffered = BUF_WORDS.saturating_sub(self.0.index());
+        block.wrapping_sub(buffered as u128)
+    }
+
+    /// Set the offset from the start of the stream, in 32-bit words.
+    ///
+    /// This can reach the first 2<sup>128</sup> words of the stream, which is
+    /// a quarter of it.
+    pub fn set_word_pos(&mut self, word_offset: u128) {
+        let index = (word_offset % BUF_WORDS as u128) as usize;
+        let block = (word_offset - index as u128) / BLOCK_WORDS as u128;
+        self.0.core.set_block_pos(block);
+        self.0.generate_and_set(index);
+    }
+}
+
+impl RngCore for AesCtrRng {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_word()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64_from_u32()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+}
+
+impl SeedableRng for AesCtrRng {
+    type Seed = <AesCtrCore as SeedableRng>::Seed;
+
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Self {
+        AesCtrRng(BlockRng::new(AesCtrCore::from_seed(seed)))
+    }
+}
+
+impl CryptoRng for AesCtrRng {}
+
+impl PartialEq for AesCtrRng {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.get_word_pos() == rhs.get_word_pos() && self.0.core == rhs.0.core
+    }
+}
+impl Eq for AesCtrRng {}
+
+/// The core of `AesCtrRng`, used with `BlockRng`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct AesCtrCore {
+    aes: Aes,
+    // The initial counter block
+    iv: u128,
+    // The next counter block
+    counter: u128,
+}
+
+// Custom Debug implementation that does not expose the internal state
+impl fmt::Debug for AesCtrCore {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "AesCtrCore {{}}")
+    }
+}
+
+impl Generator for AesCtrCore {
+    type Output = [u32; BUF_WORDS];
+
+    fn generate(&mut self, results: &mut Self::Output) {
+        let mut blocks = [0; BUF_WORDS];
+        for block in blocks.chunks_exact_mut(BLOCK_WORDS) {
+            let bytes = self.counter.to_be_bytes();
+            for (w, chunk) in block.iter_mut().zip(bytes.chunks_exact(4)) {
+                *w = u32::from_le_bytes(chunk.try_into().unwrap());
+            }
+            self.counter = self.counter.wrapping_add(1);
+        }
+        *results = self.aes.encrypt(&blocks);
+    }
+}
+
+impl AesCtrCore {
+    fn init(aes: Aes, iv: [u8; 16]) -> Self {
+        let iv = u128::from_be_bytes(iv);
+        AesCtrCore {
+            aes,
+            iv,
+            counter: iv,
+        }
+    }
+
+    // The number of blocks from the start of the stream
+    #[inline]
+    fn block_pos(&self) -> u128 {
+        self.counter.wrapping_sub(self.iv)
+    }
+
+    #[inline]
+    fn set_block_pos(&mut self, block: u128) {
+        self.counter = self.iv.wrapping_add(block);
+    }
+}
+
+impl SeedableRng for AesCtrCore {
+    type Seed = [u8; 32];
+
+    /// Create an AES-256-CTR random number generator with a 256-bit key as
+    /// seed, and an IV of zero.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::init(Aes::new_256(&seed), [0; 16])
+    }
+}
+
+impl CryptoGenerator for AesCtrCore {}
+
+#[cfg(test)]
+mod test {
+    use super::AesCtrRng;
+    use ::rand_core::{RngCore, SeedableRng};
+
+    fn from_hex<const N: usize>(hex: &str) -> [u8; N] {
+        let mut bytes = [0u8; N];
+        for (b, i) in bytes.iter_mut().zip((0..hex.len()).step_by(2)) {
+            *b = u8::from_str_radix(&hex[i..i + 2], 16).unwrap();
+        }
+        bytes
+    }
+
+    // The keystream of an example of NIST SP 800-38A, appendix F.5: the
+    // ciphertext xor the plaintext.
+    fn keystream(ciphertext: &str) -> [u8; 64] {
+        let plaintext: [u8; 64] = from_hex(
+            "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e51\
+             30c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710",
+        );
+        let mut keystream: [u8; 64] = from_hex(ciphertext);
+        for (k, p) in keystream.iter_mut().zip(plaintext.iter()) {
+            *k ^= p;
+        }
+        keystream
+    }
+
+    const IV: &str = "f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff";
+
+    #[test]
+    // F.5.1 CTR-AES128.Encrypt
+    fn test_aes128_ctr_true_values() {
+        let mut rng =
+            AesCtrRng::new_128(from_hex("2b7e151628aed2a6abf7158809cf4f3c"), from_hex(IV));
+        let mut results = [0u8; 64];
+        rng.fill_bytes(&mut results);
+        let expected = keystream(
+            "874d6191b620e3261bef6864990db6ce9806f66b7970fdff8617187bb9fffdff\
+             5ae4df3edbd5d35e5b4f09020db03eab1e031dda2fbe03d1792170a0f3009cee",
+        );
+        assert_eq!(results, expected);
+    }
+
+    #[test]
+    // F.5.5 CTR-AES256.Encrypt
+    fn test_aes256_ctr_true_values() {
+        let mut rng = AesCtrRng::new_256(
+            from_hex("603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4"),
+            from_hex(IV),
+        );
+        let mut results = [0u8; 64];
+        rng.fill_bytes(&mut results);
+        let expected = keystream(
+            "601ec313775789a5b7a7f504bbf3d228f443e3ca4d62b59aca84e990cacaf5c5\
+             2b0930daa23de94ce87017ba2d84988ddfc9c58db67aada613c2dd08457941a6",
+        );
+        assert_eq!(results, expected);
+
+        // Starting at the third block
+        rng.set_word_pos(8);
+        let mut results = [0u8; 32];
+        rng.fill_bytes(&mut results);
+        assert_eq!(results, expected[32..]);
+    }
+
+    #[test]
+    fn test_aes_ctr_counter_wraps() {
+        // The counter block is incremented as a 128-bit number.
+        let mut rng = AesCtrRng::new_128([1; 16], [0xff; 16]);
+        let mut results = [0u8; 32];
+        rng.fill_bytes(&mut results);
+
+        let mut rng = AesCtrRng::new_128([1; 16], [0; 16]);
+        let mut second = [0u8; 16];
+        rng.fill_bytes(&mut second);
+        assert_eq!(results[16..], second);
+    }
+
+    #[test]
+    fn test_aes_ctr_word_pos() {
+        let mut rng = AesCtrRng::seed_from_u64(0);
+        let mut a = [0u32; 40];
+        for x in a.iter_mut() {
+            *x = rng.next_u32();
+        }
+        assert_eq!(rng.get_word_pos(), 40);
+
+        rng.set_word_pos(21);
+        assert_eq!(rng.get_word_pos(), 21);
+        assert_eq!(rng.next_u32(), a[21]);
+        assert_eq!(rng.next_u64(), u64::from(a[22]) | (u64::from(a[23]) << 32));
+        assert_eq!(rng.get_word_pos(), 24);
+
+        rng.set_word_pos(16);
+        assert_eq!(rng.get_word_pos(), 16);
+        assert_eq!(rng.next_u32(), a[16]);
+
+        // The position wraps around at 2^128 words
+        rng.set_word_pos(u128::MAX);
+        rng.next_u32();
+        assert_eq!(rng.get_word_pos(), 0);
+    }
+
+    #[test]
+    fn test_aes_ctr_clone() {
+        let mut rng1 = AesCtrRng::new_128([0x55; 16], [1; 16]);
+        let mut rng2 = rng1.clone();
+        for _ in 0..20 {
+            assert_eq!(rng1.next_u32(), rng2.next_u32());
+        }
+        assert_eq!(rng1, rng2);
+    }
+}
@@ -0,0 +1,46 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A random number generator that uses AES in counter mode.
+//!
+//! This crate provides:
+//! - [`AesCtrRng`]: the keystream of AES-128 or AES-256 in CTR mode, as
+//!   specified by NIST SP 800-38A. It is cryptographically secure, and
+//!   supports seeking to any position of the stream.
+//! - [`Aes`]: the AES block cipher that it uses, for other constructions on
+//!   top of AES.
+//!
+//! AES is implemented in software, in constant time, and does not require
+//! hardware support.
+//!
+//! To initialize a generator, use the [`SeedableRng`][rand_core::SeedableRng]
+//! trait, or give a key and IV:
+//!
+//! ```
+//! use rand_aes::AesCtrRng;
+//! use rand_core::RngCore;
+//!
+//! let mut rng = AesCtrRng::new_128([0x2b; 16], [0; 16]);
+//! let x = rng.next_u64();
+//! ```
+
+#![doc(
+    html_logo_url = "https://www.rust-lang.org/logos/rust-logo-128x128-blk.png",
+    html_favicon_url = "https://www.rust-lang.org/favicon.ico"
+)]
+#![forbid(unsafe_code)]
+#![deny(missing_docs)]
+#![deny(missing_debug_implementations)]
+#![doc(test(attr(allow(unused_variables), deny(warnings))))]
+#![no_std]
+
+mod aes;
+mod ctr;
+
+pub use aes::Aes;
+pub use ctr::{AesCtrCore, AesCtrRng};
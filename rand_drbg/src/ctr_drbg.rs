@@ -0,0 +1,377 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The CTR_DRBG mechanism.
+
+use crate::drbg::{Drbg, MAX_REQUEST_LEN, ReseedCounter, ReseedRequired};
+use core::fmt;
+use rand_aes::Aes;
+
+const BLOCK_LEN: usize = 16;
+// The largest seed length, of AES-256
+const MAX_SEED_LEN: usize = 32 + BLOCK_LEN;
+
+/// CTR_DRBG with AES and a derivation function, as specified by NIST SP
+/// 800-90A section 10.2.
+///
+/// The working state is an AES key and a 128-bit counter block `V`. The
+/// output is the encryption of `V + 1`, `V + 2`, ..., like AES in CTR mode;
+/// after every request, the key and `V` are replaced by the update function,
+/// which makes earlier output unrecoverable from the state.
+///
+/// `KEY_LEN` is the length of the AES key in bytes, 16 or 32; see the aliases
+/// [`CtrDrbgAes128`] and [`CtrDrbgAes256`]. The security strength equals
+/// the key length.
+///
+/// The entropy input, nonce, personalization string and additional input
+/// are compressed with the block cipher derivation function, `Block_Cipher_df`,
+/// so they can have any length, and the entropy input need not have full
+/// entropy.
+///
+/// AES is the constant-time software implementation of [`rand_aes`].
+pub struct CtrDrbg<const KEY_LEN: usize> {
+    aes: Aes,
+    v: u128,
+    reseed_counter: ReseedCounter,
+}
+
+/// CTR_DRBG with AES-128, with a security strength of 128 bits.
+pub type CtrDrbgAes128 = CtrDrbg<16>;
+
+/// CTR_DRBG with AES-256, with a security strength of 256 bits.
+pub type CtrDrbgAes256 = CtrDrbg<32>;
+
+// Custom Debug implementation that does not expose the internal state
+impl<const KEY_LEN: usize> fmt::Debug for CtrDrbg<KEY_LEN> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CtrDrbg {{}}")
+    }
+}
+
+// Encrypt a single block.
+fn encrypt_block(aes: &Aes, block: [u8; BLOCK_LEN]) -> [u8; BLOCK_LEN] {
+    let mut blocks = [block, [0; BLOCK_LEN], [0; BLOCK_LEN], [0; BLOCK_LEN]];
+    aes.encrypt_blocks(&mut blocks);
+    blocks[0]
+}
+
+impl<const KEY_LEN: usize> CtrDrbg<KEY_LEN> {
+    const SEED_LEN: usize = KEY_LEN + BLOCK_LEN;
+
+    fn cipher(key: &[u8]) -> Aes {
+        const { assert!(KEY_LEN == 16 || KEY_LEN == 32, "unsupported key length") };
+        if KEY_LEN == 16 {
+            Aes::new_128(key.try_into().unwrap())
+        } else {
+            Aes::new_256(key.try_into().unwrap())
+        }
+    }
+
+    // The encryption of `V + 1` to `V + 4`, of which the first `SEED_LEN`
+    // bytes are used by `update`
+    fn keystream(&self) -> [[u8; BLOCK_LEN]; 4] {
+        let mut blocks = core::array::from_fn(|i| self.v.wrapping_add(i as u128 + 1).to_be_bytes());
+        self.aes.encrypt_blocks(&mut blocks);
+        blocks
+    }
+
+    // CTR_DRBG_Update, with the first `SEED_LEN` bytes of `provided_data`
+    fn update(&mut self, provided_data: &[u8; MAX_SEED_LEN]) {
+        let mut blocks = self.keystream();
+        let temp = &mut blocks.as_flattened_mut()[..Self::SEED_LEN];
+        for (t, p) in temp.iter_mut().zip(provided_data) {
+            *t ^= p;
+        }
+        self.aes = Self::cipher(&temp[..KEY_LEN]);
+        self.v = u128::from_be_bytes(temp[KEY_LEN..].try_into().unwrap());
+    }
+
+    // Block_Cipher_df of the concatenation of `parts`, returning `SEED_LEN`
+    // bytes
+    fn derive(parts: &[&[u8]]) -> [u8; MAX_SEED_LEN] {
+        let len: usize = parts.iter().map(|part| part.len()).sum();
+        let len = u32::try_from(len).expect("input too long for the derivation function");
+
+        // BCC of `IV_i || S` with a fixed key, for the `SEED_LEN / BLOCK_LEN`
+        // values of `i`, computed in parallel. The first block of every
+        // chain is the encryption of `IV_i`, which is `i` as a 32-bit
+        // big-endian number, padded with zeros.
+        let key: [u8; 32] = core::array::from_fn(|i| i as u8);
+        let mut bcc = Bcc {
+            aes: Self::cipher(&key[..KEY_LEN]),
+            chains: core::array::from_fn(|i| {
+                let mut iv = [0; BLOCK_LEN];
+                iv[..4].copy_from_slice(&(i as u32).to_be_bytes());
+                iv
+            }),
+            buf: [0; BLOCK_LEN],
+            buf_len: 0,
+        };
+        bcc.aes.encrypt_blocks(&mut bcc.chains);
+
+        // S = L || N || input || 0x80, padded with zeros to a whole block
+        bcc.update(&len.to_be_bytes());
+        bcc.update(&(Self::SEED_LEN as u32).to_be_bytes());
+        for part in parts {
+            bcc.update(part);
+        }
+        bcc.update(&[0x80]);
+        if bcc.buf_len != 0 {
+            bcc.update(&[0; BLOCK_LEN][bcc.buf_len..]);
+        }
+
+        // The result of the chains is a new key and a block `X`, which is
+        // encrypted repeatedly for the output.
+        let temp = bcc.chains.as_flattened();
+        let aes = Self::cipher(&temp[..KEY_LEN]);
+        let mut x: [u8; BLOCK_LEN] = temp[KEY_LEN..Self::SEED_LEN].try_into().unwrap();
+        let mut out = [0; MAX_SEED_LEN];
+        for chunk in out[..Self::SEED_LEN].chunks_exact_mut(BLOCK_LEN) {
+            x = encrypt_block(&aes, x);
+            chunk.copy_from_slice(&x);
+        }
+        out
+    }
+}
+
+// Four CBC-MACs with the same key and data, and different first blocks
+struct Bcc {
+    aes: Aes,
+    chains: [[u8; BLOCK_LEN]; 4],
+    buf: [u8; BLOCK_LEN],
+    buf_len: usize,
+}
+
+impl Bcc {
+    fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            let n = data.len().min(BLOCK_LEN - self.buf_len);
+            self.buf[self.buf_len..self.buf_len + n].copy_from_slice(&data[..n]);
+            self.buf_len += n;
+            data = &data[n..];
+            if self.buf_len == BLOCK_LEN {
+                for chain in self.chains.iter_mut() {
+                    for (c, b) in chain.iter_mut().zip(self.buf) {
+                        *c ^= b;
+                    }
+                }
+                self.aes.encrypt_blocks(&mut self.chains);
+                self.buf_len = 0;
+            }
+        }
+    }
+}
+
+impl<const KEY_LEN: usize> Drbg for CtrDrbg<KEY_LEN> {
+    const SECURITY_STRENGTH: usize = KEY_LEN;
+
+    fn instantiate(entropy_input: &[u8], nonce: &[u8], personalization_string: &[u8]) -> Self {
+        assert!(
+            entropy_input.len() >= Self::SECURITY_STRENGTH,
+            "entropy input too short"
+        );
+        let seed_material = Self::derive(&[entropy_input, nonce, personalization_string]);
+        let mut drbg = CtrDrbg {
+            aes: Self::cipher(&[0; 32][..KEY_LEN]),
+            v: 0,
+            reseed_counter: ReseedCounter::new(),
+        };
+        drbg.update(&seed_material);
+        drbg
+    }
+
+    fn reseed(&mut self, entropy_input: &[u8], additional_input: &[u8]) {
+        assert!(
+            entropy_input.len() >= Self::SECURITY_STRENGTH,
+            "entropy input too short"
+        );
+        let seed_material = Self::derive(&[entropy_input, additional_input]);
+        self.update(&seed_material);
+        self.reseed_counter.reset();
+    }
+
+    fn generate(&mut self, out: &mut [u8], additional_input: &[u8]) -> Result<(), ReseedRequired> {
+        assert!(out.len() <= MAX_REQUEST_LEN, "request too large");
+        self.reseed_counter.check()?;
+        let additional_input = if additional_input.is_empty() {
+            [0; MAX_SEED_LEN]
+        } else {
+            let additional_input = Self::derive(&[additional_input]);
+            self.update(&additional_input);
+            additional_input
+        };
+        for chunk in out.chunks_mut(4 * BLOCK_LEN) {
+            let blocks = self.keystream();
+            chunk.copy_from_slice(&blocks.as_flattened()[..chunk.len()]);
+            self.v = self.v.wrapping_add(chunk.len().div_ceil(BLOCK_LEN) as u128);
+        }
+        self.update(&additional_input);
+        self.reseed_counter.increment();
+        Ok(())
+    }
+
+    fn set_reseed_interval(&mut self, interval: u64) {
+        self.reseed_counter.set_interval(interval);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CtrDrbgAes128, CtrDrbgAes256};
+    use crate::{Drbg, ReseedRequired};
+
+    fn from_hex<const N: usize>(hex: &str) -> [u8; N] {
+        let mut bytes = [0u8; N];
+        for (b, i) in bytes.iter_mut().zip((0..hex.len()).step_by(2)) {
+            *b = u8::from_str_radix(&hex[i..i + 2], 16).unwrap();
+        }
+        bytes
+    }
+
+    // Instantiate, optionally reseed, generate `lens[0]` and then `lens[1]`
+    // bytes, and compare the second output, like the CAVP test procedure.
+    fn check<D: Drbg>(
+        [entropy_input, nonce, personalization_string]: [&str; 3],
+        reseed: Option<(&str, &str)>,
+        additional_input: [&str; 2],
+        lens: [usize; 2],
+        expected: &str,
+    ) {
+        let bytes = |hex: &str| (from_hex::<32>(hex), hex.len() / 2);
+        let ((e, e_len), (n, n_len), (p, p_len)) = (
+            bytes(entropy_input),
+            bytes(nonce),
+            bytes(personalization_string),
+        );
+        let mut drbg = D::instantiate(&e[..e_len], &n[..n_len], &p[..p_len]);
+        if let Some((entropy_input, additional_input)) = reseed {
+            let ((e, e_len), (a, a_len)) = (bytes(entropy_input), bytes(additional_input));
+            drbg.reseed(&e[..e_len], &a[..a_len]);
+        }
+        let mut results = [0u8; 128];
+        for (additional_input, len) in additional_input.into_iter().zip(lens) {
+            let (a, a_len) = bytes(additional_input);
+            drbg.generate(&mut results[..len], &a[..a_len]).unwrap();
+        }
+        let expected: [u8; 128] = from_hex(expected);
+        assert_eq!(results[..lens[1]], expected[..lens[1]]);
+    }
+
+    #[test]
+    fn test_ctr_drbg_aes128() {
+        // From the NIST CAVP test vectors for CTR_DRBG with AES-128 and the
+        // derivation function, without prediction resistance or reseed:
+        // COUNT = 0 without additional input
+        check::<CtrDrbgAes128>(
+            ["890eb067acf7382eff80b0c73bc872c6", "aad471ef3ef1d203", ""],
+            None,
+            ["", ""],
+            [64, 64],
+            "a5514ed7095f64f3d0d3a5760394ab42062f373a25072a6ea6bcfd8489e94af6\
+             cf18659fea22ed1ca0a9e33f718b115ee536b12809c31b72b08ddd8be1910fa3",
+        );
+        // With a personalization string, a reseed and additional input. The
+        // expected output was computed with the CTR-DRBG of OpenSSL 3.0.
+        check::<CtrDrbgAes128>(
+            [
+                "ce794331e4268242ccd368fa8d505f8e",
+                "2ab4bdd405969ef0",
+                "32a428981015a93bcc2bdb6bb2edfe40",
+            ],
+            Some((
+                "1aff9c73743e017486b9f12bf56e02a3",
+                "16fbad89fd914907fd20fa4ee61a1a83",
+            )),
+            [
+                "b0dc6c741a2d24120f87cac9a76f696e",
+                "537b5457d4658f8cdc81a1bdb547969c",
+            ],
+            [64, 64],
+            "65b6da589099ca8b3c08e17852b7104d9441e2794650e96c9fa76d26be9ce693\
+             3e27d0cbcc27a4acf8fa97ac31a21ac46a16bc8738692da23783e55bf86e62ae",
+        );
+    }
+
+    #[test]
+    fn test_ctr_drbg_aes256() {
+        // From the NIST CAVP test vectors for CTR_DRBG with AES-256 and the
+        // derivation function, without prediction resistance or reseed:
+        // COUNT = 0 without additional input
+        check::<CtrDrbgAes256>(
+            [
+                "36401940fa8b1fba91a1661f211d78a0b9389a74e5bccfece8d766af1a6d3b14",
+                "496f25b0f1301b4f501be30380a137eb",
+                "",
+            ],
+            None,
+            ["", ""],
+            [64, 64],
+            "5862eb38bd558dd978a696e6df164782ddd887e7e9a6c9f3f1fbafb78941b535\
+             a64912dfd224c6dc7454e5250b3d97165e16260c2faf1cc7735cb75fb4f07e1d",
+        );
+        // With a personalization string, a reseed and additional input. The
+        // expected output was computed with the CTR-DRBG of OpenSSL 3.0.
+        check::<CtrDrbgAes256>(
+            [
+                "3e79ae94b5d6c38bb3f36703aaf355ac7fb6b61feabb0ad2f525b57cd9ab061e",
+                "df4631e3350dbad9657a6529a11647c5",
+                "f7db7a32849cf12b0d0a847f7b63d82c5249c5de5415a98bee428acc4bafc706",
+            ],
+            Some((
+                "06d6e914615e34d212c878170f33ca7a3ff89dc60b2c5ed4ef0f481c2576e2d5",
+                "928411dc8155c8590fffa44480dd2ae24d1a8400683016fada13ad45fcb32ef2",
+            )),
+            [
+                "7c8a1f55d9a4ad7d4b71b358620eade5c232351972d2665b2214d8ddc62f6c52",
+                "f04ca4e912a351909c2110e4938a81faea2d34e5a6125daece80bb0f1c04a670",
+            ],
+            [64, 64],
+            "cc3449a364b2757edb4365e6c6820c82f39a9e2ba7d41bc87448e04c2bd60817\
+             4bd5441edfb19059e72e3a76bea1100ca85244f2e9e0948d4c984adc6832bf42",
+        );
+    }
+
+    #[test]
+    fn test_ctr_drbg_partial_blocks() {
+        // A request for a partial block uses up the whole block. The expected
+        // output was computed with the CTR-DRBG of OpenSSL 3.0.
+        check::<CtrDrbgAes256>(
+            [
+                "6078886377a314b518095bdf9b642f0c8ea5f50910edecd1dda17c66423e4008",
+                "c2ab6a07fa899429b213a67275fb7ae8",
+                "",
+            ],
+            None,
+            ["", ""],
+            [37, 65],
+            "bb79567f1b5d02dd17b7b41a5879988a692dd423faccb118574db0d7f51e4753\
+             b5822bd111a750f5e78f97bf72ca9c081e2b704ff209695999c992a49072f4b2\
+             ba",
+        );
+    }
+
+    #[test]
+    fn test_ctr_drbg_reseed_interval() {
+        let mut drbg = CtrDrbgAes128::instantiate(&[1; 16], &[2; 8], &[]);
+        drbg.set_reseed_interval(1);
+        let mut results = [0u8; 16];
+        assert_eq!(drbg.generate(&mut results, &[]), Ok(()));
+        assert_eq!(drbg.generate(&mut results, &[]), Err(ReseedRequired));
+        drbg.reseed(&[3; 16], b"additional input");
+        assert_eq!(drbg.generate(&mut results, &[]), Ok(()));
+        assert_eq!(drbg.generate(&mut results, &[]), Err(ReseedRequired));
+    }
+
+    #[test]
+    #[should_panic(expected = "request too large")]
+    fn test_ctr_drbg_request_too_large() {
+        let mut drbg = CtrDrbgAes256::instantiate(&[1; 32], &[2; 16], &[]);
+        let mut results = [0u8; crate::MAX_REQUEST_LEN + 1];
+        let _ = drbg.generate(&mut results, &[]);
+    }
+}
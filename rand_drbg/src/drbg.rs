@@ -0,0 +1,137 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::fmt;
+
+/// The largest reseed interval allowed by SP 800-90A for the mechanisms of
+/// this crate: 2<sup>48</sup> generate requests.
+///
+/// This is the default reseed interval.
+pub const MAX_RESEED_INTERVAL: u64 = 1 << 48;
+
+/// The largest number of bytes one generate request may return: 2<sup>16</sup>
+/// bytes, or 2<sup>19</sup> bits.
+pub const MAX_REQUEST_LEN: usize = 1 << 16;
+
+/// A DRBG mechanism of NIST SP 800-90A.
+///
+/// The methods are the instantiate, reseed and generate functions of the
+/// mechanism, which take the entropy input as argument. This makes them
+/// deterministic, as required for known-answer tests. To get the entropy
+/// input from an [`EntropySource`], and to reseed automatically, use
+/// [`DrbgRng`].
+///
+/// The working state counts the generate requests since the last reseed. Once
+/// their number reaches the reseed interval, [`generate`] returns
+/// [`ReseedRequired`] until [`reseed`] is called.
+///
+/// [`EntropySource`]: crate::EntropySource
+/// [`DrbgRng`]: crate::DrbgRng
+/// [`generate`]: Drbg::generate
+/// [`reseed`]: Drbg::reseed
+pub trait Drbg: Sized {
+    /// The security strength of the mechanism, in bytes.
+    ///
+    /// This is the minimum length of the entropy input. It is at most 32,
+    /// the highest security strength of SP 800-90A.
+    const SECURITY_STRENGTH: usize;
+
+    /// Instantiate the mechanism.
+    ///
+    /// `entropy_input` should contain at least [`SECURITY_STRENGTH`] bytes
+    /// of entropy. The `nonce` should contain half as much entropy, or be
+    /// unique for every instantiation. The personalization string is
+    /// optional, and may be empty.
+    ///
+    /// # Panics
+    ///
+    /// If `entropy_input` is shorter than [`SECURITY_STRENGTH`].
+    ///
+    /// [`SECURITY_STRENGTH`]: Drbg::SECURITY_STRENGTH
+    fn instantiate(entropy_input: &[u8], nonce: &[u8], personalization_string: &[u8]) -> Self;
+
+    /// Reseed the mechanism with fresh entropy input, and optional
+    /// additional input, and reset the count of generate requests.
+    ///
+    /// # Panics
+    ///
+    /// If `entropy_input` is shorter than [`SECURITY_STRENGTH`].
+    ///
+    /// [`SECURITY_STRENGTH`]: Drbg::SECURITY_STRENGTH
+    fn reseed(&mut self, entropy_input: &[u8], additional_input: &[u8]);
+
+    /// Fill `out` with pseudorandom bytes, with optional additional input.
+    ///
+    /// This fails without changing the state when the reseed interval has
+    /// been reached.
+    ///
+    /// # Panics
+    ///
+    /// If `out` is longer than [`MAX_REQUEST_LEN`].
+    fn generate(&mut self, out: &mut [u8], additional_input: &[u8]) -> Result<(), ReseedRequired>;
+
+    /// Set the number of generate requests after which the mechanism must
+    /// be reseeded.
+    ///
+    /// # Panics
+    ///
+    /// If `interval` is 0 or larger than [`MAX_RESEED_INTERVAL`].
+    fn set_reseed_interval(&mut self, interval: u64);
+}
+
+/// The error of [`Drbg::generate`] when the mechanism must be reseeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReseedRequired;
+
+impl fmt::Display for ReseedRequired {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "reseed required")
+    }
+}
+
+impl core::error::Error for ReseedRequired {}
+
+// The reseed counter and interval of a mechanism
+#[derive(Debug)]
+pub(crate) struct ReseedCounter {
+    count: u64,
+    interval: u64,
+}
+
+impl ReseedCounter {
+    pub(crate) fn new() -> Self {
+        ReseedCounter {
+            count: 1,
+            interval: MAX_RESEED_INTERVAL,
+        }
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.count = 1;
+    }
+
+    pub(crate) fn check(&self) -> Result<(), ReseedRequired> {
+        if self.count > self.interval {
+            Err(ReseedRequired)
+        } else {
+            Ok(())
+        }
+    }
+
+    pub(crate) fn increment(&mut self) {
+        self.count += 1;
+    }
+
+    pub(crate) fn set_interval(&mut self, interval: u64) {
+        assert!(
+            interval != 0 && interval <= MAX_RESEED_INTERVAL,
+            "invalid reseed interval"
+        );
+        self.interval = interval;
+    }
+}
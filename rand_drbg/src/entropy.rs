@@ -0,0 +1,54 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::fmt;
+
+/// A source of entropy input for a DRBG.
+///
+/// [`DrbgRng`] requests [`SECURITY_STRENGTH`] bytes to reseed, and half as
+/// many more for the nonce when instantiating, so the source should deliver
+/// bytes with full entropy. A source with less entropy per byte should
+/// condition its output first.
+///
+/// With the `jitter` feature, this is implemented for `JitterRng` of
+/// [rand_jitter], which collects 64 bits of entropy for every `u64` it
+/// returns, given the number of rounds from `JitterRng::test_timer`.
+///
+/// [`DrbgRng`]: crate::DrbgRng
+/// [`SECURITY_STRENGTH`]: crate::Drbg::SECURITY_STRENGTH
+/// [rand_jitter]: https://docs.rs/rand_jitter
+pub trait EntropySource {
+    /// The error type of the source.
+    type Error: fmt::Debug + fmt::Display;
+
+    /// Fill `dest` with entropy input.
+    fn fill_entropy(&mut self, dest: &mut [u8]) -> Result<(), Self::Error>;
+}
+
+impl<E: EntropySource + ?Sized> EntropySource for &mut E {
+    type Error = E::Error;
+
+    #[inline]
+    fn fill_entropy(&mut self, dest: &mut [u8]) -> Result<(), Self::Error> {
+        (**self).fill_entropy(dest)
+    }
+}
+
+#[cfg(feature = "jitter")]
+impl<F> EntropySource for rand_jitter::JitterRng<F>
+where
+    F: Fn() -> u64 + Send + Sync,
+{
+    type Error = core::convert::Infallible;
+
+    #[inline]
+    fn fill_entropy(&mut self, dest: &mut [u8]) -> Result<(), Self::Error> {
+        rand_core::RngCore::fill_bytes(self, dest);
+        Ok(())
+    }
+}
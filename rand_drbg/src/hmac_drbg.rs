@@ -0,0 +1,254 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The HMAC_DRBG mechanism.
+
+use crate::drbg::{Drbg, MAX_REQUEST_LEN, ReseedCounter, ReseedRequired};
+use crate::sha256::{Hmac, OUT_LEN};
+use core::fmt;
+
+/// HMAC_DRBG with SHA-256, as specified by NIST SP 800-90A section 10.1.2.
+///
+/// The working state is a key `K` and a value `V` of 256 bits each. Every
+/// output block is `V = HMAC(K, V)`, and after every request both are
+/// replaced by the update function, which makes earlier output unrecoverable
+/// from the state. The security strength is 256 bits.
+///
+/// HMAC_DRBG has no derivation function; the entropy input, nonce and
+/// personalization string are hashed directly into the state.
+pub struct HmacDrbg {
+    // HMAC keyed with `K`
+    hmac: Hmac,
+    v: [u8; OUT_LEN],
+    reseed_counter: ReseedCounter,
+}
+
+// Custom Debug implementation that does not expose the internal state
+impl fmt::Debug for HmacDrbg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "HmacDrbg {{}}")
+    }
+}
+
+impl HmacDrbg {
+    // HMAC_DRBG_Update, with `provided_data` the concatenation of `parts`
+    fn update(&mut self, parts: &[&[u8]]) {
+        let k = self.hmac.mac(
+            [&self.v[..], &[0x00]]
+                .into_iter()
+                .chain(parts.iter().copied()),
+        );
+        self.hmac = Hmac::new(&k);
+        self.v = self.hmac.mac([&self.v[..]]);
+        if parts.iter().all(|part| part.is_empty()) {
+            return;
+        }
+        let k = self.hmac.mac(
+            [&self.v[..], &[0x01]]
+                .into_iter()
+                .chain(parts.iter().copied()),
+        );
+        self.hmac = Hmac::new(&k);
+        self.v = self.hmac.mac([&self.v[..]]);
+    }
+}
+
+impl Drbg for HmacDrbg {
+    const SECURITY_STRENGTH: usize = 32;
+
+    fn instantiate(entropy_input: &[u8], nonce: &[u8], personalization_string: &[u8]) -> Self {
+        assert!(
+            entropy_input.len() >= Self::SECURITY_STRENGTH,
+            "entropy input too short"
+        );
+        let mut drbg = HmacDrbg {
+            hmac: Hmac::new(&[0x00; OUT_LEN]),
+            v: [0x01; OUT_LEN],
+            reseed_counter: ReseedCounter::new(),
+        };
+        drbg.update(&[entropy_input, nonce, personalization_string]);
+        drbg
+    }
+
+    fn reseed(&mut self, entropy_input: &[u8], additional_input: &[u8]) {
+        assert!(
+            entropy_input.len() >= Self::SECURITY_STRENGTH,
+            "entropy input too short"
+        );
+        self.update(&[entropy_input, additional_input]);
+        self.reseed_counter.reset();
+    }
+
+    fn generate(&mut self, out: &mut [u8], additional_input: &[u8]) -> Result<(), ReseedRequired> {
+        assert!(out.len() <= MAX_REQUEST_LEN, "request too large");
+        self.reseed_counter.check()?;
+        if !additional_input.is_empty() {
+            self.update(&[additional_input]);
+        }
+        for chunk in out.chunks_mut(OUT_LEN) {
+            self.v = self.hmac.mac([&self.v[..]]);
+            chunk.copy_from_slice(&self.v[..chunk.len()]);
+        }
+        self.update(&[additional_input]);
+        self.reseed_counter.increment();
+        Ok(())
+    }
+
+    fn set_reseed_interval(&mut self, interval: u64) {
+        self.reseed_counter.set_interval(interval);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::HmacDrbg;
+    use crate::{Drbg, ReseedRequired};
+
+    fn from_hex<const N: usize>(hex: &str) -> [u8; N] {
+        let mut bytes = [0u8; N];
+        for (b, i) in bytes.iter_mut().zip((0..hex.len()).step_by(2)) {
+            *b = u8::from_str_radix(&hex[i..i + 2], 16).unwrap();
+        }
+        bytes
+    }
+
+    // Instantiate, optionally reseed, generate twice, and compare the second
+    // output, like the CAVP test procedure.
+    fn check(
+        entropy_input: &str,
+        nonce: &str,
+        personalization_string: &str,
+        reseed: Option<(&str, &str)>,
+        additional_input: [&str; 2],
+        expected: &str,
+    ) {
+        let mut drbg = HmacDrbg::instantiate(
+            &from_hex::<32>(entropy_input),
+            &from_hex::<16>(nonce),
+            &from_hex::<32>(personalization_string)[..personalization_string.len() / 2],
+        );
+        if let Some((entropy_input, additional_input)) = reseed {
+            drbg.reseed(
+                &from_hex::<32>(entropy_input),
+                &from_hex::<32>(additional_input)[..additional_input.len() / 2],
+            );
+        }
+        let mut results = [0u8; 128];
+        for additional_input in additional_input {
+            let bytes = from_hex::<32>(additional_input);
+            drbg.generate(&mut results, &bytes[..additional_input.len() / 2])
+                .unwrap();
+        }
+        assert_eq!(results, from_hex::<128>(expected));
+    }
+
+    #[test]
+    fn test_hmac_drbg_true_values() {
+        // From the NIST CAVP test vectors for HMAC_DRBG with SHA-256, without
+        // prediction resistance or reseed: COUNT = 0 without additional input
+        check(
+            "ca851911349384bffe89de1cbdc46e6831e44d34a4fb935ee285dd14b71a7488",
+            "659ba96c601dc69fc902940805ec0ca8",
+            "",
+            None,
+            ["", ""],
+            "e528e9abf2dece54d47c7e75e5fe302149f817ea9fb4bee6f4199697d04d5b89\
+             d54fbb978a15b5c443c9ec21036d2460b6f73ebad0dc2aba6e624abf07745bc1\
+             07694bb7547bb0995f70de25d6b29e2d3011bb19d27676c07162c8b5ccde0668\
+             961df86803482cb37ed6d5c0bb8d50cf1f50d476aa0458bdaba806f48be9dcb8",
+        );
+        // ... COUNT = 0 with a 256-bit personalization string
+        check(
+            "5cacc68165a2e2ee20812f35ec73a79dbf30fd475476ac0c44fc6174cdac2b55",
+            "6f885496c1e63af620becd9e71ecb824",
+            "e72dd8590d4ed5295515c35ed6199e9d211b8f069b3058caa6670b96ef1208d0",
+            None,
+            ["", ""],
+            "f1012cf543f94533df27fedfbf58e5b79a3dc517a9c402bdbfc9a0c0f721f9d5\
+             3faf4aafdc4b8f7a1b580fcaa52338d4bd95f58966a243cdcd3f446ed4bc546d\
+             9f607b190dd69954450d16cd0e2d6437067d8b44d19a6af7a7cfa8794e5fbd72\
+             8e8fb2f2e8db5dd4ff1aa275f35886098e80ff844886060da8b1e7137846b23b",
+        );
+        // ... and COUNT = 0 with 256 bits of additional input
+        check(
+            "d3cc4d1acf3dde0c4bd2290d262337042dc632948223d3a2eaab87da44295fbd",
+            "0109b0e729f457328aa18569a9224921",
+            "",
+            None,
+            [
+                "3c311848183c9a212a26f27f8c6647e40375e466a0857cc39c4e47575d53f1f6",
+                "fcb9abd19ccfbccef88c9c39bfb3dd7b1c12266c9808992e305bc3cff566e4e4",
+            ],
+            "9c7b758b212cd0fcecd5daa489821712e3cdea4467b560ef5ddc24ab47749a1f\
+             1ffdbbb118f4e62fcfca3371b8fbfc5b0646b83e06bfbbab5fac30ea09ea2bc7\
+             6f1ea568c9be0444b2cc90517b20ca825f2d0eccd88e7175538b85d90ab39018\
+             3ca6395535d34473af6b5a5b88f5a59ee7561573337ea819da0dcc3573a22974",
+        );
+    }
+
+    #[test]
+    fn test_hmac_drbg_reseed() {
+        // From the NIST CAVP test vectors for HMAC_DRBG with SHA-256, without
+        // prediction resistance: COUNT = 0 without personalization string or
+        // additional input
+        check(
+            "06032cd5eed33f39265f49ecb142c511da9aff2af71203bffaf34a9ca5bd9c0d",
+            "0e66f71edc43e42a45ad3c6fc6cdc4df",
+            "",
+            Some((
+                "01920a4e669ed3a85ae8a33b35a74ad7fb2a6bb4cf395ce00334a9c9a5a5d552",
+                "",
+            )),
+            ["", ""],
+            "76fc79fe9b50beccc991a11b5635783a83536add03c157fb30645e611c2898bb\
+             2b1bc215000209208cd506cb28da2a51bdb03826aaf2bd2335d576d519160842\
+             e7158ad0949d1a9ec3e66ea1b1a064b005de914eac2e9d4f2d72a8616a802254\
+             22918250ff66a41bd2f864a6a38cc5b6499dc43f7f2bd09e1e0f8f5885935124",
+        );
+        // With a personalization string and a reseed with additional input.
+        // The expected output was computed with the HMAC-DRBG of OpenSSL 3.0.
+        check(
+            "35663bf417fefaf9360ced3898b9225b8111cb9837b4497abcdf7875467b2fc9",
+            "519f1777eeef6e4e962dd5cf40b21336",
+            "e4522a408044038ad30385d5c7e905dc40c55454197b478080ae9d2168f68e4b",
+            Some((
+                "a4007ed2f22e40302951313c27abb745471d3e7f69a351174a42d39a69d585a4",
+                "65c087815b0abdad402bc5f4d393510f2f8609c7334f67c98916c0ce09468781",
+            )),
+            [
+                "b0540cf89aa4a7346d1e01e2c72ff69068928b66ccbf6983ae56c276dcf35148",
+                "4c99df42cb1c38dd73d7691a8717286a9f1806671cc78ade9cb859661e96ac0d",
+            ],
+            "a7bfa5890ba2c5a20b07aa7deab68b37a6e25c96692ae3d6d7bf9275a84d7788\
+             363cd808dc715f573fc98d0adb9fbee955e058cbfa2ae003ebfc4f004c1e47a7\
+             a0f5e87582435216fffa3a9ba8dd9bdebd8b59422cd0a65464436c38cdfd2a90\
+             35a2dc368381002f5bac8beb450d4c898e4ead1760650137117d0ffb4e0ea751",
+        );
+    }
+
+    #[test]
+    fn test_hmac_drbg_reseed_interval() {
+        let mut drbg = HmacDrbg::instantiate(&[1; 32], &[2; 16], &[]);
+        drbg.set_reseed_interval(2);
+        let mut results = [0u8; 16];
+        assert_eq!(drbg.generate(&mut results, &[]), Ok(()));
+        assert_eq!(drbg.generate(&mut results, &[]), Ok(()));
+        let previous = results;
+        assert_eq!(drbg.generate(&mut results, &[]), Err(ReseedRequired));
+        assert_eq!(results, previous);
+
+        drbg.reseed(&[3; 32], &[]);
+        assert_eq!(drbg.generate(&mut results, &[]), Ok(()));
+    }
+
+    #[test]
+    #[should_panic(expected = "entropy input too short")]
+    fn test_hmac_drbg_short_entropy_input() {
+        HmacDrbg::instantiate(&[1; 31], &[2; 16], &[]);
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Deterministic random bit generators of NIST SP 800-90A.
+//!
+//! This crate provides two DRBG mechanisms of SP 800-90A Rev. 1:
+//! - [`CtrDrbg`]: CTR_DRBG with AES-128 or AES-256 and the derivation
+//!   function, using the AES of [`rand_aes`].
+//! - [`HmacDrbg`]: HMAC_DRBG with SHA-256.
+//!
+//! Unlike a plain stream cipher generator, such as `Hc128Rng` or
+//! `AesCtrRng`, a DRBG is reseeded from an entropy source during its
+//! lifetime, limits the number of requests between reseeds, and accepts
+//! additional input with every request.
+//!
+//! The mechanisms implement the [`Drbg`] trait, whose instantiate, reseed
+//! and generate functions take the entropy input as argument. [`DrbgRng`]
+//! combines a mechanism with an [`EntropySource`], reseeds it when
+//! required, and optionally provides prediction resistance. With the
+//! `jitter` feature, `JitterRng` of [rand_jitter] is an entropy source.
+//!
+//! ```
+//! use rand_drbg::{Drbg, HmacDrbg};
+//!
+//! # let entropy_input = [0x42; 32];
+//! # let nonce = [0x17; 16];
+//! let mut drbg = HmacDrbg::instantiate(&entropy_input, &nonce, b"personalization");
+//! let mut out = [0u8; 32];
+//! drbg.generate(&mut out, b"additional input").unwrap();
+//! ```
+//!
+//! [rand_jitter]: https://docs.rs/rand_jitter
+
+#![doc(
+    html_logo_url = "https://www.rust-lang.org/logos/rust-logo-128x128-blk.png",
+    html_favicon_url = "https://www.rust-lang.org/favicon.ico"
+)]
+#![forbid(unsafe_code)]
+#![deny(missing_docs)]
+#![deny(missing_debug_implementations)]
+#![doc(test(attr(allow(unused_variables), deny(warnings))))]
+#![no_std]
+
+mod ctr_drbg;
+mod drbg;
+mod entropy;
+mod hmac_drbg;
+mod rng;
+mod sha256;
+
+pub use ctr_drbg::{CtrDrbg, CtrDrbgAes128, CtrDrbgAes256};
+pub use drbg::{Drbg, MAX_REQUEST_LEN, MAX_RESEED_INTERVAL, ReseedRequired};
+pub use entropy::EntropySource;
+pub use hmac_drbg::HmacDrbg;
+pub use rng::DrbgRng;
@@ -0,0 +1,324 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::drbg::{Drbg, MAX_REQUEST_LEN};
+use crate::entropy::EntropySource;
+use core::fmt;
+use rand_core::{TryCryptoRng, TryRngCore};
+
+// The largest entropy input and nonce together, for a security strength of
+// 256 bits
+const MAX_SEED_LEN: usize = 48;
+
+/// A DRBG that gets its entropy input from an [`EntropySource`].
+///
+/// This follows the instantiate, reseed and generate functions of NIST SP
+/// 800-90A section 9:
+///
+/// - [`new`] instantiates the mechanism `D` with entropy input and a nonce
+///   from the source;
+/// - [`generate`] reseeds from the source when the reseed interval of the
+///   mechanism has been reached, and before every request if prediction
+///   resistance is enabled with [`with_prediction_resistance`];
+/// - [`reseed`] reseeds on demand.
+///
+/// The source is only used for reseeding, so an error of the source is the
+/// only error of `DrbgRng`.
+///
+/// `DrbgRng` implements [`TryRngCore`], where every method is one generate
+/// request without additional input. Wrap it in [`UnwrapErr`] to use it as
+/// an [`RngCore`] if the source cannot fail.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(all(feature = "jitter", target_arch = "x86_64"))] {
+/// use rand_core::{RngCore, UnwrapErr};
+/// use rand_drbg::{DrbgRng, HmacDrbg};
+/// use rand_jitter::{JitterRng, timer};
+///
+/// let mut jitter = JitterRng::new_with_timer(timer::rdtscp);
+/// if let Ok(rounds) = jitter.test_timer() {
+///     jitter.set_rounds(rounds);
+///     let Ok(drbg) = DrbgRng::<HmacDrbg, _>::new(jitter, b"example");
+///     let mut rng = UnwrapErr(drbg.with_reseed_interval(1 << 10));
+///     let mut buf = [0u8; 64];
+///     rng.fill_bytes(&mut buf);
+/// }
+/// # }
+/// ```
+///
+/// [`new`]: DrbgRng::new
+/// [`generate`]: DrbgRng::generate
+/// [`reseed`]: DrbgRng::reseed
+/// [`with_prediction_resistance`]: DrbgRng::with_prediction_resistance
+/// [`UnwrapErr`]: rand_core::UnwrapErr
+/// [`RngCore`]: rand_core::RngCore
+pub struct DrbgRng<D, E> {
+    drbg: D,
+    source: E,
+    prediction_resistance: bool,
+}
+
+// Custom Debug implementation that does not expose the internal state
+impl<D, E> fmt::Debug for DrbgRng<D, E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DrbgRng {{}}")
+    }
+}
+
+impl<D: Drbg, E: EntropySource> DrbgRng<D, E> {
+    /// Instantiate a DRBG with entropy input and a nonce from `source`, and
+    /// an optional personalization string.
+    ///
+    /// The entropy input and nonce are taken from the source in a single
+    /// request, as allowed by SP 800-90A section 8.6.7.
+    pub fn new(mut source: E, personalization_string: &[u8]) -> Result<Self, E::Error> {
+        let len = D::SECURITY_STRENGTH + D::SECURITY_STRENGTH / 2;
+        let mut seed = [0; MAX_SEED_LEN];
+        source.fill_entropy(&mut seed[..len])?;
+        let (entropy_input, nonce) = seed[..len].split_at(D::SECURITY_STRENGTH);
+        Ok(DrbgRng {
+            drbg: D::instantiate(entropy_input, nonce, personalization_string),
+            source,
+            prediction_resistance: false,
+        })
+    }
+
+    /// Set the number of generate requests after which the DRBG reseeds.
+    ///
+    /// The default is [`MAX_RESEED_INTERVAL`].
+    ///
+    /// # Panics
+    ///
+    /// If `interval` is 0 or larger than [`MAX_RESEED_INTERVAL`].
+    ///
+    /// [`MAX_RESEED_INTERVAL`]: crate::MAX_RESEED_INTERVAL
+    pub fn with_reseed_interval(mut self, interval: u64) -> Self {
+        self.drbg.set_reseed_interval(interval);
+        self
+    }
+
+    /// Reseed before every generate request, so that its output does not
+    /// depend on the state before the request.
+    ///
+    /// This provides prediction resistance: a compromise of the state does
+    /// not reveal later output. It requires an entropy source that is always
+    /// available, and costs a reseed for every request.
+    pub fn with_prediction_resistance(mut self) -> Self {
+        self.prediction_resistance = true;
+        self
+    }
+
+    /// Reseed the DRBG with entropy input from the source, and optional
+    /// additional input.
+    pub fn reseed(&mut self, additional_input: &[u8]) -> Result<(), E::Error> {
+        let mut entropy_input = [0; MAX_SEED_LEN];
+        let entropy_input = &mut entropy_input[..D::SECURITY_STRENGTH];
+        self.source.fill_entropy(entropy_input)?;
+        self.drbg.reseed(entropy_input, additional_input);
+        Ok(())
+    }
+
+    /// Fill `out` with pseudorandom bytes, with optional additional input,
+    /// reseeding first if necessary.
+    ///
+    /// When the DRBG reseeds, the additional input is used for the reseed
+    /// instead of the generate request.
+    ///
+    /// # Panics
+    ///
+    /// If `out` is longer than [`MAX_REQUEST_LEN`].
+    ///
+    /// [`MAX_REQUEST_LEN`]: crate::MAX_REQUEST_LEN
+    pub fn generate(
+        &mut self,
+        out: &mut [u8],
+        mut additional_input: &[u8],
+    ) -> Result<(), E::Error> {
+        if self.prediction_resistance {
+            self.reseed(additional_input)?;
+            additional_input = &[];
+        }
+        if self.drbg.generate(out, additional_input).is_err() {
+            self.reseed(additional_input)?;
+            // The request cannot fail right after a reseed.
+            self.drbg.generate(out, &[]).unwrap();
+        }
+        Ok(())
+    }
+}
+
+impl<D: Drbg, E: EntropySource> TryRngCore for DrbgRng<D, E> {
+    type Error = E::Error;
+
+    #[inline]
+    fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+        let mut buf = [0; 4];
+        self.generate(&mut buf, &[])?;
+        Ok(u32::from_le_bytes(buf))
+    }
+
+    #[inline]
+    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+        let mut buf = [0; 8];
+        self.generate(&mut buf, &[])?;
+        Ok(u64::from_le_bytes(buf))
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Self::Error> {
+        for chunk in dest.chunks_mut(MAX_REQUEST_LEN) {
+            self.generate(chunk, &[])?;
+        }
+        Ok(())
+    }
+}
+
+impl<D: Drbg, E: EntropySource> TryCryptoRng for DrbgRng<D, E> {}
+
+#[cfg(test)]
+mod test {
+    use super::DrbgRng;
+    use crate::{CtrDrbgAes128, Drbg, EntropySource, HmacDrbg};
+    use rand_core::TryRngCore;
+
+    // Returns the bytes 0, 1, 2, ..., and fails after `limit` bytes.
+    struct Counter {
+        next: u8,
+        limit: usize,
+    }
+
+    impl Counter {
+        fn new(limit: usize) -> Self {
+            Counter { next: 0, limit }
+        }
+    }
+
+    impl EntropySource for Counter {
+        type Error = &'static str;
+
+        fn fill_entropy(&mut self, dest: &mut [u8]) -> Result<(), Self::Error> {
+            if dest.len() > self.limit {
+                return Err("out of entropy");
+            }
+            self.limit -= dest.len();
+            for b in dest {
+                *b = self.next;
+                self.next = self.next.wrapping_add(1);
+            }
+            Ok(())
+        }
+    }
+
+    fn counter_bytes<const N: usize>(start: u8) -> [u8; N] {
+        core::array::from_fn(|i| start.wrapping_add(i as u8))
+    }
+
+    #[test]
+    fn test_drbg_rng_instantiate() {
+        // The entropy input and nonce are consecutive bytes of the source.
+        let mut rng = DrbgRng::<HmacDrbg, _>::new(Counter::new(48), b"pers").unwrap();
+        let mut drbg =
+            HmacDrbg::instantiate(&counter_bytes::<32>(0), &counter_bytes::<16>(32), b"pers");
+
+        let (mut a, mut b) = ([0u8; 40], [0u8; 40]);
+        rng.generate(&mut a, b"add").unwrap();
+        drbg.generate(&mut b, b"add").unwrap();
+        assert_eq!(a, b);
+
+        assert_eq!(
+            DrbgRng::<HmacDrbg, _>::new(Counter::new(47), &[]).unwrap_err(),
+            "out of entropy"
+        );
+    }
+
+    #[test]
+    fn test_drbg_rng_reseed_interval() {
+        let mut rng = DrbgRng::<CtrDrbgAes128, _>::new(Counter::new(40), &[])
+            .unwrap()
+            .with_reseed_interval(2);
+        let mut drbg =
+            CtrDrbgAes128::instantiate(&counter_bytes::<16>(0), &counter_bytes::<8>(16), &[]);
+
+        let (mut a, mut b) = ([0u8; 16], [0u8; 16]);
+        for _ in 0..2 {
+            rng.generate(&mut a, b"add").unwrap();
+            drbg.generate(&mut b, b"add").unwrap();
+            assert_eq!(a, b);
+        }
+        // The third request reseeds with the additional input.
+        rng.generate(&mut a, b"add").unwrap();
+        drbg.reseed(&counter_bytes::<16>(24), b"add");
+        drbg.generate(&mut b, &[]).unwrap();
+        assert_eq!(a, b);
+
+        // The source is exhausted by the next reseed.
+        rng.generate(&mut a, &[]).unwrap();
+        assert_eq!(rng.generate(&mut a, &[]), Err("out of entropy"));
+    }
+
+    #[test]
+    fn test_drbg_rng_prediction_resistance() {
+        let mut rng = DrbgRng::<HmacDrbg, _>::new(Counter::new(usize::MAX), &[])
+            .unwrap()
+            .with_prediction_resistance();
+        let mut drbg =
+            HmacDrbg::instantiate(&counter_bytes::<32>(0), &counter_bytes::<16>(32), &[]);
+
+        let (mut a, mut b) = ([0u8; 32], [0u8; 32]);
+        for i in 0..3 {
+            rng.generate(&mut a, b"add").unwrap();
+            drbg.reseed(&counter_bytes::<32>(48 + 32 * i), b"add");
+            drbg.generate(&mut b, &[]).unwrap();
+            assert_eq!(a, b);
+        }
+    }
+
+    #[test]
+    fn test_drbg_rng_try_rng_core() {
+        let mut rng = DrbgRng::<HmacDrbg, _>::new(Counter::new(48), &[]).unwrap();
+        let mut drbg =
+            HmacDrbg::instantiate(&counter_bytes::<32>(0), &counter_bytes::<16>(32), &[]);
+
+        let mut buf = [0u8; 8];
+        drbg.generate(&mut buf[..4], &[]).unwrap();
+        assert_eq!(
+            rng.try_next_u32(),
+            Ok(u32::from_le_bytes(buf[..4].try_into().unwrap()))
+        );
+        drbg.generate(&mut buf, &[]).unwrap();
+        assert_eq!(rng.try_next_u64(), Ok(u64::from_le_bytes(buf)));
+
+        // Large requests are split up into separate generate requests.
+        let mut a = [0u8; crate::MAX_REQUEST_LEN + 10];
+        rng.try_fill_bytes(&mut a).unwrap();
+        let mut b = [0u8; crate::MAX_REQUEST_LEN];
+        drbg.generate(&mut b, &[]).unwrap();
+        assert_eq!(a[..crate::MAX_REQUEST_LEN], b);
+        drbg.generate(&mut b[..10], &[]).unwrap();
+        assert_eq!(a[crate::MAX_REQUEST_LEN..], b[..10]);
+    }
+
+    #[cfg(feature = "jitter")]
+    #[test]
+    #[cfg_attr(miri, ignore)] // too slow
+    fn test_drbg_rng_jitter() {
+        use crate::CtrDrbgAes256;
+        use rand_core::{RngCore, UnwrapErr};
+        use rand_jitter::{JitterRng, testing};
+
+        let mut jitter = JitterRng::new_with_timer(testing::gaussian(1000.0, 100.0, 1));
+        let rounds = jitter.test_timer().unwrap();
+        jitter.set_rounds(rounds);
+        let drbg = DrbgRng::<CtrDrbgAes256, _>::new(jitter, b"jitter").unwrap();
+        let mut rng = UnwrapErr(drbg.with_prediction_resistance());
+        let mut buf = [0u8; 64];
+        rng.fill_bytes(&mut buf);
+        assert_ne!(buf, [0; 64]);
+    }
+}
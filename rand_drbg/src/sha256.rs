@@ -0,0 +1,234 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! SHA-256 (FIPS 180-4) and HMAC-SHA-256 (FIPS 198-1), for `HmacDrbg`.
+
+const BLOCK_LEN: usize = 64;
+pub(crate) const OUT_LEN: usize = 32;
+
+// The first 32 bits of the fractional parts of the cube roots of the first
+// 64 primes
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+// The first 32 bits of the fractional parts of the square roots of the first
+// 8 primes
+const H0: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// The state of a SHA-256 computation.
+#[derive(Clone)]
+pub(crate) struct Sha256 {
+    state: [u32; 8],
+    buf: [u8; BLOCK_LEN],
+    buf_len: usize,
+    // The length of the message so far, in bytes
+    len: u64,
+}
+
+impl Sha256 {
+    pub(crate) fn new() -> Self {
+        Sha256 {
+            state: H0,
+            buf: [0; BLOCK_LEN],
+            buf_len: 0,
+            len: 0,
+        }
+    }
+
+    pub(crate) fn update(&mut self, mut data: &[u8]) {
+        self.len = self.len.wrapping_add(data.len() as u64);
+        if self.buf_len > 0 {
+            let n = data.len().min(BLOCK_LEN - self.buf_len);
+            self.buf[self.buf_len..self.buf_len + n].copy_from_slice(&data[..n]);
+            self.buf_len += n;
+            data = &data[n..];
+            if self.buf_len < BLOCK_LEN {
+                return;
+            }
+            compress(&mut self.state, &self.buf);
+            self.buf_len = 0;
+        }
+        let mut blocks = data.chunks_exact(BLOCK_LEN);
+        for block in &mut blocks {
+            compress(&mut self.state, block.try_into().unwrap());
+        }
+        let rest = blocks.remainder();
+        self.buf[..rest.len()].copy_from_slice(rest);
+        self.buf_len = rest.len();
+    }
+
+    pub(crate) fn finalize(mut self) -> [u8; OUT_LEN] {
+        let bits = self.len.wrapping_mul(8);
+        // Pad with a 1 bit, zeros, and the length in bits, to a multiple of
+        // the block length.
+        let zeros = (BLOCK_LEN + BLOCK_LEN - 9 - self.buf_len) % BLOCK_LEN;
+        self.update(&[0x80]);
+        self.update(&[0; BLOCK_LEN][..zeros]);
+        self.update(&bits.to_be_bytes());
+        debug_assert_eq!(self.buf_len, 0);
+
+        let mut out = [0; OUT_LEN];
+        for (chunk, s) in out.chunks_exact_mut(4).zip(self.state) {
+            chunk.copy_from_slice(&s.to_be_bytes());
+        }
+        out
+    }
+}
+
+fn compress(state: &mut [u32; 8], block: &[u8; BLOCK_LEN]) {
+    let mut w = [0u32; 64];
+    for (w, chunk) in w.iter_mut().zip(block.chunks_exact(4)) {
+        *w = u32::from_be_bytes(chunk.try_into().unwrap());
+    }
+    for i in 16..64 {
+        let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+        let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+        w[i] = w[i - 16]
+            .wrapping_add(s0)
+            .wrapping_add(w[i - 7])
+            .wrapping_add(s1);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+    for (&k, &w) in K.iter().zip(w.iter()) {
+        let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+        let ch = (e & f) ^ (!e & g);
+        let t1 = h
+            .wrapping_add(s1)
+            .wrapping_add(ch)
+            .wrapping_add(k)
+            .wrapping_add(w);
+        let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+        let maj = (a & b) ^ (a & c) ^ (b & c);
+        let t2 = s0.wrapping_add(maj);
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(t1);
+        d = c;
+        c = b;
+        b = a;
+        a = t1.wrapping_add(t2);
+    }
+    for (s, x) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+        *s = s.wrapping_add(x);
+    }
+}
+
+/// HMAC-SHA-256 with a fixed key.
+///
+/// This keeps the hash states after the inner and outer padded key, so that
+/// they are only computed once per key.
+#[derive(Clone)]
+pub(crate) struct Hmac {
+    inner: Sha256,
+    outer: Sha256,
+}
+
+impl Hmac {
+    pub(crate) fn new(key: &[u8]) -> Self {
+        let mut padded = [0; BLOCK_LEN];
+        if key.len() > BLOCK_LEN {
+            let mut h = Sha256::new();
+            h.update(key);
+            padded[..OUT_LEN].copy_from_slice(&h.finalize());
+        } else {
+            padded[..key.len()].copy_from_slice(key);
+        }
+
+        let mut inner = Sha256::new();
+        inner.update(&padded.map(|b| b ^ 0x36));
+        let mut outer = Sha256::new();
+        outer.update(&padded.map(|b| b ^ 0x5c));
+        Hmac { inner, outer }
+    }
+
+    /// The MAC of the concatenation of `parts`.
+    pub(crate) fn mac<'a>(&self, parts: impl IntoIterator<Item = &'a [u8]>) -> [u8; OUT_LEN] {
+        let mut inner = self.inner.clone();
+        for part in parts {
+            inner.update(part);
+        }
+        let mut outer = self.outer.clone();
+        outer.update(&inner.finalize());
+        outer.finalize()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Hmac, Sha256};
+
+    fn from_hex<const N: usize>(hex: &str) -> [u8; N] {
+        let mut bytes = [0u8; N];
+        for (b, i) in bytes.iter_mut().zip((0..hex.len()).step_by(2)) {
+            *b = u8::from_str_radix(&hex[i..i + 2], 16).unwrap();
+        }
+        bytes
+    }
+
+    fn sha256(parts: &[&[u8]]) -> [u8; 32] {
+        let mut h = Sha256::new();
+        for part in parts {
+            h.update(part);
+        }
+        h.finalize()
+    }
+
+    #[test]
+    fn test_sha256_true_values() {
+        // The one-block and two-block examples of FIPS 180-2, appendix B
+        let expected: [u8; 32] =
+            from_hex("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+        assert_eq!(sha256(&[b"abc"]), expected);
+
+        let expected: [u8; 32] =
+            from_hex("248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1");
+        let message = b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq";
+        assert_eq!(sha256(&[message]), expected);
+        // The same message, given in pieces
+        assert_eq!(
+            sha256(&[
+                &message[..3],
+                &message[3..3],
+                &message[3..50],
+                &message[50..]
+            ]),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_hmac_true_values() {
+        // RFC 4231, test case 2
+        let hmac = Hmac::new(b"Jefe");
+        let expected: [u8; 32] =
+            from_hex("5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843");
+        assert_eq!(
+            hmac.mac([&b"what do ya want "[..], b"for nothing?"]),
+            expected
+        );
+
+        // RFC 4231, test case 6: a key longer than the block
+        let hmac = Hmac::new(&[0xaa; 131]);
+        let expected: [u8; 32] =
+            from_hex("60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54");
+        let message = b"Test Using Larger Than Block-Size Key - Hash Key First";
+        assert_eq!(hmac.mac([&message[..]]), expected);
+    }
+}
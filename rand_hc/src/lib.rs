@@ -6,7 +6,22 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-//! The HC128 random number generator.
+//! The HC128 random number generator, and the other members of the software
+//! profile of the eSTREAM portfolio.
+//!
+//! This crate provides:
+//! - [`Hc128Rng`]: uses the HC-128 stream cipher. Very fast, but has a large
+//!   state of 4kb.
+//! - [`RabbitRng`]: uses the Rabbit stream cipher, with a 128-bit key and an
+//!   optional 64-bit IV.
+//! - [`Salsa12Rng`]: uses the Salsa20/12 stream cipher, with a 128-bit or
+//!   256-bit key and a 64-bit IV. Supports seeking to any position of the
+//!   stream.
+//! - [`SosemanukRng`]: uses the Sosemanuk stream cipher, with a 128-bit to
+//!   256-bit key and a 128-bit IV.
+//!
+//! All of them are cryptographically secure, and reproduce the keystream of
+//! the cipher for the same key and IV.
 //!
 //! To initialize a generator, use the [`SeedableRng`][rand_core::SeedableRng] trait.
 
@@ -21,5 +36,11 @@
 #![no_std]
 
 mod hc128;
+mod rabbit;
+mod salsa12;
+mod sosemanuk;
 
 pub use hc128::{Hc128Core, Hc128Rng};
+pub use rabbit::{RabbitCore, RabbitRng};
+pub use salsa12::{Salsa12Core, Salsa12Rng};
+pub use sosemanuk::{SosemanukCore, SosemanukRng};
@@ -0,0 +1,349 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Salsa20/12 random number generator.
+
+use core::fmt;
+use rand_core::block::{BlockRng, CryptoGenerator, Generator};
+use rand_core::{CryptoRng, RngCore, SeedableRng, utils};
+
+// "expand 32-byte k" and "expand 16-byte k"
+const SIGMA: [u32; 4] = [0x61707865, 0x3320646e, 0x79622d32, 0x6b206574];
+const TAU: [u32; 4] = [0x61707865, 0x3120646e, 0x79622d36, 0x6b206574];
+
+const DOUBLE_ROUNDS: usize = 6;
+
+const BLOCK_WORDS: usize = 16;
+// The stream is 2^64 blocks of 16 words long
+const WORD_POS_MASK: u128 = (1 << 68) - 1;
+
+/// A cryptographically secure random number generator that uses the
+/// Salsa20/12 algorithm.
+///
+/// Salsa20 is a stream cipher designed by Daniel J. Bernstein[^1]. Its
+/// variant reduced to 12 rounds, Salsa20/12, is part of the software profile
+/// of the eSTREAM portfolio[^2]. The best known attacks break 8 rounds[^3],
+/// which leaves a comfortable security margin.
+///
+/// Salsa20 encrypts a 64-bit block counter with a 256-bit or 128-bit key and a
+/// 64-bit nonce (the IV). Every block gives sixteen `u32` words, so the
+/// stream of a single key and IV is 2<sup>68</sup> words long. Because the
+/// block function is independent for every counter value, the generator can
+/// jump to any position in the stream in constant time with
+/// [`set_word_pos`]. [ChaCha] is a later variant of this design.
+///
+/// The seed of [`SeedableRng`] is a 256-bit key, and the IV is zero. Use
+/// [`Salsa12Rng::new`] or [`Salsa12Rng::new_128`] to give an IV as well.
+///
+/// This implementation uses an output buffer of sixteen `u32` words, and uses
+/// [`BlockRng`] to implement the [`RngCore`] methods.
+///
+/// ## References
+/// [^1]: Daniel J. Bernstein (2008). ["The Salsa20 family of stream ciphers"](
+///       https://cr.yp.to/snuffle/salsafamily-20071225.pdf).
+///       *The eSTREAM Finalists*, LNCS 4986, pp. 84–97, Springer-Verlag.
+///
+/// [^2]: [eSTREAM: the ECRYPT Stream Cipher Project](
+///       http://www.ecrypt.eu.org/stream/)
+///
+/// [^3]: Jean-Philippe Aumasson, Simon Fischer, Shahram Khazaei, Willi Meier
+///       and Christian Rechberger (2008). ["New Features of Latin Dances:
+///       Analysis of Salsa, ChaCha, and Rumba"](
+///       https://eprint.iacr.org/2007/472).
+///       *Fast Software Encryption*, LNCS 5086, pp. 470–488, Springer-Verlag.
+///
+/// [ChaCha]: https://docs.rs/rand_chacha
+/// [`set_word_pos`]: Salsa12Rng::set_word_pos
+#[derive(Clone, Debug)]
+pub struct Salsa12Rng(BlockRng<Salsa12Core>);
+
+impl Salsa12Rng {
+    /// Create a generator from a 256-bit `key` and a 64-bit `iv`.
+    ///
+    /// The output is the keystream of Salsa20/12 with the same key and IV.
+    pub fn new(key: [u8; 32], iv: [u8; 8]) -> Self {
+        let key: [u32; 8] = utils::read_words(&key);
+        let (k1, k2) = key.split_at(4);
+        Salsa12Rng(BlockRng::new(Salsa12Core::init(SIGMA, k1, k2, iv)))
+    }
+
+    /// Create a generator from a 128-bit `key` and a 64-bit `iv`.
+    ///
+    /// The output is the keystream of Salsa20/12 with the same key and IV.
+    pub fn new_128(key: [u8; 16], iv: [u8; 8]) -> Self {
+        let key: [u32; 4] = utils::read_words(&key);
+        Salsa12Rng(BlockRng::new(Salsa12Core::init(TAU, &key, &key, iv)))
+    }
+
+    /// Get the offset from the start of the stream, in 32-bit words.
+    ///
+    /// The position wraps around at 2<sup>68</sup>, the length of the stream.
+    pub fn get_word_pos(&self) -> u128 {
+        let block = u128::from(self.0.core.counter()) * BLOCK_WORDS as u128;
+        let buffered = BLOCK_WORDS.saturating_sub(self.0.index());
+        block.wrapping_sub(buffered as u128) & WORD_POS_MASK
+    }
+
+    /// Set the offset from the start of the stream, in 32-bit words.
+    ///
+    /// Only the lower 68 bits of `word_offset` are used, as the stream is
+    /// 2<sup>68</sup> words long.
+    pub fn set_word_pos(&mut self, word_offset: u128) {
+        let block = (word_offset >> 4) as u64;
+        let index = (word_offset % BLOCK_WORDS as u128) as usize;
+        self.0.core.set_counter(block);
+        self.0.generate_and_set(index);
+    }
+}
+
+impl RngCore for Salsa12Rng {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_word()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64_from_u32()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+}
+
+impl SeedableRng for Salsa12Rng {
+    type Seed = <Salsa12Core as SeedableRng>::Seed;
+
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Self {
+        Salsa12Rng(BlockRng::new(Salsa12Core::from_seed(seed)))
+    }
+}
+
+impl CryptoRng for Salsa12Rng {}
+
+impl PartialEq for Salsa12Rng {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.get_word_pos() == rhs.get_word_pos() && self.0.core == rhs.0.core
+    }
+}
+impl Eq for Salsa12Rng {}
+
+/// The core of `Salsa12Rng`, used with `BlockRng`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Salsa12Core {
+    // The input block: the constants, key, IV and block counter.
+    state: [u32; 16],
+}
+
+// Custom Debug implementation that does not expose the internal state
+impl fmt::Debug for Salsa12Core {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Salsa12Core {{}}")
+    }
+}
+
+impl Generator for Salsa12Core {
+    type Output = [u32; 16];
+
+    fn generate(&mut self, results: &mut Self::Output) {
+        #[inline(always)]
+        fn quarter_round(x: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+            x[b] ^= x[a].wrapping_add(x[d]).rotate_left(7);
+            x[c] ^= x[b].wrapping_add(x[a]).rotate_left(9);
+            x[d] ^= x[c].wrapping_add(x[b]).rotate_left(13);
+            x[a] ^= x[d].wrapping_add(x[c]).rotate_left(18);
+        }
+
+        let mut x = self.state;
+        for _ in 0..DOUBLE_ROUNDS {
+            // Column round
+            quarter_round(&mut x, 0, 4, 8, 12);
+            quarter_round(&mut x, 5, 9, 13, 1);
+            quarter_round(&mut x, 10, 14, 2, 6);
+            quarter_round(&mut x, 15, 3, 7, 11);
+            // Row round
+            quarter_round(&mut x, 0, 1, 2, 3);
+            quarter_round(&mut x, 5, 6, 7, 4);
+            quarter_round(&mut x, 10, 11, 8, 9);
+            quarter_round(&mut x, 15, 12, 13, 14);
+        }
+        for ((r, x), s) in results.iter_mut().zip(x.iter()).zip(self.state.iter()) {
+            *r = x.wrapping_add(*s);
+        }
+
+        self.set_counter(self.counter().wrapping_add(1));
+    }
+}
+
+impl Salsa12Core {
+    fn init(constants: [u32; 4], k1: &[u32], k2: &[u32], iv: [u8; 8]) -> Self {
+        let iv: [u32; 2] = utils::read_words(&iv);
+        let mut state = [0u32; 16];
+        state[0] = constants[0];
+        state[1..5].copy_from_slice(k1);
+        state[5] = constants[1];
+        state[6..8].copy_from_slice(&iv);
+        // state[8..10] is the block counter
+        state[10] = constants[2];
+        state[11..15].copy_from_slice(k2);
+        state[15] = constants[3];
+        Self { state }
+    }
+
+    #[inline]
+    fn counter(&self) -> u64 {
+        u64::from(self.state[8]) | (u64::from(self.state[9]) << 32)
+    }
+
+    #[inline]
+    fn set_counter(&mut self, counter: u64) {
+        self.state[8] = counter as u32;
+        self.state[9] = (counter >> 32) as u32;
+    }
+}
+
+impl SeedableRng for Salsa12Core {
+    type Seed = [u8; 32];
+
+    /// Create a Salsa20/12 random number generator with a 256-bit key as
+    /// seed, and an IV of zero.
+    fn from_seed(seed: Self::Seed) -> Self {
+        let key: [u32; 8] = utils::read_words(&seed);
+        let (k1, k2) = key.split_at(4);
+        Self::init(SIGMA, k1, k2, [0; 8])
+    }
+}
+
+impl CryptoGenerator for Salsa12Core {}
+
+#[cfg(test)]
+mod test {
+    use super::Salsa12Rng;
+    use ::rand_core::{RngCore, SeedableRng};
+
+    fn from_hex<const N: usize>(hex: &str) -> [u8; N] {
+        let mut bytes = [0u8; N];
+        for (b, i) in bytes.iter_mut().zip((0..hex.len()).step_by(2)) {
+            *b = u8::from_str_radix(&hex[i..i + 2], 16).unwrap();
+        }
+        bytes
+    }
+
+    #[test]
+    // Set 1, vector 0 of the eSTREAM test vectors for Salsa20/12 with a
+    // 256-bit key
+    fn test_salsa12_true_values_a() {
+        let mut rng = Salsa12Rng::from_seed(from_hex(
+            "8000000000000000000000000000000000000000000000000000000000000000",
+        ));
+        let mut results = [0u8; 64];
+        rng.fill_bytes(&mut results);
+        let expected: [u8; 64] = from_hex(
+            "AFE411ED1C4E07E4D0CDE3B33E31EC190FA4CC796A58BAFB848EAD8D07D02CD2\
+             D4B6F9F30CB0B57007E3733895CC8D1060107975ACAEEB689B6CF614AB64A3D6",
+        );
+        assert_eq!(results, expected);
+
+        // Stream[448..511]
+        rng.set_word_pos(448 / 4);
+        rng.fill_bytes(&mut results);
+        let expected: [u8; 64] = from_hex(
+            "87A5191EC2E3C9049FA524CD8673E0677C77ADCF8AB5328FD828C4ACB3ECCCA5\
+             49ADEDA04872518ECDF874ADCB2420C7BD1CCFE561B074080224FA7176F0CB5F",
+        );
+        assert_eq!(results, expected);
+    }
+
+    #[test]
+    // Set 6, vector 0 of the eSTREAM test vectors for Salsa20/12 with a
+    // 256-bit key
+    fn test_salsa12_true_values_b() {
+        let mut rng = Salsa12Rng::new(
+            from_hex("0053A6F94C9FF24598EB3E91E4378ADD3083D6297CCF2275C81B6EC11467BA0D"),
+            from_hex("0D74DB42A91077DE"),
+        );
+        let mut results = [0u8; 64];
+        rng.fill_bytes(&mut results);
+        let expected: [u8; 64] = from_hex(
+            "52E20CF8775AE882F200C2999FE4BA31A7A18F1D5C9716191D123175E147BD4E\
+             8CA6ED166CE0FC8E65A5CA608420FC6544C9700A0F2138E8C1A286FB8C1FBFA0",
+        );
+        assert_eq!(results, expected);
+
+        // Stream[192..255]
+        rng.set_word_pos(192 / 4);
+        rng.fill_bytes(&mut results);
+        let expected: [u8; 64] = from_hex(
+            "BA7A557C442AED7665813A4919C533AB9C6DE02534EFA290416CA65C21C71253\
+             C89C7C36723A8EDAB17DB6D976A8967847CD4EFCF1673F2C8487AF2805E99C1C",
+        );
+        assert_eq!(results, expected);
+    }
+
+    #[test]
+    // Set 1, vector 0 of the eSTREAM test vectors for Salsa20/12 with a
+    // 128-bit key
+    fn test_salsa12_true_values_128() {
+        let mut rng = Salsa12Rng::new_128(from_hex("80000000000000000000000000000000"), [0; 8]);
+        let mut results = [0u8; 64];
+        rng.fill_bytes(&mut results);
+        let expected: [u8; 64] = from_hex(
+            "FC207DBFC76C5E1774961E7A5AAD09069B2225AC1CE0FE7A0CE77003E7E5BDF8\
+             B31AF821000813E6C56B8C1771D6EE7039B2FBD0A68E8AD70A3944B677937897",
+        );
+        assert_eq!(results, expected);
+
+        // Stream[256..319]
+        rng.set_word_pos(256 / 4);
+        rng.fill_bytes(&mut results);
+        let expected: [u8; 64] = from_hex(
+            "F52383D9DEFB20810325F7AEC9EADE34D9D883FEE37E05F74BF40875B2D0BE79\
+             ED8886E5BFF556CEA8D1D9E86B1F68A964598C34F177F8163E271B8D2FEB5996",
+        );
+        assert_eq!(results, expected);
+    }
+
+    #[test]
+    fn test_salsa12_word_pos() {
+        let mut rng = Salsa12Rng::seed_from_u64(0);
+        let mut a = [0u32; 40];
+        for x in a.iter_mut() {
+            *x = rng.next_u32();
+        }
+        assert_eq!(rng.get_word_pos(), 40);
+
+        rng.set_word_pos(21);
+        assert_eq!(rng.get_word_pos(), 21);
+        assert_eq!(rng.next_u32(), a[21]);
+        assert_eq!(rng.next_u64(), u64::from(a[22]) | (u64::from(a[23]) << 32));
+        assert_eq!(rng.get_word_pos(), 24);
+
+        rng.set_word_pos(16);
+        assert_eq!(rng.get_word_pos(), 16);
+        assert_eq!(rng.next_u32(), a[16]);
+
+        // The position wraps around at the end of the stream
+        rng.set_word_pos((1 << 68) - 1);
+        rng.next_u32();
+        assert_eq!(rng.get_word_pos(), 0);
+        assert_eq!(rng.next_u32(), a[0]);
+    }
+
+    #[test]
+    fn test_salsa12_clone() {
+        let mut rng1 = Salsa12Rng::new([0x55; 32], [1; 8]);
+        let mut rng2 = rng1.clone();
+        for _ in 0..20 {
+            assert_eq!(rng1.next_u32(), rng2.next_u32());
+        }
+        assert_eq!(rng1, rng2);
+    }
+}
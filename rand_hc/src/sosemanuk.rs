@@ -0,0 +1,454 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Sosemanuk random number generator.
+
+use core::fmt;
+use rand_core::block::{BlockRng, CryptoGenerator, Generator};
+use rand_core::{CryptoRng, RngCore, SeedableRng, utils};
+
+const SEED_WORDS: usize = 8; // 128 bit key followed by 128 bit iv
+
+// The Serpent S-boxes, used in the key schedule and the IV setup
+const SBOX: [[u8; 16]; 8] = [
+    [3, 8, 15, 1, 10, 6, 5, 11, 14, 13, 4, 2, 7, 0, 9, 12],
+    [15, 12, 2, 7, 9, 0, 5, 10, 1, 11, 14, 8, 6, 13, 3, 4],
+    [8, 6, 7, 9, 3, 12, 10, 15, 13, 1, 14, 4, 0, 11, 5, 2],
+    [0, 15, 11, 8, 12, 9, 6, 3, 13, 1, 2, 4, 10, 7, 5, 14],
+    [1, 15, 8, 3, 12, 0, 11, 6, 2, 5, 4, 10, 9, 14, 7, 13],
+    [15, 5, 2, 11, 4, 10, 9, 12, 0, 3, 14, 8, 13, 6, 7, 1],
+    [7, 2, 12, 5, 8, 4, 6, 11, 14, 9, 1, 15, 13, 3, 10, 0],
+    [1, 13, 15, 0, 14, 8, 2, 11, 7, 4, 12, 10, 9, 3, 5, 6],
+];
+
+// Multiplication in GF(2^8), defined by the polynomial X^8+X^7+X^5+X^3+1
+const fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut r = 0;
+    while b != 0 {
+        if b & 1 != 0 {
+            r ^= a;
+        }
+        let carry = a & 0x80 != 0;
+        a <<= 1;
+        if carry {
+            a ^= 0xA9;
+        }
+        b >>= 1;
+    }
+    r
+}
+
+// Powers of the root β of the GF(2^8) polynomial
+const fn beta_pow(e: u32) -> u8 {
+    let mut r = 1;
+    let mut i = 0;
+    while i < e {
+        r = gf_mul(r, 2);
+        i += 1;
+    }
+    r
+}
+
+// Tables for the multiplication with an element `c` of GF(2^8) of the four
+// coefficients, given as powers of β
+const fn mul_table(e: [u32; 4]) -> [u32; 256] {
+    let b = [
+        beta_pow(e[0]),
+        beta_pow(e[1]),
+        beta_pow(e[2]),
+        beta_pow(e[3]),
+    ];
+    let mut table = [0u32; 256];
+    let mut c = 0;
+    while c < 256 {
+        let x = c as u8;
+        table[c] = ((gf_mul(x, b[0]) as u32) << 24)
+            | ((gf_mul(x, b[1]) as u32) << 16)
+            | ((gf_mul(x, b[2]) as u32) << 8)
+            | (gf_mul(x, b[3]) as u32);
+        c += 1;
+    }
+    table
+}
+
+// The LFSR works in GF(2^32), an extension of GF(2^8) defined by the
+// polynomial X^4 + β^23 X^3 + β^245 X^2 + β^48 X + β^239 with root α.
+const MUL_ALPHA: [u32; 256] = mul_table([23, 245, 48, 239]);
+const DIV_ALPHA: [u32; 256] = mul_table([16, 39, 6, 64]);
+
+#[inline(always)]
+fn mul_alpha(x: u32) -> u32 {
+    (x << 8) ^ MUL_ALPHA[(x >> 24) as usize]
+}
+
+#[inline(always)]
+fn div_alpha(x: u32) -> u32 {
+    (x >> 8) ^ DIV_ALPHA[(x & 0xFF) as usize]
+}
+
+// Apply a Serpent S-box to 32 4-bit values in bitslice mode.
+fn sbox(table: &[u8; 16], x: [u32; 4]) -> [u32; 4] {
+    let mut out = [0u32; 4];
+    for bit in 0..32 {
+        let n = (((x[0] >> bit) & 1)
+            | (((x[1] >> bit) & 1) << 1)
+            | (((x[2] >> bit) & 1) << 2)
+            | (((x[3] >> bit) & 1) << 3)) as usize;
+        let v = u32::from(table[n]);
+        for (i, o) in out.iter_mut().enumerate() {
+            *o |= ((v >> i) & 1) << bit;
+        }
+    }
+    out
+}
+
+// The S-box S2 of Serpent in bitslice mode, as a constant-time Boolean
+// circuit, used for the output of Sosemanuk.
+#[inline(always)]
+fn sbox2(x: [u32; 4]) -> [u32; 4] {
+    let [mut r0, mut r1, mut r2, mut r3] = x;
+    let mut r4 = r0;
+    r0 &= r2;
+    r0 ^= r3;
+    r2 ^= r1;
+    r2 ^= r0;
+    r3 |= r4;
+    r3 ^= r1;
+    r4 ^= r2;
+    r1 = r3;
+    r3 |= r4;
+    r3 ^= r0;
+    r0 &= r1;
+    r4 ^= r0;
+    r1 ^= r3;
+    r1 ^= r4;
+    [r2, r3, r1, !r4]
+}
+
+// The linear transformation of Serpent
+fn serpent_lt(x: [u32; 4]) -> [u32; 4] {
+    let [mut x0, mut x1, mut x2, mut x3] = x;
+    x0 = x0.rotate_left(13);
+    x2 = x2.rotate_left(3);
+    x1 ^= x0 ^ x2;
+    x3 ^= x2 ^ (x0 << 3);
+    x1 = x1.rotate_left(1);
+    x3 = x3.rotate_left(7);
+    x0 ^= x1 ^ x3;
+    x2 ^= x3 ^ (x1 << 7);
+    x0 = x0.rotate_left(5);
+    x2 = x2.rotate_left(22);
+    [x0, x1, x2, x3]
+}
+
+/// A cryptographically secure random number generator that uses the
+/// Sosemanuk algorithm.
+///
+/// Sosemanuk is a stream cipher designed by Côme Berbain, Olivier Billet, Anne
+/// Canteaut, Nicolas Courtois, Henri Gilbert, Louis Goubin, Aline Gouget, Louis
+/// Granboulan, Cédric Lauradoux, Marine Minier, Thomas Pornin and Hervé
+/// Sibert[^1], and is part of the software profile of the eSTREAM
+/// portfolio[^2].
+///
+/// It combines a linear feedback shift register of ten 32-bit words with a
+/// small finite state machine, in the style of SNOW 2.0, and passes the result
+/// through the S-box S2 of the Serpent block cipher. The key schedule and the
+/// IV setup use a reduced version of Serpent with 24 rounds. The state is
+/// only 384 bits.
+///
+/// Sosemanuk accepts keys from 128 to 256 bits, and a 128-bit IV. The seed of
+/// [`SeedableRng`] is a 256-bit array, matching a 128 bit key concatenated
+/// with a 128 bit IV. Use [`SosemanukRng::new`] for other key lengths.
+///
+/// This implementation uses an output buffer of sixteen `u32` words, and uses
+/// [`BlockRng`] to implement the [`RngCore`] methods.
+///
+/// ## References
+/// [^1]: Côme Berbain et al. (2008). ["Sosemanuk, a fast software-oriented
+///       stream cipher"](https://arxiv.org/abs/0810.1858).
+///       *The eSTREAM Finalists*, LNCS 4986, pp. 98–118, Springer-Verlag.
+///
+/// [^2]: [eSTREAM: the ECRYPT Stream Cipher Project](
+///       http://www.ecrypt.eu.org/stream/)
+#[derive(Clone, Debug)]
+pub struct SosemanukRng(BlockRng<SosemanukCore>);
+
+impl SosemanukRng {
+    /// Create a generator from a `key` and a 128-bit `iv`.
+    ///
+    /// The output is the keystream of Sosemanuk with the same key and IV.
+    ///
+    /// # Panics
+    ///
+    /// If the length of `key` is not between 16 and 32 bytes.
+    pub fn new(key: &[u8], iv: [u8; 16]) -> Self {
+        assert!(
+            (16..=32).contains(&key.len()),
+            "Sosemanuk key must be 128 to 256 bits"
+        );
+        SosemanukRng(BlockRng::new(SosemanukCore::init(key, iv)))
+    }
+}
+
+impl RngCore for SosemanukRng {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_word()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64_from_u32()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+}
+
+impl SeedableRng for SosemanukRng {
+    type Seed = <SosemanukCore as SeedableRng>::Seed;
+
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Self {
+        SosemanukRng(BlockRng::new(SosemanukCore::from_seed(seed)))
+    }
+}
+
+impl CryptoRng for SosemanukRng {}
+
+impl PartialEq for SosemanukRng {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.0.core == rhs.0.core && self.0.index() == rhs.0.index()
+    }
+}
+impl Eq for SosemanukRng {}
+
+/// The core of `SosemanukRng`, used with `BlockRng`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SosemanukCore {
+    // The LFSR, with s[0] the oldest word
+    s: [u32; 10],
+    r1: u32,
+    r2: u32,
+}
+
+// Custom Debug implementation that does not expose the internal state
+impl fmt::Debug for SosemanukCore {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SosemanukCore {{}}")
+    }
+}
+
+impl Generator for SosemanukCore {
+    type Output = [u32; 16];
+
+    fn generate(&mut self, results: &mut Self::Output) {
+        for chunk in results.chunks_exact_mut(4) {
+            let mut f = [0u32; 4];
+            let mut v = [0u32; 4];
+            for (f, v) in f.iter_mut().zip(v.iter_mut()) {
+                (*f, *v) = self.step();
+            }
+            let z = sbox2(f);
+            for ((r, z), v) in chunk.iter_mut().zip(z.iter()).zip(v.iter()) {
+                *r = z ^ v;
+            }
+        }
+    }
+}
+
+impl SosemanukCore {
+    // One step of Sosemanuk: update the FSM and the LFSR. Returns the output
+    // of the FSM, and the word dropped from the LFSR.
+    #[inline(always)]
+    fn step(&mut self) -> (u32, u32) {
+        let s = &mut self.s;
+        let mux = if self.r1 & 1 == 0 { s[1] } else { s[1] ^ s[8] };
+        let r1 = self.r2.wrapping_add(mux);
+        self.r2 = self.r1.wrapping_mul(0x54655307).rotate_left(7);
+        self.r1 = r1;
+        let f = s[9].wrapping_add(self.r1) ^ self.r2;
+
+        let dropped = s[0];
+        let feedback = s[9] ^ div_alpha(s[3]) ^ mul_alpha(s[0]);
+        s.copy_within(1.., 0);
+        s[9] = feedback;
+        (f, dropped)
+    }
+
+    // Initialize Sosemanuk with a key of at most 32 bytes and a 128-bit IV.
+    fn init(key: &[u8], iv: [u8; 16]) -> Self {
+        // Keys shorter than 256 bits are padded with a single 1 bit, as in
+        // Serpent.
+        let mut padded = [0u8; 32];
+        padded[..key.len()].copy_from_slice(key);
+        if key.len() < 32 {
+            padded[key.len()] = 1;
+        }
+
+        // The Serpent key schedule, for the 25 subkeys of Serpent24
+        let mut w = [0u32; 108];
+        w[..8].copy_from_slice(&utils::read_words::<u32, 8>(&padded));
+        for i in 8..108 {
+            w[i] = (w[i - 8] ^ w[i - 5] ^ w[i - 3] ^ w[i - 1] ^ 0x9E3779B9 ^ (i as u32 - 8))
+                .rotate_left(11);
+        }
+        let mut subkeys = [[0u32; 4]; 25];
+        for (i, k) in subkeys.iter_mut().enumerate() {
+            let words = [w[8 + 4 * i], w[9 + 4 * i], w[10 + 4 * i], w[11 + 4 * i]];
+            *k = sbox(&SBOX[(35 - i) % 8], words);
+        }
+
+        // Encrypt the IV with Serpent24, keeping the outputs of rounds 12, 18
+        // and 24.
+        let mut x: [u32; 4] = utils::read_words(&iv);
+        let mut y12 = [0u32; 4];
+        let mut y18 = [0u32; 4];
+        for (round, k) in subkeys[..24].iter().enumerate() {
+            for (x, k) in x.iter_mut().zip(k.iter()) {
+                *x ^= k;
+            }
+            x = serpent_lt(sbox(&SBOX[round % 8], x));
+            match round + 1 {
+                12 => y12 = x,
+                18 => y18 = x,
+                _ => {}
+            }
+        }
+        for (x, k) in x.iter_mut().zip(subkeys[24].iter()) {
+            *x ^= k;
+        }
+
+        Self {
+            s: [
+                x[3], x[2], x[1], x[0], y18[1], y18[3], y12[3], y12[2], y12[1], y12[0],
+            ],
+            r1: y18[0],
+            r2: y18[2],
+        }
+    }
+}
+
+impl SeedableRng for SosemanukCore {
+    type Seed = [u8; SEED_WORDS * 4];
+
+    /// Create a Sosemanuk random number generator with a seed. The seed has to
+    /// be 256 bits in length, matching the 128 bit `key` followed by 128 bit
+    /// `iv` when Sosemanuk were to be used as a stream cipher.
+    fn from_seed(seed: Self::Seed) -> Self {
+        let (key, iv) = seed.split_at(16);
+        Self::init(key, iv.try_into().unwrap())
+    }
+}
+
+impl CryptoGenerator for SosemanukCore {}
+
+#[cfg(test)]
+mod test {
+    use super::SosemanukRng;
+    use ::rand_core::{RngCore, SeedableRng};
+
+    fn from_hex<const N: usize>(hex: &str) -> [u8; N] {
+        let mut bytes = [0u8; N];
+        for (b, i) in bytes.iter_mut().zip((0..hex.len()).step_by(2)) {
+            *b = u8::from_str_radix(&hex[i..i + 2], 16).unwrap();
+        }
+        bytes
+    }
+
+    #[test]
+    // Test vector 2 from the paper "Sosemanuk, a fast software-oriented
+    // stream cipher"
+    fn test_sosemanuk_true_values_a() {
+        let seed = from_hex(
+            "00112233445566778899AABBCCDDEEFF\
+             8899AABBCCDDEEFF0011223344556677",
+        );
+        let mut rng = SosemanukRng::from_seed(seed);
+        let mut results = [0u8; 64];
+        rng.fill_bytes(&mut results);
+        let expected: [u8; 64] = from_hex(
+            "FA61DBEB71178131A77C714BD2EABF4E1394207A25698AA1308F2F063A0F7606\
+             04CF67569BA59A3DFAD7F00145C78D29C5FFE5F964950486424451952C84039D",
+        );
+        assert_eq!(results, expected);
+
+        // Stream[192..255]
+        let mut skip = [0u8; 128];
+        rng.fill_bytes(&mut skip);
+        rng.fill_bytes(&mut results);
+        let expected: [u8; 64] = from_hex(
+            "64A552F2ADCD9EDCF2BD8CCE008B84D049DA1CF3CD57ED5D0F4C39C29F2C88C2\
+             55F2EDA0BA13C4CFDC90E03289293BAB0D205FDB02FF5F1FBBDDC5F2AD30DA90",
+        );
+        assert_eq!(results, expected);
+    }
+
+    #[test]
+    // Set 1, vector 0 of the eSTREAM test vectors for Sosemanuk with a 128-bit
+    // key
+    fn test_sosemanuk_true_values_b() {
+        let key: [u8; 16] = from_hex("80000000000000000000000000000000");
+        let mut rng = SosemanukRng::new(&key, [0; 16]);
+        let mut results = [0u8; 64];
+        rng.fill_bytes(&mut results);
+        let expected: [u8; 64] = from_hex(
+            "53CAFDD607EB210D76C83F898592A34E1D52AFCD3E3709D14F8CC9D1566528C2\
+             47B3D7253FF81B7B037B8D7ABA761FD253A9F4FA7F10713E6903F66DAD7CB109",
+        );
+        assert_eq!(results, expected);
+    }
+
+    #[test]
+    // Set 1, vector 0 of the eSTREAM test vectors for Sosemanuk with a 256-bit
+    // key
+    fn test_sosemanuk_true_values_c() {
+        let key: [u8; 32] =
+            from_hex("8000000000000000000000000000000000000000000000000000000000000000");
+        let mut rng = SosemanukRng::new(&key, [0; 16]);
+        let mut results = [0u8; 64];
+        rng.fill_bytes(&mut results);
+        let expected: [u8; 64] = from_hex(
+            "1782FABFF497A0E89E16E1BCF22F0FE8AA8C566D293AA35B2425E4F26E31C3E7\
+             701C08A0D614AF3D3861A7DFF7D6A38A0EFE84A29FADF68D390A3D15B75C972D",
+        );
+        assert_eq!(results, expected);
+    }
+
+    #[test]
+    fn test_sosemanuk_true_values_u64() {
+        let mut rng = SosemanukRng::from_seed([0; 32]);
+        let mut results = [0u64; 6];
+        for i in results.iter_mut() {
+            *i = rng.next_u64();
+        }
+        #[rustfmt::skip]
+        let expected = [0x2a91b12ebc681c76, 0x1f294f7c8071dc8e,
+                        0xd76b0155355f7580, 0xaad8a1a040ea12fc,
+                        0x79153431f8e83abf, 0x297efa07c422ea38];
+        assert_eq!(results, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sosemanuk_short_key() {
+        SosemanukRng::new(&[0; 15], [0; 16]);
+    }
+
+    #[test]
+    fn test_sosemanuk_clone() {
+        let mut rng1 = SosemanukRng::new(&[0x55; 20], [1; 16]);
+        let mut rng2 = rng1.clone();
+        for _ in 0..20 {
+            assert_eq!(rng1.next_u32(), rng2.next_u32());
+        }
+        assert_eq!(rng1, rng2);
+    }
+}
@@ -0,0 +1,168 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{JitterRng, TimerError};
+use core::fmt;
+use core::sync::atomic::{AtomicU16, Ordering};
+
+// Encoding of the cached test result in `CalibratedTimer::result`:
+// 0 when not yet tested, 1..=255 for a successful test with that many rounds,
+// and `FAILED` plus a small index for a failed test.
+const UNCALIBRATED: u16 = 0;
+const FAILED: u16 = 0x100;
+
+fn encode(result: &Result<u8, TimerError>) -> u16 {
+    match *result {
+        Ok(rounds) => rounds as u16,
+        Err(ref e) => {
+            FAILED
+                | match *e {
+                    TimerError::NoTimer => 1,
+                    TimerError::CoarseTimer => 2,
+                    TimerError::NotMonotonic => 3,
+                    TimerError::TinyVariations => 4,
+                    TimerError::TooManyStuck => 5,
+                    TimerError::__Nonexhaustive => unreachable!(),
+                }
+        }
+    }
+}
+
+fn decode(value: u16) -> Option<Result<u8, TimerError>> {
+    match value {
+        UNCALIBRATED => None,
+        1..=255 => Some(Ok(value as u8)),
+        _ => Some(Err(match value & !FAILED {
+            1 => TimerError::NoTimer,
+            2 => TimerError::CoarseTimer,
+            3 => TimerError::NotMonotonic,
+            4 => TimerError::TinyVariations,
+            _ => TimerError::TooManyStuck,
+        })),
+    }
+}
+
+/// A timer together with the cached result of its quality test.
+///
+/// The number of rounds `JitterRng` needs per output depends on the timer.
+/// `CalibratedTimer` runs [`JitterRng::test_timer`] the first time it is
+/// needed and remembers the result, so generators created with
+/// [`JitterRng::new_with_calibrated_timer`] start out with the right number of
+/// rounds for their timer without testing it again.
+///
+/// A `CalibratedTimer` can be shared between threads, and can be used as a
+/// `static`:
+///
+/// ```
+/// # #[cfg(all(target_arch = "x86_64", not(miri)))] {
+/// use rand_core::RngCore;
+/// use rand_jitter::{CalibratedTimer, JitterRng, timer};
+///
+/// static TSC: CalibratedTimer<fn() -> u64> = CalibratedTimer::new(timer::rdtscp);
+///
+/// if let Ok(mut rng) = JitterRng::new_with_calibrated_timer(&TSC) {
+///     let x = rng.next_u64();
+/// }
+/// # }
+/// ```
+///
+/// Timing characteristics may change while a program runs, for example when
+/// the CPU frequency is scaled. See [`with_recalibration`] to periodically
+/// repeat the test.
+///
+/// [`with_recalibration`]: CalibratedTimer::with_recalibration
+pub struct CalibratedTimer<F> {
+    timer: F,
+    result: AtomicU16,
+    recalibration_interval: u32,
+}
+
+// Custom Debug implementation that does not require `F: Debug`
+impl<F> fmt::Debug for CalibratedTimer<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CalibratedTimer")
+            .field("result", &decode(self.result.load(Ordering::Relaxed)))
+            .field("recalibration_interval", &self.recalibration_interval)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F> CalibratedTimer<F>
+where
+    F: Fn() -> u64 + Send + Sync,
+{
+    /// Wrap `timer`. It is tested on first use.
+    pub const fn new(timer: F) -> Self {
+        CalibratedTimer {
+            timer,
+            result: AtomicU16::new(UNCALIBRATED),
+            recalibration_interval: 0,
+        }
+    }
+
+    /// Make generators created from this timer repeat the quality test after
+    /// every `outputs` generated `u64` values, with [`recalibrate`], and
+    /// adjust their number of rounds to the new result.
+    ///
+    /// If a repeated test fails, the error is cached, so that no new
+    /// generators can be created from this timer, and the generator falls
+    /// back to the conservative 64 rounds of [`JitterRng::new_with_timer`].
+    /// A value of 0 disables recalibration, which is the default.
+    ///
+    /// [`recalibrate`]: CalibratedTimer::recalibrate
+    pub const fn with_recalibration(mut self, outputs: u32) -> Self {
+        self.recalibration_interval = outputs;
+        self
+    }
+
+    /// Get the number of rounds `JitterRng` should use with this timer.
+    ///
+    /// This runs [`JitterRng::test_timer`] if the timer was not tested yet,
+    /// and otherwise returns the cached result, which may be an error.
+    pub fn rounds(&self) -> Result<u8, TimerError> {
+        match decode(self.result.load(Ordering::Relaxed)) {
+            Some(result) => result,
+            None => self.recalibrate(),
+        }
+    }
+
+    /// Test the timer again, replacing the cached result.
+    pub fn recalibrate(&self) -> Result<u8, TimerError> {
+        let result = JitterRng::new_with_timer(&self.timer).test_timer();
+        // Concurrent calibrations may race; any of their results will do.
+        self.result.store(encode(&result), Ordering::Relaxed);
+        debug!("JitterRng: calibrated timer: {:?}", result);
+        result
+    }
+
+    /// Get a reference to the wrapped timer.
+    pub fn timer(&self) -> &F {
+        &self.timer
+    }
+}
+
+// The recalibration of a `CalibratedTimer`, independent of the type of its
+// timer, for `JitterRng` to keep a reference to it.
+pub(crate) trait Recalibrate: Sync {
+    fn recalibration_interval(&self) -> u32;
+
+    fn recalibrate(&self) -> Result<u8, TimerError>;
+}
+
+impl<F> Recalibrate for CalibratedTimer<F>
+where
+    F: Fn() -> u64 + Send + Sync,
+{
+    fn recalibration_interval(&self) -> u32 {
+        self.recalibration_interval
+    }
+
+    fn recalibrate(&self) -> Result<u8, TimerError> {
+        CalibratedTimer::recalibrate(self)
+    }
+}
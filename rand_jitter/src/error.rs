@@ -17,21 +17,32 @@ const ERROR_BASE: u32 = 0xAE53_0400;
 /// All variants have a value of 0xAE530400 = 2924676096 plus a small
 /// increment (1 through 5).
 ///
+/// The test measures the time delta of one round of the entropy collector 300
+/// times (see [`TimerReport`]). A timer reading of 0 or a time delta of 0 ends
+/// the test immediately; the other conditions are checked afterwards, in the
+/// order of the variants below.
+///
 /// [`JitterRng::test_timer`]: crate::JitterRng::test_timer
+/// [`TimerReport`]: crate::TimerReport
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[repr(u32)]
 #[allow(clippy::manual_non_exhaustive)]
 //^ TODO: Replace with `#[non_exhaustive]` for Rust >= 1.40
 pub enum TimerError {
-    /// No timer available.
+    /// No timer available: the timer returned 0.
     NoTimer = ERROR_BASE + 1,
-    /// Timer too coarse to use as an entropy source.
+    /// Timer too coarse to use as an entropy source: a time delta was 0, or
+    /// more than 90% of the time deltas were a multiple of 100.
     CoarseTimer = ERROR_BASE + 2,
-    /// Timer is not monotonically increasing.
+    /// Timer is not monotonically increasing: it did not advance during more
+    /// than 3 measurements.
     NotMonotonic = ERROR_BASE + 3,
-    /// Variations of deltas of time too small.
+    /// Variations of deltas of time too small: the absolute differences
+    /// between successive time deltas sum to less than 300.
     TinyVariations = ERROR_BASE + 4,
-    /// Too many stuck results (indicating no added entropy).
+    /// Too many stuck results (indicating no added entropy): for more than 90%
+    /// of the measurements the time delta, or its first or second derivative,
+    /// was 0.
     TooManyStuck = ERROR_BASE + 5,
     #[doc(hidden)]
     __Nonexhaustive,
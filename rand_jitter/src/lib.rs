@@ -101,21 +101,38 @@ macro_rules! error { ($($x:tt)*) => (
     }
 ) }
 
+mod calibration;
 mod error;
 #[cfg(feature = "std")]
 mod platform;
+mod report;
+mod reseeding;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod timer;
 
+pub use crate::calibration::CalibratedTimer;
 pub use crate::error::TimerError;
+pub use crate::report::TimerReport;
+pub use crate::reseeding::ReseedingRng;
+
+use crate::calibration::Recalibrate;
 use rand_core::{RngCore, utils};
 
 use core::{fmt, mem, ptr};
-#[cfg(feature = "std")]
-use std::sync::atomic::{AtomicUsize, Ordering};
 
 const MEMORY_BLOCKS: usize = 64;
 const MEMORY_BLOCKSIZE: usize = 32;
 const MEMORY_SIZE: usize = MEMORY_BLOCKS * MEMORY_BLOCKSIZE;
 
+// TESTLOOPCOUNT needs some loops to identify edge systems.
+// 100 is definitely too little.
+const TESTLOOPCOUNT: u64 = 300;
+const CLEARCACHE: u64 = 100;
+
+// The conservative number of rounds used when the timer was not tested.
+const DEFAULT_ROUNDS: u8 = 64;
+
 /// A true random number generator based on jitter in the CPU execution time,
 /// and jitter in memory access time.
 ///
@@ -131,6 +148,10 @@ pub struct JitterRng<F> {
     mem_prev_index: u16,
     // Make `next_u32` not waste 32 bits
     data_half_used: bool,
+    // The timer this generator was created from, to repeat its test
+    calibration: Option<&'static dyn Recalibrate>,
+    // Number of `u64` outputs since the last timer test
+    outputs: u32,
 }
 
 // Note: `JitterRng` maintains a small 64-bit entropy pool. With every
@@ -202,41 +223,41 @@ where
             // The 32 bits that may still be unused from the previous round are
             // for the original to use, not for the clone.
             data_half_used: false,
+            calibration: self.calibration,
+            outputs: self.outputs,
         }
     }
 }
 
-// Initialise to zero; must be positive
-#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
-static JITTER_ROUNDS: AtomicUsize = AtomicUsize::new(0);
-
 impl JitterRng<()> {
     /// Create a new `JitterRng`. Makes use of `std::time` for a timer, or a
     /// platform-specific function with higher accuracy if necessary and
     /// available.
     ///
+    /// On Linux and Android the timers from the [`timer`] module are tried
+    /// first, best first: the cycle counter (`timer::rdtscp` on x86-64,
+    /// `timer::cntvct` on AArch64), then `timer::clock_monotonic_raw`.
+    /// The first timer to pass the quality tests is used.
+    ///
     /// During initialization CPU execution timing jitter is measured a few
     /// hundred times. If this does not pass basic quality tests, an error is
-    /// returned. The test result is cached to make subsequent calls faster.
+    /// returned. The test result of each timer is cached (see
+    /// [`CalibratedTimer`]) to make subsequent calls faster.
     #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
     pub fn new() -> Result<JitterRng<impl Fn() -> u64 + Send + Sync>, TimerError> {
         if cfg!(target_arch = "wasm32") {
             return Err(TimerError::NoTimer);
         }
-        let mut state = JitterRng::new_with_timer(platform::get_nstime);
-        let mut rounds = JITTER_ROUNDS.load(Ordering::Relaxed) as u8;
-        if rounds == 0 {
-            // No result yet: run test.
-            // This allows the timer test to run multiple times; we don't care.
-            rounds = state.test_timer()?;
-            JITTER_ROUNDS.store(rounds as usize, Ordering::Relaxed);
-            info!("JitterRng: using {} rounds per u64 output", rounds);
+        // Use the first timer that passes the tests. If none do, report the
+        // error of the last (most portable) one.
+        let mut result = Err(TimerError::NoTimer);
+        for timer in platform::TIMERS {
+            result = JitterRng::new_with_calibrated_timer(timer);
+            if result.is_ok() {
+                break;
+            }
         }
-        state.set_rounds(rounds);
-
-        // Fill `data` with a non-zero value.
-        state.gen_entropy();
-        Ok(state)
+        result
     }
 }
 
@@ -254,7 +275,8 @@ where
     /// the caller to run [`test_timer`] before using any numbers generated with
     /// `JitterRng`, and optionally call [`set_rounds`]. Also it is important to
     /// consume at least one `u64` before using the first result to initialize
-    /// the entropy collection pool.
+    /// the entropy collection pool. [`new_with_calibrated_timer`] takes care
+    /// of all of this, and remembers the test result for the timer.
     ///
     /// # Example
     ///
@@ -283,16 +305,48 @@ where
     ///
     /// [`test_timer`]: JitterRng::test_timer
     /// [`set_rounds`]: JitterRng::set_rounds
+    /// [`new_with_calibrated_timer`]: JitterRng::new_with_calibrated_timer
     pub fn new_with_timer(timer: F) -> JitterRng<F> {
         JitterRng {
             data: 0,
-            rounds: 64,
+            rounds: DEFAULT_ROUNDS,
             timer,
             mem_prev_index: 0,
             data_half_used: false,
+            calibration: None,
+            outputs: 0,
         }
     }
 
+    /// Create a new `JitterRng` using the timer of a [`CalibratedTimer`].
+    ///
+    /// The number of rounds is taken from the cached test result of `timer`,
+    /// testing it first if necessary. If the test failed, its error is
+    /// returned. Unlike with [`new_with_timer`], the returned generator is
+    /// ready for use.
+    ///
+    /// The generator keeps a reference to `timer`, through which it repeats
+    /// the test if `timer` was configured with
+    /// [`CalibratedTimer::with_recalibration`].
+    ///
+    /// [`new_with_timer`]: JitterRng::new_with_timer
+    pub fn new_with_calibrated_timer(
+        timer: &'static CalibratedTimer<F>,
+    ) -> Result<JitterRng<F>, TimerError>
+    where
+        F: Clone,
+    {
+        let rounds = timer.rounds()?;
+        info!("JitterRng: using {} rounds per u64 output", rounds);
+        let mut state = JitterRng::new_with_timer(timer.timer().clone());
+        state.set_rounds(rounds);
+        state.calibration = Some(timer);
+
+        // Fill `data` with a non-zero value.
+        state.gen_entropy();
+        Ok(state)
+    }
+
     /// Configures how many rounds are used to generate each 64-bit value.
     /// This must be greater than zero, and has a big impact on performance
     /// and output quality.
@@ -552,18 +606,70 @@ where
     /// If successful, this will return the estimated number of rounds necessary
     /// to collect 64 bits of entropy. Otherwise a [`TimerError`] with the cause
     /// of the failure will be returned.
+    ///
+    /// Use [`analyze_timer`] to also get the statistics this result is based
+    /// on.
+    ///
+    /// [`analyze_timer`]: JitterRng::analyze_timer
     pub fn test_timer(&mut self) -> Result<u8, TimerError> {
+        self.analyze_timer().rounds
+    }
+
+    /// Run the same quality tests as [`test_timer`], but return a
+    /// [`TimerReport`] with the collected statistics instead of only the
+    /// outcome.
+    ///
+    /// The outcome of the tests is available as [`TimerReport::rounds`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(all(target_arch = "x86_64", not(miri)))] {
+    /// use rand_jitter::{JitterRng, timer};
+    ///
+    /// let mut rng = JitterRng::new_with_timer(timer::rdtscp);
+    /// let report = rng.analyze_timer();
+    /// println!("{:?}", report);
+    /// if let Ok(rounds) = report.rounds {
+    ///     rng.set_rounds(rounds);
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`test_timer`]: JitterRng::test_timer
+    pub fn analyze_timer(&mut self) -> TimerReport {
         debug!("JitterRng: testing timer ...");
         // We could add a check for system capabilities such as `clock_getres`
         // or check for `CONFIG_X86_TSC`, but it does not make much sense as the
         // following sanity checks verify that we have a high-resolution timer.
 
-        let mut delta_sum = 0;
-        let mut old_delta = 0;
+        let mut report = TimerReport {
+            samples: 0,
+            delta_sum: 0,
+            delta_average: 0,
+            count_mod: 0,
+            count_stuck: 0,
+            time_backwards: 0,
+            min_entropy: 0.0,
+            rounds: Err(TimerError::NoTimer),
+        };
+        let mut deltas = [0; TESTLOOPCOUNT as usize];
+
+        report.rounds = self
+            .sample_timer(&mut report, &mut deltas)
+            .and_then(|()| report.evaluate());
+        report.min_entropy = report::min_entropy(&mut deltas[..report.samples as usize]);
+        report
+    }
 
-        let mut time_backwards = 0;
-        let mut count_mod = 0;
-        let mut count_stuck = 0;
+    // Collect the statistics for `analyze_timer`. Returns early if the timer
+    // is obviously unusable.
+    fn sample_timer(
+        &mut self,
+        report: &mut TimerReport,
+        deltas: &mut [i32; TESTLOOPCOUNT as usize],
+    ) -> Result<(), TimerError> {
+        let mut old_delta = 0;
 
         let mut ec = EcState {
             prev_time: (self.timer)(),
@@ -572,11 +678,6 @@ where
             mem: [0; MEMORY_SIZE],
         };
 
-        // TESTLOOPCOUNT needs some loops to identify edge systems.
-        // 100 is definitely too little.
-        const TESTLOOPCOUNT: u64 = 300;
-        const CLEARCACHE: u64 = 100;
-
         for i in 0..(CLEARCACHE + TESTLOOPCOUNT) {
             // Measure time delta of core entropy collection logic
             let time = (self.timer)();
@@ -606,96 +707,37 @@ where
                 continue;
             }
 
+            deltas[report.samples as usize] = delta;
+            report.samples += 1;
+
             if ec.stuck(delta) {
-                count_stuck += 1;
+                report.count_stuck += 1;
             }
 
             // Test whether we have an increasing timer.
             if time2 <= time {
-                time_backwards += 1;
+                report.time_backwards += 1;
             }
 
             // Count the number of times the counter increases in steps of 100ns
             // or greater.
             if (delta % 100) == 0 {
-                count_mod += 1;
+                report.count_mod += 1;
             }
 
             // Ensure that we have a varying delta timer which is necessary for
             // the calculation of entropy -- perform this check only after the
             // first loop is executed as we need to prime the old_delta value
-            delta_sum += (delta - old_delta).unsigned_abs() as u64;
+            report.delta_sum += (delta - old_delta).unsigned_abs() as u64;
             old_delta = delta;
         }
+        report.delta_average = report.delta_sum / TESTLOOPCOUNT;
 
         // Do a single read from `self.mem` to make sure the Memory Access noise
         // source is not optimised out.
         black_box(ec.mem[0]);
 
-        // We allow the time to run backwards for up to three times.
-        // This can happen if the clock is being adjusted by NTP operations.
-        // If such an operation just happens to interfere with our test, it
-        // should not fail. The value of 3 should cover the NTP case being
-        // performed during our test run.
-        if time_backwards > 3 {
-            return Err(TimerError::NotMonotonic);
-        }
-
-        // Test that the available amount of entropy per round does not get to
-        // low. We expect 1 bit of entropy per round as a reasonable minimum
-        // (although less is possible, it means the collector loop has to run
-        // much more often).
-        // `assert!(delta_average >= log2(1))`
-        // `assert!(delta_sum / TESTLOOPCOUNT >= 1)`
-        // `assert!(delta_sum >= TESTLOOPCOUNT)`
-        if delta_sum < TESTLOOPCOUNT {
-            return Err(TimerError::TinyVariations);
-        }
-
-        // Ensure that we have variations in the time stamp below 100 for at
-        // least 10% of all checks -- on some platforms, the counter increments
-        // in multiples of 100, but not always
-        if count_mod > (TESTLOOPCOUNT * 9 / 10) {
-            return Err(TimerError::CoarseTimer);
-        }
-
-        // If we have more than 90% stuck results, then this Jitter RNG is
-        // likely to not work well.
-        if count_stuck > (TESTLOOPCOUNT * 9 / 10) {
-            return Err(TimerError::TooManyStuck);
-        }
-
-        // Estimate the number of `measure_jitter` rounds necessary for 64 bits
-        // of entropy.
-        //
-        // We don't try very hard to come up with a good estimate of the
-        // available bits of entropy per round here for two reasons:
-        // 1. Simple estimates of the available bits (like Shannon entropy) are
-        //    too optimistic.
-        // 2. Unless we want to waste a lot of time during initialization, there
-        //    only a small number of samples are available.
-        //
-        // Therefore we use a very simple and conservative estimate:
-        // `let bits_of_entropy = log2(delta_average) / 2`.
-        //
-        // The number of rounds `measure_jitter` should run to collect 64 bits
-        // of entropy is `64 / bits_of_entropy`.
-        let delta_average = delta_sum / TESTLOOPCOUNT;
-
-        if delta_average >= 16 {
-            let log2 = 64 - delta_average.leading_zeros();
-            // Do something similar to roundup(64/(log2/2)):
-            Ok((64u32 * 2).div_ceil(log2) as u8)
-        } else {
-            // For values < 16 the rounding error becomes too large, use a
-            // lookup table.
-            // Values 0 and 1 are invalid, and filtered out by the
-            // `delta_sum < TESTLOOPCOUNT` test above.
-            let log2_lookup = [
-                0, 0, 128, 81, 64, 56, 50, 46, 43, 41, 39, 38, 36, 35, 34, 33,
-            ];
-            Ok(log2_lookup[delta_average as usize])
-        }
+        Ok(())
     }
 
     /// Statistical test: return the timer delta of one normal run of the
@@ -751,6 +793,25 @@ where
 
     fn next_u64(&mut self) -> u64 {
         self.data_half_used = false;
+        if let Some(timer) = self.calibration {
+            let interval = timer.recalibration_interval();
+            if interval != 0 {
+                self.outputs += 1;
+                if self.outputs >= interval {
+                    self.outputs = 0;
+                    match timer.recalibrate() {
+                        Ok(rounds) => self.set_rounds(rounds),
+                        Err(_e) => {
+                            warn!(
+                                "JitterRng: recalibration failed, using {} rounds: {}",
+                                DEFAULT_ROUNDS, _e
+                            );
+                            self.set_rounds(DEFAULT_ROUNDS);
+                        }
+                    }
+                }
+            }
+        }
         self.gen_entropy()
     }
 
@@ -7,6 +7,38 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use crate::CalibratedTimer;
+
+type Timer = CalibratedTimer<fn() -> u64>;
+
+// Timer sources tried by `JitterRng::new`, best first.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub static TIMERS: &[&Timer] = &[
+    #[cfg(target_arch = "x86_64")]
+    &TSC,
+    #[cfg(target_arch = "aarch64")]
+    &CNTVCT,
+    &MONOTONIC_RAW,
+    &NSTIME,
+];
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+pub static TIMERS: &[&Timer] = &[&NSTIME];
+
+#[cfg(all(
+    any(target_os = "linux", target_os = "android"),
+    target_arch = "x86_64"
+))]
+static TSC: Timer = CalibratedTimer::new(crate::timer::rdtscp);
+#[cfg(all(
+    any(target_os = "linux", target_os = "android"),
+    target_arch = "aarch64"
+))]
+static CNTVCT: Timer = CalibratedTimer::new(crate::timer::cntvct);
+#[cfg(any(target_os = "linux", target_os = "android"))]
+static MONOTONIC_RAW: Timer = CalibratedTimer::new(crate::timer::clock_monotonic_raw);
+static NSTIME: Timer = CalibratedTimer::new(get_nstime);
+
 #[cfg(not(any(target_os = "macos", target_os = "ios", target_os = "windows")))]
 pub fn get_nstime() -> u64 {
     use std::time::{SystemTime, UNIX_EPOCH};
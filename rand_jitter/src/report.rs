@@ -0,0 +1,177 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{TESTLOOPCOUNT, TimerError};
+
+/// Statistics collected by [`JitterRng::analyze_timer`].
+///
+/// The counters are taken over [`samples`] measurements of one round of the
+/// entropy collector. If a measurement showed the timer to be unusable
+/// (reported as [`TimerError::NoTimer`] or [`TimerError::CoarseTimer`]),
+/// sampling stops early and the counters only cover the samples before it.
+///
+/// [`JitterRng::analyze_timer`]: crate::JitterRng::analyze_timer
+/// [`samples`]: TimerReport::samples
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct TimerReport {
+    /// Number of measurements the statistics are based on.
+    pub samples: u64,
+    /// Sum of the absolute differences between successive time deltas.
+    pub delta_sum: u64,
+    /// `delta_sum` divided by the full number of test rounds (300). The
+    /// number of rounds is derived from this value.
+    pub delta_average: u64,
+    /// Number of time deltas that were a multiple of 100.
+    pub count_mod: u64,
+    /// Number of measurements for which the stuck test triggered: the time
+    /// delta, or its first or second derivative, was zero.
+    pub count_stuck: u64,
+    /// Number of measurements for which the timer did not increase.
+    pub time_backwards: u64,
+    /// Estimated min-entropy in bits per time delta, according to the Most
+    /// Common Value estimate of NIST SP 800-90B (section 6.3.1).
+    ///
+    /// The estimate is made over few samples; use the procedure from this
+    /// crate's README for a thorough assessment.
+    pub min_entropy: f64,
+    /// Outcome of the tests: the number of rounds necessary to collect 64
+    /// bits of entropy, as returned by [`JitterRng::test_timer`].
+    ///
+    /// [`JitterRng::test_timer`]: crate::JitterRng::test_timer
+    pub rounds: Result<u8, TimerError>,
+}
+
+impl TimerReport {
+    // Apply the quality tests to the collected statistics, and estimate the
+    // number of rounds.
+    pub(crate) fn evaluate(&self) -> Result<u8, TimerError> {
+        // We allow the time to run backwards for up to three times.
+        // This can happen if the clock is being adjusted by NTP operations.
+        // If such an operation just happens to interfere with our test, it
+        // should not fail. The value of 3 should cover the NTP case being
+        // performed during our test run.
+        if self.time_backwards > 3 {
+            return Err(TimerError::NotMonotonic);
+        }
+
+        // Test that the available amount of entropy per round does not get to
+        // low. We expect 1 bit of entropy per round as a reasonable minimum
+        // (although less is possible, it means the collector loop has to run
+        // much more often).
+        // `assert!(delta_average >= log2(1))`
+        // `assert!(delta_sum / TESTLOOPCOUNT >= 1)`
+        // `assert!(delta_sum >= TESTLOOPCOUNT)`
+        if self.delta_sum < TESTLOOPCOUNT {
+            return Err(TimerError::TinyVariations);
+        }
+
+        // Ensure that we have variations in the time stamp below 100 for at
+        // least 10% of all checks -- on some platforms, the counter increments
+        // in multiples of 100, but not always
+        if self.count_mod > (TESTLOOPCOUNT * 9 / 10) {
+            return Err(TimerError::CoarseTimer);
+        }
+
+        // If we have more than 90% stuck results, then this Jitter RNG is
+        // likely to not work well.
+        if self.count_stuck > (TESTLOOPCOUNT * 9 / 10) {
+            return Err(TimerError::TooManyStuck);
+        }
+
+        // Estimate the number of `measure_jitter` rounds necessary for 64 bits
+        // of entropy.
+        //
+        // We don't try very hard to come up with a good estimate of the
+        // available bits of entropy per round here for two reasons:
+        // 1. Simple estimates of the available bits (like Shannon entropy) are
+        //    too optimistic.
+        // 2. Unless we want to waste a lot of time during initialization, there
+        //    only a small number of samples are available.
+        //
+        // Therefore we use a very simple and conservative estimate:
+        // `let bits_of_entropy = log2(delta_average) / 2`.
+        //
+        // The number of rounds `measure_jitter` should run to collect 64 bits
+        // of entropy is `64 / bits_of_entropy`.
+        let delta_average = self.delta_average;
+
+        if delta_average >= 16 {
+            let log2 = 64 - delta_average.leading_zeros();
+            // Do something similar to roundup(64/(log2/2)):
+            Ok((64u32 * 2).div_ceil(log2) as u8)
+        } else {
+            // For values < 16 the rounding error becomes too large, use a
+            // lookup table.
+            // Values 0 and 1 are invalid, and filtered out by the
+            // `delta_sum < TESTLOOPCOUNT` test above.
+            let log2_lookup = [
+                0, 0, 128, 81, 64, 56, 50, 46, 43, 41, 39, 38, 36, 35, 34, 33,
+            ];
+            Ok(log2_lookup[delta_average as usize])
+        }
+    }
+}
+
+// Most Common Value estimate (NIST SP 800-90B, section 6.3.1): the
+// min-entropy corresponding to the upper bound of the 99% confidence interval
+// on the probability of the most common value.
+//
+// Sorts `deltas` in place.
+pub(crate) fn min_entropy(deltas: &mut [i32]) -> f64 {
+    let n = deltas.len();
+    if n < 2 {
+        return 0.0;
+    }
+
+    deltas.sort_unstable();
+    let mut mcv = 0;
+    for run in deltas.chunk_by(|a, b| a == b) {
+        mcv = mcv.max(run.len());
+    }
+
+    let p = mcv as f64 / n as f64;
+    let p_u = p + 2.576 * sqrt(p * (1.0 - p) / (n - 1) as f64);
+    if p_u >= 1.0 { 0.0 } else { log2(1.0 / p_u) }
+}
+
+// `core` does not provide floating point functions, and we only need these
+// two with modest precision.
+
+// Square root of `x >= 0` by Newton's method.
+fn sqrt(x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    let mut r = if x > 1.0 { x } else { 1.0 };
+    for _ in 0..64 {
+        r = 0.5 * (r + x / r);
+    }
+    r
+}
+
+// Binary logarithm of `x >= 1`, with 32 bits of fractional precision.
+fn log2(mut x: f64) -> f64 {
+    let mut result = 0.0;
+    while x >= 2.0 {
+        x /= 2.0;
+        result += 1.0;
+    }
+    // Square `x` repeatedly: each time it exceeds 2, the next bit of the
+    // fractional part is one.
+    let mut bit = 0.5;
+    for _ in 0..32 {
+        x *= x;
+        if x >= 2.0 {
+            x /= 2.0;
+            result += bit;
+        }
+        bit /= 2.0;
+    }
+    result
+}
@@ -0,0 +1,175 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::JitterRng;
+use core::fmt;
+use rand_core::{RngCore, SeedableRng};
+
+/// A wrapper around a fast PRNG which seeds it from a [`JitterRng`], and
+/// reseeds it periodically.
+///
+/// `JitterRng` is much too slow to generate bulk random data, but it does not
+/// need an operating system to collect entropy. `ReseedingRng` combines it
+/// with a fast generator such as `Hc128Rng` for systems without another
+/// entropy source.
+///
+/// The wrapped generator is reseeded:
+///
+/// - after generating `threshold` bytes, see [`new`];
+/// - after the timer of the `JitterRng` advanced by a number of ticks, see
+///   [`with_tick_interval`];
+/// - on [`reseed`];
+/// - when the `ReseedingRng` is cloned, so the clone does not repeat the
+///   output of the original. The clone reseeds before its first output.
+///
+/// Reseeding does not fail: `JitterRng` only fails when creating it, in
+/// [`JitterRng::new`] or [`JitterRng::test_timer`].
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(all(target_arch = "x86_64", not(miri)))] {
+/// use rand_core::RngCore;
+/// use rand_hc::Hc128Rng;
+/// use rand_jitter::{JitterRng, ReseedingRng, timer};
+///
+/// let mut jitter = JitterRng::new_with_timer(timer::rdtscp);
+/// if let Ok(rounds) = jitter.test_timer() {
+///     jitter.set_rounds(rounds);
+///     // Reseed after every MiB of output.
+///     let mut rng = ReseedingRng::<Hc128Rng, _>::new(jitter, 1 << 20);
+///     let mut buf = [0u8; 64];
+///     rng.fill_bytes(&mut buf);
+/// }
+/// # }
+/// ```
+///
+/// [`new`]: ReseedingRng::new
+/// [`with_tick_interval`]: ReseedingRng::with_tick_interval
+/// [`reseed`]: ReseedingRng::reseed
+/// [`JitterRng::new`]: crate::JitterRng::new
+/// [`JitterRng::test_timer`]: crate::JitterRng::test_timer
+pub struct ReseedingRng<R, F> {
+    rng: R,
+    reseeder: JitterRng<F>,
+    // `u64::MAX` if reseeding after a number of bytes is disabled
+    threshold: u64,
+    bytes_until_reseed: u64,
+    // 0 if reseeding after a number of timer ticks is disabled
+    tick_interval: u64,
+    last_reseed: u64,
+}
+
+// Custom Debug implementation that does not expose the internal state
+impl<R, F> fmt::Debug for ReseedingRng<R, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ReseedingRng {{}}")
+    }
+}
+
+impl<R, F> Clone for ReseedingRng<R, F>
+where
+    R: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> ReseedingRng<R, F> {
+        ReseedingRng {
+            rng: self.rng.clone(),
+            reseeder: self.reseeder.clone(),
+            threshold: self.threshold,
+            // Force a reseed before the clone generates any output.
+            bytes_until_reseed: 0,
+            tick_interval: self.tick_interval,
+            last_reseed: self.last_reseed,
+        }
+    }
+}
+
+impl<R, F> ReseedingRng<R, F>
+where
+    R: RngCore + SeedableRng,
+    F: Fn() -> u64 + Send + Sync,
+{
+    /// Create a new `ReseedingRng`, seeding a new `R` from `reseeder`.
+    ///
+    /// The wrapped generator is reseeded after generating `threshold` bytes.
+    /// A `threshold` of 0 disables reseeding based on the amount of output.
+    ///
+    /// `reseeder` should be ready for use: see [`JitterRng::new_with_timer`]
+    /// for what that involves.
+    ///
+    /// [`JitterRng::new_with_timer`]: crate::JitterRng::new_with_timer
+    pub fn new(mut reseeder: JitterRng<F>, threshold: u64) -> Self {
+        let threshold = if threshold == 0 { u64::MAX } else { threshold };
+        ReseedingRng {
+            rng: R::from_rng(&mut reseeder),
+            last_reseed: (reseeder.timer)(),
+            reseeder,
+            threshold,
+            bytes_until_reseed: threshold,
+            tick_interval: 0,
+        }
+    }
+
+    /// Also reseed the wrapped generator when at least `ticks` timer ticks
+    /// have passed since the last reseed. A value of 0 disables this, which
+    /// is the default.
+    ///
+    /// The elapsed time is only checked when output is requested, so a
+    /// generator that is not used is not reseeded either.
+    pub fn with_tick_interval(mut self, ticks: u64) -> Self {
+        self.tick_interval = ticks;
+        self
+    }
+
+    /// Reseed the wrapped generator from the `JitterRng`.
+    pub fn reseed(&mut self) {
+        self.rng = R::from_rng(&mut self.reseeder);
+        self.bytes_until_reseed = self.threshold;
+        self.last_reseed = (self.reseeder.timer)();
+    }
+
+    // Account for `bytes` bytes of output, reseeding first if necessary.
+    #[inline]
+    fn consume(&mut self, bytes: u64) {
+        let expired = self.tick_interval != 0
+            && (self.reseeder.timer)().wrapping_sub(self.last_reseed) >= self.tick_interval;
+        if bytes > self.bytes_until_reseed || expired {
+            self.reseed();
+        }
+        self.bytes_until_reseed = self.bytes_until_reseed.saturating_sub(bytes);
+    }
+}
+
+impl<R, F> RngCore for ReseedingRng<R, F>
+where
+    R: RngCore + SeedableRng,
+    F: Fn() -> u64 + Send + Sync,
+{
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.consume(4);
+        self.rng.next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.consume(8);
+        self.rng.next_u64()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        // Only split up large requests, so that no more than `threshold`
+        // bytes are ever generated from a single seed.
+        for chunk in dest.chunks_mut(self.threshold.try_into().unwrap_or(usize::MAX)) {
+            self.consume(chunk.len() as u64);
+            self.rng.fill_bytes(chunk);
+        }
+    }
+}
@@ -0,0 +1,139 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Scripted timers for testing.
+//!
+//! The timers in this module do not measure anything: they return a
+//! predetermined sequence of values. This makes the behaviour of
+//! [`JitterRng`] with them deterministic, which is useful to test code that
+//! handles the different outcomes of [`JitterRng::test_timer`], or to
+//! reproduce the output of `JitterRng` for a recorded timer trace.
+//!
+//! Every call to a timer advances its sequence, so the values depend on how
+//! often `JitterRng` reads the timer. Use a timer from a single thread only to
+//! keep its sequence deterministic.
+//!
+//! ```
+//! use rand_jitter::{JitterRng, TimerError, testing};
+//!
+//! let mut rng = JitterRng::new_with_timer(testing::constant(42));
+//! assert_eq!(rng.test_timer(), Err(TimerError::CoarseTimer));
+//!
+//! let mut rng = JitterRng::new_with_timer(testing::gaussian(1000.0, 100.0, 1));
+//! assert!(rng.test_timer().is_ok());
+//! ```
+//!
+//! This module is only available with the `testing` feature. It should not
+//! be used outside of tests: `JitterRng` with a scripted timer is a
+//! deterministic generator that does not collect any entropy.
+//!
+//! [`JitterRng`]: crate::JitterRng
+//! [`JitterRng::test_timer`]: crate::JitterRng::test_timer
+
+use core::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+/// The first value returned by [`gaussian`] and [`replay`].
+pub const START: u64 = 1 << 32;
+
+/// A timer which always returns `value`.
+///
+/// `JitterRng::test_timer` reports this as `TimerError::NoTimer` if `value`
+/// is 0, and as `TimerError::CoarseTimer` otherwise.
+pub fn constant(value: u64) -> impl Fn() -> u64 + Send + Sync {
+    move || value
+}
+
+/// A timer which advances by a random amount on every call, following a
+/// normal distribution with the given `mean` and `std_dev`.
+///
+/// The distribution is approximated by the sum of twelve uniform samples
+/// from a PRNG seeded with `seed`. The step is rounded to an integer, and
+/// at least 1, so the timer is strictly monotonic.
+///
+/// With a `std_dev` of 0 this is a counter advancing by `mean` per call.
+pub fn gaussian(mean: f64, std_dev: f64, seed: u64) -> impl Fn() -> u64 + Send + Sync {
+    // xorshift64* does not accept a zero state.
+    let state = AtomicU64::new(if seed == 0 {
+        0x9E37_79B9_7F4A_7C15
+    } else {
+        seed
+    });
+    let time = AtomicU64::new(START);
+    move || {
+        let mut x = state.load(Ordering::Relaxed);
+        let mut sum = 0.0;
+        for _ in 0..12 {
+            x ^= x >> 12;
+            x ^= x << 25;
+            x ^= x >> 27;
+            let bits = x.wrapping_mul(0x2545_F491_4F6C_DD1D) >> 11;
+            sum += bits as f64 * (1.0 / (1u64 << 53) as f64);
+        }
+        state.store(x, Ordering::Relaxed);
+
+        let step = mean + std_dev * (sum - 6.0);
+        let step = if step < 1.0 { 1 } else { (step + 0.5) as u64 };
+        time.fetch_add(step, Ordering::Relaxed)
+    }
+}
+
+/// A timer which replays recorded `deltas` between successive calls.
+///
+/// The first call returns [`START`], and every following call the previous
+/// value plus the next delta. Negative deltas make the timer step backwards.
+/// After the last delta the sequence continues with the first one again.
+///
+/// If `deltas` is empty, the timer always returns `START`.
+pub fn replay(deltas: &[i64]) -> impl Fn() -> u64 + Send + Sync + '_ {
+    let index = AtomicUsize::new(0);
+    let time = AtomicU64::new(START);
+    move || {
+        let t = time.load(Ordering::Relaxed);
+        if !deltas.is_empty() {
+            let i = index.load(Ordering::Relaxed);
+            time.store(t.wrapping_add(deltas[i] as u64), Ordering::Relaxed);
+            index.store((i + 1) % deltas.len(), Ordering::Relaxed);
+        }
+        t
+    }
+}
+
+/// A timer which rounds the values of `timer` down to a multiple of
+/// `resolution`, like a clock that only counts in coarse steps.
+///
+/// # Panics
+///
+/// If `resolution` is 0.
+pub fn coarse<T>(resolution: u64, timer: T) -> impl Fn() -> u64 + Send + Sync
+where
+    T: Fn() -> u64 + Send + Sync,
+{
+    assert!(resolution != 0, "resolution must be positive");
+    move || timer() / resolution * resolution
+}
+
+/// A timer which is set back by `step` on every `period`-th call, but
+/// otherwise follows `timer`.
+///
+/// If `step` is larger than the amount `timer` advances between two calls,
+/// the timer runs backwards on those calls. A `period` of 0 never sets the
+/// timer back.
+pub fn backwards<T>(period: u64, step: u64, timer: T) -> impl Fn() -> u64 + Send + Sync
+where
+    T: Fn() -> u64 + Send + Sync,
+{
+    let calls = AtomicU64::new(0);
+    let offset = AtomicU64::new(0);
+    move || {
+        let n = calls.fetch_add(1, Ordering::Relaxed) + 1;
+        if period != 0 && n % period == 0 {
+            offset.fetch_add(step, Ordering::Relaxed);
+        }
+        timer().wrapping_sub(offset.load(Ordering::Relaxed))
+    }
+}
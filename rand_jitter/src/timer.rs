@@ -0,0 +1,146 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! High-resolution timer sources.
+//!
+//! The functions in this module can be passed directly to
+//! [`JitterRng::new_with_timer`]. None of them require `std`, so they are
+//! also the easiest way to use `JitterRng` in a `no_std` environment on a
+//! supported platform:
+//!
+//! ```
+//! # #[cfg(all(target_arch = "x86_64", not(miri)))] {
+//! use rand_jitter::{JitterRng, timer};
+//!
+//! let mut rng = JitterRng::new_with_timer(timer::rdtscp);
+//! if let Ok(rounds) = rng.test_timer() {
+//!     rng.set_rounds(rounds);
+//! }
+//! # }
+//! ```
+//!
+//! As with any timer, run [`JitterRng::test_timer`] before relying on the
+//! output. [`JitterRng::new`] does this for every source available on the
+//! current platform, and uses the first one that passes.
+//!
+//! [`JitterRng::new_with_timer`]: crate::JitterRng::new_with_timer
+//! [`JitterRng::test_timer`]: crate::JitterRng::test_timer
+//! [`JitterRng::new`]: crate::JitterRng::new
+
+/// Read `CLOCK_MONOTONIC_RAW` using `clock_gettime`.
+///
+/// Unlike `std::time::SystemTime` this clock is monotonic, and unlike
+/// `CLOCK_MONOTONIC` it is not subject to frequency adjustments by NTP.
+/// This makes it a safe choice when the cycle counters are unavailable.
+///
+/// Returns 0 if the clock cannot be read, which [`JitterRng::test_timer`]
+/// reports as [`TimerError::NoTimer`].
+///
+/// [`JitterRng::test_timer`]: crate::JitterRng::test_timer
+/// [`TimerError::NoTimer`]: crate::TimerError::NoTimer
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn clock_monotonic_raw() -> u64 {
+    let mut ts = libc::timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    // SAFETY: `ts` is a valid, writable `timespec`.
+    let ret = unsafe { libc::clock_gettime(libc::CLOCK_MONOTONIC_RAW, &mut ts) };
+    if ret != 0 {
+        return 0;
+    }
+    // See `get_nstime` in the platform module: we only need the bits, not
+    // an exact count of nanoseconds.
+    ((ts.tv_sec as u64) << 30) | ts.tv_nsec as u64
+}
+
+/// Read the x86-64 time stamp counter using `rdtsc`.
+///
+/// The time stamp counter counts at a constant rate close to the nominal CPU
+/// frequency on all recent processors, giving sub-nanosecond resolution.
+/// `rdtsc` is not a serializing instruction, so the measurement can be
+/// reordered with surrounding code; prefer [`rdtscp`] where available.
+#[cfg(target_arch = "x86_64")]
+pub fn rdtsc() -> u64 {
+    // SAFETY: `rdtsc` is part of the x86-64 baseline.
+    #[allow(unused_unsafe)]
+    unsafe {
+        core::arch::x86_64::_rdtsc()
+    }
+}
+
+/// Read the x86-64 time stamp counter using `rdtscp`.
+///
+/// `rdtscp` waits until all previous instructions have executed before
+/// reading the counter, which makes it a better fit than [`rdtsc`] for
+/// measuring execution time jitter.
+///
+/// Not all processors support `rdtscp`. On those that do not, this falls back
+/// to [`rdtsc`].
+#[cfg(target_arch = "x86_64")]
+pub fn rdtscp() -> u64 {
+    if has_rdtscp() {
+        let mut aux = 0;
+        // SAFETY: support for `rdtscp` was checked above.
+        unsafe { core::arch::x86_64::__rdtscp(&mut aux) }
+    } else {
+        rdtsc()
+    }
+}
+
+// Check CPUID for `rdtscp` support, caching the result.
+#[cfg(target_arch = "x86_64")]
+fn has_rdtscp() -> bool {
+    use core::sync::atomic::{AtomicU8, Ordering};
+
+    const UNKNOWN: u8 = 0;
+    const ABSENT: u8 = 1;
+    const PRESENT: u8 = 2;
+    static RDTSCP: AtomicU8 = AtomicU8::new(UNKNOWN);
+
+    match RDTSCP.load(Ordering::Relaxed) {
+        UNKNOWN => {
+            // SAFETY: `cpuid` is part of the x86-64 baseline.
+            #[allow(unused_unsafe)]
+            let max_ext = unsafe { core::arch::x86_64::__cpuid(0x8000_0000) }.eax;
+            let present = max_ext >= 0x8000_0001 && {
+                #[allow(unused_unsafe)]
+                let edx = unsafe { core::arch::x86_64::__cpuid(0x8000_0001) }.edx;
+                edx & (1 << 27) != 0
+            };
+            RDTSCP.store(if present { PRESENT } else { ABSENT }, Ordering::Relaxed);
+            present
+        }
+        state => state == PRESENT,
+    }
+}
+
+/// Read the AArch64 virtual counter register `cntvct_el0`.
+///
+/// The generic timer runs at a fixed frequency (see `cntfrq_el0`), which is
+/// often only tens of MHz. On such systems [`JitterRng::test_timer`] is likely
+/// to reject it as [`TimerError::CoarseTimer`].
+///
+/// [`JitterRng::test_timer`]: crate::JitterRng::test_timer
+/// [`TimerError::CoarseTimer`]: crate::TimerError::CoarseTimer
+#[cfg(target_arch = "aarch64")]
+pub fn cntvct() -> u64 {
+    let t: u64;
+    // SAFETY: reading `cntvct_el0` is permitted from EL0 on all mainstream
+    // operating systems. The `isb` prevents the read from being speculated
+    // ahead of earlier instructions.
+    unsafe {
+        core::arch::asm!(
+            "isb",
+            "mrs {}, cntvct_el0",
+            out(reg) t,
+            options(nomem, nostack, preserves_flags),
+        );
+    }
+    t
+}
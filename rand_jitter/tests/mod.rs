@@ -1,4 +1,18 @@
 use rand_jitter::JitterRng;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+// A timer advancing by pseudo-random amounts, which passes the timer tests.
+fn jittery() -> u64 {
+    static STATE: AtomicU64 = AtomicU64::new(0x853c_49e6_748f_ea9b);
+    static TIME: AtomicU64 = AtomicU64::new(1);
+
+    let mut x = STATE.load(Ordering::Relaxed);
+    x ^= x << 13;
+    x ^= x >> 7;
+    x ^= x << 17;
+    STATE.store(x, Ordering::Relaxed);
+    TIME.fetch_add(1 + (x >> 54), Ordering::Relaxed)
+}
 
 #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
 #[test]
@@ -10,12 +24,9 @@ fn test_jitter_init() {
     // Don't fail this test if initializing `JitterRng` fails because of a
     // bad timer (the timer from the standard library may not have enough
     // accuracy on all platforms).
-    match JitterRng::new() {
-        Ok(ref mut rng) => {
-            // false positives are possible, but extremely unlikely
-            assert!(rng.next_u32() | rng.next_u32() != 0);
-        }
-        Err(_) => {}
+    if let Ok(ref mut rng) = JitterRng::new() {
+        // false positives are possible, but extremely unlikely
+        assert!(rng.next_u32() | rng.next_u32() != 0);
     }
 }
 
@@ -36,3 +47,355 @@ fn test_jitter_closure() {
     let at_start = bad_timer();
     let _ = JitterRng::new_with_timer(move || bad_timer() - at_start);
 }
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[test]
+#[cfg_attr(miri, ignore)] // CLOCK_MONOTONIC_RAW is not supported by Miri
+fn test_jitter_clock_monotonic_raw() {
+    use rand_jitter::timer::clock_monotonic_raw;
+
+    let a = clock_monotonic_raw();
+    let b = clock_monotonic_raw();
+    assert!(a != 0);
+    assert!(b >= a);
+}
+
+#[cfg(target_arch = "x86_64")]
+#[test]
+#[cfg_attr(miri, ignore)] // inline assembly is not supported by Miri
+fn test_jitter_tsc() {
+    use rand_jitter::timer::{rdtsc, rdtscp};
+
+    let a = rdtsc();
+    let b = rdtscp();
+    assert!(a != 0);
+    assert!(b != 0);
+
+    // As in `test_jitter_init`, the timer test itself may legitimately fail
+    // on some (virtualised) hosts.
+    let mut rng = JitterRng::new_with_timer(rdtscp);
+    if let Ok(rounds) = rng.test_timer() {
+        assert!(rounds > 0);
+    }
+}
+
+#[test]
+fn test_jitter_analyze_timer() {
+    use rand_jitter::TimerError;
+
+    fn bad_timer() -> u64 {
+        0
+    }
+    let report = JitterRng::new_with_timer(bad_timer).analyze_timer();
+    assert_eq!(report.rounds, Err(TimerError::NoTimer));
+    assert_eq!(report.samples, 0);
+
+    // A timer that ticks once per call measures the same delta every time.
+    static TICKS: AtomicU64 = AtomicU64::new(1);
+    fn counter() -> u64 {
+        TICKS.fetch_add(1, Ordering::Relaxed)
+    }
+    let mut rng = JitterRng::new_with_timer(counter);
+    let report = rng.analyze_timer();
+    assert_eq!(report.rounds, Err(TimerError::TinyVariations));
+    assert_eq!(report.rounds, rng.test_timer());
+    assert_eq!(report.samples, 300);
+    assert_eq!(report.time_backwards, 0);
+    assert_eq!(report.count_mod, 0);
+    assert!(report.count_stuck > 290);
+    assert!(report.delta_sum < 300);
+    assert_eq!(report.min_entropy, 0.0);
+}
+
+#[test]
+fn test_jitter_calibrated_timer() {
+    use rand_core::RngCore;
+    use rand_jitter::{CalibratedTimer, TimerError};
+
+    fn bad_timer() -> u64 {
+        0
+    }
+    static BAD: CalibratedTimer<fn() -> u64> = CalibratedTimer::new(bad_timer);
+    assert_eq!(BAD.rounds(), Err(TimerError::NoTimer));
+    assert_eq!(BAD.rounds(), Err(TimerError::NoTimer));
+    assert_eq!(
+        JitterRng::new_with_calibrated_timer(&BAD).err(),
+        Some(TimerError::NoTimer)
+    );
+
+    // Every timer keeps its own result.
+    static TICKS: AtomicU64 = AtomicU64::new(1);
+    fn counter() -> u64 {
+        TICKS.fetch_add(1, Ordering::Relaxed)
+    }
+    let slow = CalibratedTimer::new(counter);
+    assert_eq!(slow.rounds(), Err(TimerError::TinyVariations));
+    assert_eq!(BAD.rounds(), Err(TimerError::NoTimer));
+
+    // A timer advancing by pseudo-random amounts passes the tests.
+    static TIMER: CalibratedTimer<fn() -> u64> =
+        CalibratedTimer::new(jittery as fn() -> u64).with_recalibration(2);
+    let rounds = TIMER.rounds().unwrap();
+    assert!(rounds > 0);
+    assert_eq!(TIMER.recalibrate(), Ok(rounds));
+
+    let mut rng = JitterRng::new_with_calibrated_timer(&TIMER).unwrap();
+    for _ in 0..5 {
+        rng.next_u64();
+    }
+}
+
+#[test]
+fn test_jitter_recalibration_failure() {
+    use rand_core::RngCore;
+    use rand_jitter::{CalibratedTimer, TimerError};
+    use std::sync::atomic::AtomicU32;
+
+    // A timer which returns 0 for the next `STOPPED` calls. The timer test
+    // fails after three of them.
+    static STOPPED: AtomicU32 = AtomicU32::new(0);
+    fn stopping() -> u64 {
+        match STOPPED.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| n.checked_sub(1)) {
+            Ok(_) => 0,
+            Err(_) => jittery(),
+        }
+    }
+    static TIMER: CalibratedTimer<fn() -> u64> =
+        CalibratedTimer::new(stopping as fn() -> u64).with_recalibration(2);
+    let mut rng = JitterRng::new_with_calibrated_timer(&TIMER).unwrap();
+    assert!(TIMER.rounds().is_ok());
+    rng.next_u64();
+
+    // The failed test is cached in the `CalibratedTimer`, and the generator
+    // keeps running with the default rounds.
+    STOPPED.store(3, Ordering::Relaxed);
+    rng.next_u64();
+    assert_eq!(STOPPED.load(Ordering::Relaxed), 0);
+    assert_eq!(TIMER.rounds(), Err(TimerError::NoTimer));
+    assert_eq!(
+        JitterRng::new_with_calibrated_timer(&TIMER).err(),
+        Some(TimerError::NoTimer)
+    );
+    rng.next_u64();
+}
+
+#[test]
+fn test_jitter_reseeding() {
+    use rand_core::{RngCore, SeedableRng, utils};
+    use rand_jitter::ReseedingRng;
+
+    // Counts up from the seed, so a reseed shows up as a jump.
+    #[derive(Clone)]
+    struct Counter(u64);
+    impl SeedableRng for Counter {
+        type Seed = [u8; 8];
+        fn from_seed(seed: [u8; 8]) -> Self {
+            Counter(u64::from_le_bytes(seed))
+        }
+    }
+    impl RngCore for Counter {
+        fn next_u32(&mut self) -> u32 {
+            self.next_u64() as u32
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0 = self.0.wrapping_add(1);
+            self.0
+        }
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            utils::fill_bytes_via_next_word(dest, || self.next_u64())
+        }
+    }
+
+    let mut jitter = JitterRng::new_with_timer(jittery);
+    let rounds = jitter.test_timer().unwrap();
+    jitter.set_rounds(rounds);
+
+    // Reseed after 24 bytes.
+    let mut rng = ReseedingRng::<Counter, _>::new(jitter.clone(), 24);
+    let a = rng.next_u64();
+    assert_eq!(rng.next_u64(), a.wrapping_add(1));
+    assert_eq!(rng.next_u32(), a.wrapping_add(2) as u32);
+    // 20 bytes used, so the next `u64` is generated after reseeding.
+    let b = rng.next_u64();
+    assert_ne!(b, a.wrapping_add(3));
+    assert_eq!(rng.next_u64(), b.wrapping_add(1));
+
+    // `fill_bytes` reseeds after every 24 bytes as well.
+    let mut buf = [0u8; 48];
+    rng.fill_bytes(&mut buf);
+    let c = u64::from_le_bytes(buf[0..8].try_into().unwrap());
+    let d = u64::from_le_bytes(buf[24..32].try_into().unwrap());
+    assert_ne!(c, b.wrapping_add(2));
+    assert_ne!(d, c.wrapping_add(3));
+
+    // A clone reseeds before its first output.
+    let mut rng = ReseedingRng::<Counter, _>::new(jitter.clone(), 0);
+    let e = rng.next_u64();
+    let mut clone = rng.clone();
+    assert_ne!(clone.next_u64(), e.wrapping_add(1));
+    assert_eq!(rng.next_u64(), e.wrapping_add(1));
+
+    // The timer advances with every call, so an interval of 1 tick reseeds
+    // for every output.
+    let mut rng = ReseedingRng::<Counter, _>::new(jitter, 0).with_tick_interval(1);
+    let f = rng.next_u64();
+    assert_ne!(rng.next_u64(), f.wrapping_add(1));
+}
+
+#[cfg(feature = "testing")]
+mod scripted {
+    use rand_core::RngCore;
+    use rand_jitter::{JitterRng, TimerError, TimerReport, testing};
+
+    // The result of a complete test run according to the documentation of
+    // `TimerError`, based on the statistics of the run.
+    fn documented(report: &TimerReport) -> Option<TimerError> {
+        assert_eq!(report.samples, 300);
+        if report.time_backwards > 3 {
+            Some(TimerError::NotMonotonic)
+        } else if report.delta_sum < 300 {
+            Some(TimerError::TinyVariations)
+        } else if report.count_mod > 270 {
+            Some(TimerError::CoarseTimer)
+        } else if report.count_stuck > 270 {
+            Some(TimerError::TooManyStuck)
+        } else {
+            None
+        }
+    }
+
+    fn analyze(timer: impl Fn() -> u64 + Send + Sync) -> TimerReport {
+        JitterRng::new_with_timer(timer).analyze_timer()
+    }
+
+    #[test]
+    fn no_timer() {
+        let report = analyze(testing::constant(0));
+        assert_eq!(report.rounds, Err(TimerError::NoTimer));
+        assert_eq!(report.samples, 0);
+
+        // A timer that works for a while, then returns 0.
+        let mut trace: Vec<i64> = (0..500).map(|i| 300 + i * 7919 % 101).collect();
+        let end = testing::START as i64 + trace.iter().sum::<i64>();
+        trace.push(-end);
+        let report = analyze(testing::replay(&trace));
+        assert_eq!(report.rounds, Err(TimerError::NoTimer));
+        assert!(report.samples > 0 && report.samples < 300);
+    }
+
+    #[test]
+    fn coarse_timer() {
+        // Zero deltas end the test immediately.
+        let report = analyze(testing::constant(42));
+        assert_eq!(report.rounds, Err(TimerError::CoarseTimer));
+        assert_eq!(report.samples, 0);
+
+        let report = analyze(testing::coarse(1000, testing::gaussian(100.0, 30.0, 1)));
+        assert_eq!(report.rounds, Err(TimerError::CoarseTimer));
+        assert!(report.samples < 300);
+
+        // Deltas which are all multiples of 100.
+        let report = analyze(testing::coarse(100, testing::gaussian(400.0, 100.0, 2)));
+        assert_eq!(report.count_mod, 300);
+        assert!(report.delta_sum >= 300);
+        assert_eq!(report.rounds.clone().err(), documented(&report));
+        assert_eq!(report.rounds, Err(TimerError::CoarseTimer));
+
+        // A counter advancing by 100 per call measures deltas of 300.
+        let report = analyze(testing::gaussian(100.0, 0.0, 3));
+        assert_eq!(report.delta_sum, 300);
+        assert_eq!(report.count_mod, 300);
+        assert_eq!(report.rounds, Err(TimerError::CoarseTimer));
+    }
+
+    #[test]
+    fn not_monotonic() {
+        let mut failed = false;
+        let mut passed = false;
+        for period in [5, 13, 50, 150, 400, 1000, 0] {
+            let timer = testing::gaussian(1000.0, 100.0, 4);
+            let report = analyze(testing::backwards(period, 10_000, timer));
+            assert_eq!(report.rounds.clone().err(), documented(&report));
+            match report.rounds {
+                Err(TimerError::NotMonotonic) => failed = true,
+                Ok(_) => passed = true,
+                Err(e) => panic!("unexpected error: {}", e),
+            }
+        }
+        assert!(failed && passed);
+    }
+
+    #[test]
+    fn tiny_variations() {
+        // A counter advancing by 99 per call measures deltas of 297, which
+        // only vary from the initial delta of 0.
+        let report = analyze(testing::gaussian(99.0, 0.0, 5));
+        assert_eq!(report.delta_sum, 297);
+        assert_eq!(report.count_mod, 0);
+        assert_eq!(report.rounds.clone().err(), documented(&report));
+        assert_eq!(report.rounds, Err(TimerError::TinyVariations));
+    }
+
+    #[test]
+    fn too_many_stuck() {
+        // With deltas of 303 only the first measurement is not stuck.
+        let report = analyze(testing::gaussian(101.0, 0.0, 6));
+        assert_eq!(report.delta_sum, 303);
+        assert_eq!(report.count_mod, 0);
+        assert_eq!(report.count_stuck, 299);
+        assert_eq!(report.rounds.clone().err(), documented(&report));
+        assert_eq!(report.rounds, Err(TimerError::TooManyStuck));
+    }
+
+    #[test]
+    fn gaussian_passes() {
+        let report = analyze(testing::gaussian(1000.0, 100.0, 7));
+        assert_eq!(documented(&report), None);
+        assert!(report.rounds.is_ok());
+    }
+
+    fn outputs(timer: impl Fn() -> u64 + Send + Sync) -> [u64; 4] {
+        let mut rng = JitterRng::new_with_timer(timer);
+        let rounds = rng.test_timer().unwrap();
+        rng.set_rounds(rounds);
+        [
+            rng.next_u64(),
+            rng.next_u64(),
+            rng.next_u64(),
+            rng.next_u64(),
+        ]
+    }
+
+    #[test]
+    fn replay_reproducible() {
+        let trace: Vec<i64> = (0..4096).map(|i| 200 + i * 7919 % 97).collect();
+        let results = outputs(testing::replay(&trace));
+        assert_eq!(outputs(testing::replay(&trace)), results);
+        assert_eq!(
+            results,
+            [
+                17720642955782530769,
+                15854940782595356617,
+                5991432258789468196,
+                17494605033398244232
+            ]
+        );
+
+        // Replaying a recorded trace reproduces the output with the original
+        // timer.
+        let timer = testing::gaussian(1000.0, 100.0, 8);
+        let mut prev = timer();
+        let recorded: Vec<i64> = (0..100_000)
+            .map(|_| {
+                let t = timer();
+                let delta = t.wrapping_sub(prev) as i64;
+                prev = t;
+                delta
+            })
+            .collect();
+        assert_eq!(
+            outputs(testing::replay(&recorded)),
+            outputs(testing::gaussian(1000.0, 100.0, 8))
+        );
+    }
+}
@@ -0,0 +1,173 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Jumping ahead with the characteristic polynomial, see Haramoto et al.,
+//! "Efficient Jump Ahead for F2-Linear Random Number Generators", INFORMS
+//! Journal on Computing 20(3), 2008.
+//!
+//! The state transition `T` of both generators is linear over GF(2), with
+//! characteristic polynomial `φ` of degree 19937. To advance by `n` steps, we
+//! compute `g(x) = x^n mod φ(x)`, and then `T^n s = g(T) s` with Horner's
+//! method in `DEGREE` steps.
+
+/// The degree of the characteristic polynomial of both generators.
+pub(crate) const DEGREE: usize = 19937;
+
+// Number of words of a polynomial of degree less than `DEGREE`.
+const WORDS: usize = DEGREE / 64 + 1;
+
+type Poly = [u64; WORDS];
+
+/// The state of a generator as a linear recurrence.
+pub(crate) trait Recurrence {
+    /// The zero state.
+    fn zero() -> Self;
+    /// Advance by one step.
+    fn step(&mut self);
+    /// Add `other` to `self`.
+    fn add(&mut self, other: &Self);
+}
+
+/// Advance `state` by `n` steps.
+///
+/// `terms` are the exponents of the non-leading terms of the characteristic
+/// polynomial, in increasing order.
+pub(crate) fn jump<R: Recurrence>(state: &R, n: u128, terms: &[u16]) -> R {
+    let g = pow_x(n, terms);
+    let mut result = R::zero();
+    for i in (0..DEGREE).rev() {
+        result.step();
+        if (g[i / 64] >> (i % 64)) & 1 != 0 {
+            result.add(state);
+        }
+    }
+    result
+}
+
+// Compute `x^n mod φ(x)`, by squaring and multiplying from the most
+// significant bit of `n`.
+fn pow_x(n: u128, terms: &[u16]) -> Poly {
+    let mut p = [0; WORDS];
+    p[0] = 1;
+    for bit in (0..128 - n.leading_zeros()).rev() {
+        square(&mut p, terms);
+        if (n >> bit) & 1 != 0 {
+            mul_x(&mut p, terms);
+        }
+    }
+    p
+}
+
+// Spread the bits of `x` to the even bit positions, which squares it as a
+// polynomial.
+fn spread(x: u32) -> u64 {
+    let mut x = u64::from(x);
+    x = (x | (x << 16)) & 0x0000_FFFF_0000_FFFF;
+    x = (x | (x << 8)) & 0x00FF_00FF_00FF_00FF;
+    x = (x | (x << 4)) & 0x0F0F_0F0F_0F0F_0F0F;
+    x = (x | (x << 2)) & 0x3333_3333_3333_3333;
+    (x | (x << 1)) & 0x5555_5555_5555_5555
+}
+
+// Add `bits` to `p`, starting at bit `pos`.
+#[inline]
+fn xor_at(p: &mut [u64], pos: usize, bits: u64) {
+    let (word, shift) = (pos / 64, pos % 64);
+    p[word] ^= bits << shift;
+    if shift != 0 {
+        p[word + 1] ^= bits >> (64 - shift);
+    }
+}
+
+fn square(p: &mut Poly, terms: &[u16]) {
+    let mut sq = [0u64; 2 * WORDS];
+    for (i, &w) in p.iter().enumerate() {
+        sq[2 * i] = spread(w as u32);
+        sq[2 * i + 1] = spread((w >> 32) as u32);
+    }
+
+    // Replace `x^d` by `x^(d - DEGREE) (φ(x) - x^DEGREE)` for every `d` of at
+    // least `DEGREE`, 64 bits at a time from the top. The gap below the
+    // leading term of `φ` is larger than 64, so this never adds bits above the
+    // bits being reduced.
+    debug_assert!(DEGREE - usize::from(terms[terms.len() - 1]) > 64);
+    for i in (DEGREE / 64..2 * WORDS).rev() {
+        let (bits, base) = if i == DEGREE / 64 {
+            let bits = sq[i] >> (DEGREE % 64);
+            sq[i] ^= bits << (DEGREE % 64);
+            (bits, DEGREE)
+        } else {
+            let bits = sq[i];
+            sq[i] = 0;
+            (bits, 64 * i)
+        };
+        if bits != 0 {
+            for &t in terms {
+                xor_at(&mut sq, base - DEGREE + usize::from(t), bits);
+            }
+        }
+    }
+    p.copy_from_slice(&sq[..WORDS]);
+}
+
+fn mul_x(p: &mut Poly, terms: &[u16]) {
+    let mut carry = 0;
+    for w in p.iter_mut() {
+        let next = *w >> 63;
+        *w = (*w << 1) | carry;
+        carry = next;
+    }
+    let top = (p[DEGREE / 64] >> (DEGREE % 64)) & 1;
+    if top != 0 {
+        p[DEGREE / 64] ^= 1 << (DEGREE % 64);
+        for &t in terms {
+            let t = usize::from(t);
+            p[t / 64] ^= 1 << (t % 64);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // x^DEGREE + x + 1
+    const TERMS: [u16; 2] = [0, 1];
+
+    #[test]
+    fn small_powers() {
+        // Below the degree, powers of x are single bits.
+        for n in [0u128, 1, 63, 64, 1000, DEGREE as u128 - 1] {
+            let p = pow_x(n, &TERMS);
+            for (i, &w) in p.iter().enumerate() {
+                let expected = if i == n as usize / 64 {
+                    1 << (n % 64)
+                } else {
+                    0
+                };
+                assert_eq!(w, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn reduction() {
+        // x^DEGREE = x + 1, and x^(DEGREE + 100) = x^101 + x^100
+        assert_eq!(pow_x(DEGREE as u128, &TERMS)[0], 0b11);
+        let p = pow_x(DEGREE as u128 + 100, &TERMS);
+        assert_eq!(p[1], 0b11 << 36);
+        assert_eq!(p.iter().map(|w| w.count_ones()).sum::<u32>(), 2);
+
+        // Squaring and multiplying by x agree.
+        let a = pow_x(2 * DEGREE as u128 + 7, &TERMS);
+        let mut b = pow_x(DEGREE as u128 + 3, &TERMS);
+        square(&mut b, &TERMS);
+        mul_x(&mut b, &TERMS);
+        assert_eq!(a, b);
+    }
+}
@@ -0,0 +1,71 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! This crate implements the Mersenne Twister generators MT19937 and
+//! MT19937-64 by Makoto Matsumoto and Takuji Nishimura, and the related
+//! generators dSFMT-19937, WELL512a and WELL1024a.
+//!
+//! The Mersenne Twister is the generator of `std::mt19937` in C++, the
+//! `random` module of Python, and many other languages and libraries. It has a
+//! long period and is fast, but it has a large state, it fails some
+//! statistical tests, and it is [predictable]. Better generators are available
+//! for new code; this crate is meant for reproducing numbers generated by
+//! other software.
+//!
+//! This crate provides:
+//! - [`Mt19937`]: 32-bit output, equal to `std::mt19937` and Python's
+//!   `random`.
+//! - [`Mt19937_64`]: 64-bit output, equal to `std::mt19937_64`.
+//! - [`Dsfmt19937`]: a variant of the Mersenne Twister which generates `f64`
+//!   values directly, equal to the reference implementation of dSFMT.
+//! - [`Well512a`] and [`Well1024a`]: WELL generators, with smaller states and
+//!   better equidistribution than the Mersenne Twister, equal to their
+//!   reference implementations.
+//!
+//! The Mersenne Twisters can be seeded like the reference implementation, and can jump ahead
+//! with `discard`:
+//!
+//! ```
+//! use rand_core::RngCore;
+//! use rand_mersenne::Mt19937;
+//!
+//! // Like `std::mt19937 rng(5489); rng.discard(9999);`
+//! let mut rng = Mt19937::new(5489);
+//! rng.discard(9999);
+//! assert_eq!(rng.next_u32(), 4123659995);
+//!
+//! // Like `random.seed(42)` in Python.
+//! let mut rng = Mt19937::from_python_seed(42);
+//! assert_eq!(rng.next_u32(), 2746317213);
+//! ```
+//!
+//! [predictable]: https://en.wikipedia.org/wiki/Mersenne_Twister#Disadvantages
+
+#![doc(
+    html_logo_url = "https://www.rust-lang.org/logos/rust-logo-128x128-blk.png",
+    html_favicon_url = "https://www.rust-lang.org/favicon.ico"
+)]
+#![forbid(unsafe_code)]
+#![deny(missing_docs)]
+#![deny(missing_debug_implementations)]
+#![doc(test(attr(allow(unused_variables), deny(warnings))))]
+#![no_std]
+
+mod dsfmt;
+mod jump;
+mod mt19937;
+mod mt19937_64;
+mod well1024;
+mod well512;
+
+pub use dsfmt::Dsfmt19937;
+pub use mt19937::Mt19937;
+pub use mt19937_64::Mt19937_64;
+pub use rand_core;
+pub use well512::Well512a;
+pub use well1024::Well1024a;
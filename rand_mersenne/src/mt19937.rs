@@ -0,0 +1,410 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::jump::{self, Recurrence};
+use core::fmt;
+use rand_core::{RngCore, SeedableRng, utils};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const N: usize = 624;
+const M: usize = 397;
+const MATRIX_A: u32 = 0x9908_B0DF;
+const UPPER_MASK: u32 = 0x8000_0000;
+const LOWER_MASK: u32 = 0x7FFF_FFFF;
+
+// Below this number of steps, `discard` is faster by generating and
+// discarding the output than by jumping.
+const JUMP_THRESHOLD: u128 = 1 << 24;
+
+/// The 32-bit Mersenne Twister MT19937, by Makoto Matsumoto and Takuji
+/// Nishimura.
+///
+/// This is the generator of `std::mt19937` in C++ and of the `random` module
+/// of Python. It has a period of 2<sup>19937</sup> - 1, but a large state of
+/// 2.5 KiB, and it fails some statistical tests of linearity. It is not
+/// cryptographically secure: the state can be recovered from 624 outputs.
+///
+/// Its main use is reproducing numbers generated by other software, which is
+/// why it can be seeded in the same ways as the reference implementation
+/// `mt19937ar.c`: with [`new`] like `init_genrand`, with [`new_by_array`] like
+/// `init_by_array`, and with [`from_python_seed`] like `random.seed` in
+/// Python. `SeedableRng::from_seed` uses `init_by_array` with the seed as
+/// eight little-endian words.
+///
+/// `next_u64` combines two outputs, the first as the low word, like
+/// `random.getrandbits(64)` in Python.
+///
+/// [`new`]: Mt19937::new
+/// [`new_by_array`]: Mt19937::new_by_array
+/// [`from_python_seed`]: Mt19937::from_python_seed
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Mt19937 {
+    #[cfg_attr(feature = "serde", serde(with = "serde_arrays"))]
+    state: [u32; N],
+    // The index of the next output in `state`, `N` or larger if all are used.
+    index: usize,
+}
+
+// Custom Debug implementation that does not expose the internal state
+impl fmt::Debug for Mt19937 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Mt19937 {{}}")
+    }
+}
+
+impl Mt19937 {
+    /// Create a generator like `init_genrand(seed)` of the reference
+    /// implementation, and `std::mt19937(seed)` in C++.
+    ///
+    /// The default seed of `std::mt19937` is 5489.
+    pub fn new(seed: u32) -> Self {
+        let mut state = [0; N];
+        state[0] = seed;
+        for i in 1..N {
+            let prev = state[i - 1];
+            state[i] = 1812433253u32
+                .wrapping_mul(prev ^ (prev >> 30))
+                .wrapping_add(i as u32);
+        }
+        Mt19937 { state, index: N }
+    }
+
+    /// Create a generator like `init_by_array(key)` of the reference
+    /// implementation.
+    ///
+    /// # Panics
+    ///
+    /// If `key` is empty.
+    pub fn new_by_array(key: &[u32]) -> Self {
+        assert!(!key.is_empty(), "key must not be empty");
+        let mut rng = Mt19937::new(19650218);
+        let mt = &mut rng.state;
+        let mut i = 1;
+        let mut j = 0;
+        for _ in 0..N.max(key.len()) {
+            let prev = mt[i - 1];
+            mt[i] = (mt[i] ^ (prev ^ (prev >> 30)).wrapping_mul(1664525))
+                .wrapping_add(key[j])
+                .wrapping_add(j as u32);
+            i += 1;
+            j += 1;
+            if i >= N {
+                mt[0] = mt[N - 1];
+                i = 1;
+            }
+            if j >= key.len() {
+                j = 0;
+            }
+        }
+        for _ in 0..N - 1 {
+            let prev = mt[i - 1];
+            mt[i] = (mt[i] ^ (prev ^ (prev >> 30)).wrapping_mul(1566083941)).wrapping_sub(i as u32);
+            i += 1;
+            if i >= N {
+                mt[0] = mt[N - 1];
+                i = 1;
+            }
+        }
+        // The MSB is 1, assuring a non-zero initial state.
+        mt[0] = 0x8000_0000;
+        rng
+    }
+
+    /// Create a generator like `random.seed(seed)` in Python, for an integer
+    /// `seed`.
+    ///
+    /// Python uses `init_by_array` with the absolute value of the seed split
+    /// into 32-bit words, least significant first. Larger seeds can be used
+    /// by passing their words to [`new_by_array`](Mt19937::new_by_array).
+    pub fn from_python_seed(seed: i128) -> Self {
+        let mut n = seed.unsigned_abs();
+        let mut key = [0u32; 4];
+        let mut len = 0;
+        while len == 0 || n != 0 {
+            key[len] = n as u32;
+            n >>= 32;
+            len += 1;
+        }
+        Mt19937::new_by_array(&key[..len])
+    }
+
+    /// Advance the generator by `n` outputs of `next_u32`, like
+    /// `std::mt19937::discard(n)` in C++.
+    ///
+    /// Large jumps use the characteristic polynomial of the generator, and
+    /// take about the same time as generating 2<sup>24</sup> outputs
+    /// regardless of `n`.
+    pub fn discard(&mut self, n: u128) {
+        let remaining = (N - self.index.min(N)) as u128;
+        if n <= remaining {
+            self.index += n as usize;
+            return;
+        }
+        let n = n - remaining;
+        if n < JUMP_THRESHOLD {
+            for _ in 0..n / N as u128 {
+                self.twist();
+            }
+            self.index = N;
+            let rest = (n % N as u128) as usize;
+            if rest > 0 {
+                self.twist();
+                self.index = rest;
+            }
+        } else {
+            self.jump(n);
+        }
+    }
+
+    // Jump ahead by `n` words, starting with all outputs used.
+    fn jump(&mut self, n: u128) {
+        let window = Window {
+            words: self.state,
+            start: 0,
+        };
+        let window = jump::jump(&window, n, &CHAR_POLY);
+        for (k, w) in self.state.iter_mut().enumerate() {
+            *w = window.words[(window.start + k) % N];
+        }
+
+        // The jump only determines the upper bit of the oldest word, which
+        // is all the recurrence uses. Recover the other bits from the last
+        // word, so that the state is the same as after stepping.
+        let mut z = self.state[N - 1] ^ self.state[M - 1];
+        let odd = z & UPPER_MASK != 0;
+        if odd {
+            z ^= MATRIX_A;
+        }
+        let lower = ((z << 1) | odd as u32) & LOWER_MASK;
+        self.state[0] = (self.state[0] & UPPER_MASK) | lower;
+        self.index = N;
+    }
+
+    // Generate the next `N` words.
+    fn twist(&mut self) {
+        let mt = &mut self.state;
+        for i in 0..N {
+            let y = (mt[i] & UPPER_MASK) | (mt[(i + 1) % N] & LOWER_MASK);
+            let mag = if y & 1 != 0 { MATRIX_A } else { 0 };
+            mt[i] = mt[(i + M) % N] ^ (y >> 1) ^ mag;
+        }
+        self.index = 0;
+    }
+}
+
+impl RngCore for Mt19937 {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        if self.index >= N {
+            self.twist();
+        }
+        let mut y = self.state[self.index];
+        self.index += 1;
+
+        // Tempering
+        y ^= y >> 11;
+        y ^= (y << 7) & 0x9D2C_5680;
+        y ^= (y << 15) & 0xEFC6_0000;
+        y ^ (y >> 18)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        utils::next_u64_via_u32(self)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        utils::fill_bytes_via_next_word(dest, || self.next_u32());
+    }
+}
+
+impl SeedableRng for Mt19937 {
+    type Seed = [u8; 32];
+
+    /// Create a generator with `init_by_array`, using the seed as eight
+    /// little-endian `u32` words.
+    fn from_seed(seed: [u8; 32]) -> Self {
+        let key: [u32; 8] = utils::read_words(&seed);
+        Mt19937::new_by_array(&key)
+    }
+}
+
+// The last `N` words of the sequence, in a ring buffer starting at `start`.
+#[derive(Clone)]
+struct Window {
+    words: [u32; N],
+    start: usize,
+}
+
+impl Recurrence for Window {
+    fn zero() -> Self {
+        Window {
+            words: [0; N],
+            start: 0,
+        }
+    }
+
+    #[inline]
+    fn step(&mut self) {
+        let i = self.start;
+        let w = &mut self.words;
+        let y = (w[i] & UPPER_MASK) | (w[(i + 1) % N] & LOWER_MASK);
+        let mag = if y & 1 != 0 { MATRIX_A } else { 0 };
+        w[i] = w[(i + M) % N] ^ (y >> 1) ^ mag;
+        self.start = (i + 1) % N;
+    }
+
+    fn add(&mut self, other: &Self) {
+        for k in 0..N {
+            self.words[(self.start + k) % N] ^= other.words[(other.start + k) % N];
+        }
+    }
+}
+
+// The exponents of the non-leading terms of the characteristic polynomial
+// of MT19937, computed with the Berlekamp-Massey algorithm from its output.
+const CHAR_POLY: [u16; 134] = [
+    0, 1189, 1416, 1585, 1643, 1870, 2493, 2773, 3000, 3227, 3454, 3681, 3908, 4135, 4362, 4753,
+    5661, 6337, 6569, 7129, 7477, 7525, 7583, 7752, 7979, 8206, 9505, 9901, 9969, 10128, 10693,
+    10761, 10920, 11089, 11147, 11157, 11215, 11321, 11374, 11384, 11485, 11611, 11712, 11717,
+    11838, 11881, 11944, 11997, 12277, 12335, 12393, 12504, 12509, 12620, 12673, 12731, 12736,
+    12789, 12905, 12958, 12963, 13137, 13185, 13190, 13243, 13301, 13412, 13528, 13533, 13639,
+    13697, 13760, 13813, 13866, 14093, 14151, 14209, 14320, 14325, 14436, 14547, 14552, 14605,
+    14721, 14774, 14779, 14953, 15001, 15006, 15059, 15117, 15228, 15344, 15349, 15455, 15513,
+    15576, 15629, 15682, 15909, 15967, 16025, 16136, 16141, 16252, 16363, 16368, 16421, 16537,
+    16590, 16595, 16817, 16822, 16875, 16933, 17044, 17160, 17271, 17329, 17445, 17498, 17725,
+    17783, 17841, 17952, 18068, 18179, 18237, 18406, 18633, 18691, 18860, 19087, 19314,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference() {
+        // These values are the output of `std::mt19937` of libstdc++, whose
+        // 10000th output for the default seed is required by the C++ standard.
+        let mut rng = Mt19937::new(5489);
+        let expected = [3499211612, 581869302, 3890346734, 3586334585];
+        for e in expected {
+            assert_eq!(rng.next_u32(), e);
+        }
+        let mut rng = Mt19937::new(5489);
+        for _ in 0..9999 {
+            rng.next_u32();
+        }
+        assert_eq!(rng.next_u32(), 4123659995);
+    }
+
+    #[test]
+    fn reference_by_array() {
+        // These values are the first outputs of the test program of the
+        // reference implementation `mt19937ar.c`:
+        // http://www.math.sci.hiroshima-u.ac.jp/m-mat/MT/MT2002/emt19937ar.html
+        let mut rng = Mt19937::new_by_array(&[0x123, 0x234, 0x345, 0x456]);
+        let expected = [1067595299, 955945823, 477289528, 4107218783, 4228976476];
+        for e in expected {
+            assert_eq!(rng.next_u32(), e);
+        }
+    }
+
+    #[test]
+    fn python() {
+        // `random.seed(42); [random.getrandbits(32) for _ in range(4)]`
+        let mut rng = Mt19937::from_python_seed(42);
+        let expected = [2746317213, 478163327, 107420369, 3184935163];
+        for e in expected {
+            assert_eq!(rng.next_u32(), e);
+        }
+
+        // Python uses the absolute value of negative seeds.
+        assert_eq!(
+            Mt19937::from_python_seed(-42),
+            Mt19937::from_python_seed(42)
+        );
+
+        // `random.seed(2**40 + 5); [random.getrandbits(32) for _ in range(2)]`
+        let mut rng = Mt19937::from_python_seed((1 << 40) + 5);
+        assert_eq!(rng.next_u32(), 2166296868);
+        assert_eq!(rng.next_u32(), 2220160828);
+
+        // `random.seed(0); random.getrandbits(64)`
+        let mut rng = Mt19937::from_python_seed(0);
+        assert_eq!(rng.next_u64(), (1654615998 << 32) | 3626764237);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // too slow
+    fn discard() {
+        // `std::mt19937 rng(1); rng.discard(1000000007);`
+        let mut rng = Mt19937::new(1);
+        rng.discard(1000000007);
+        assert_eq!(rng.next_u32(), 3011563551);
+        assert_eq!(rng.next_u32(), 515739572);
+
+        let mut rng = Mt19937::new(5489);
+        rng.next_u32();
+        rng.discard(9998);
+        assert_eq!(rng.next_u32(), 4123659995);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // too slow
+    fn jump() {
+        // Compare jumps to generating the output. After a multiple of N
+        // outputs, the state is the same.
+        for n in [1, 623, 624, 625, 5000, 3 * N, 40000] {
+            let mut stepped = Mt19937::new(7);
+            let mut jumped = stepped.clone();
+            for _ in 0..n {
+                stepped.next_u32();
+            }
+            jumped.jump(n as u128);
+            if n % N == 0 {
+                assert_eq!(jumped, stepped);
+            }
+            for _ in 0..2 * N {
+                assert_eq!(jumped.next_u32(), stepped.next_u32());
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // too slow
+    fn char_poly() {
+        // Check that the characteristic polynomial annihilates the output.
+        let mut rng = Mt19937::new(1);
+        let mut bits = [0u8; 2 * jump::DEGREE];
+        for b in bits.iter_mut() {
+            *b = (rng.next_u32() & 1) as u8;
+        }
+        for i in 0..jump::DEGREE {
+            let mut sum = bits[i + jump::DEGREE];
+            for &t in CHAR_POLY.iter() {
+                sum ^= bits[i + usize::from(t)];
+            }
+            assert_eq!(sum, 0);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let mut rng = Mt19937::new(5489);
+        rng.next_u32();
+        let buf = postcard::to_allocvec(&rng).unwrap();
+        let mut deserialized: Mt19937 = postcard::from_bytes(&buf).unwrap();
+        assert_eq!(deserialized, rng);
+        for _ in 0..1000 {
+            assert_eq!(rng.next_u32(), deserialized.next_u32());
+        }
+    }
+}
@@ -0,0 +1,388 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::jump::{self, Recurrence};
+use core::fmt;
+use rand_core::{RngCore, SeedableRng, utils};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const N: usize = 312;
+const M: usize = 156;
+const MATRIX_A: u64 = 0xB502_6F5A_A966_19E9;
+const UPPER_MASK: u64 = 0xFFFF_FFFF_8000_0000;
+const LOWER_MASK: u64 = 0x7FFF_FFFF;
+
+// Below this number of steps, `discard` is faster by generating and
+// discarding the output than by jumping.
+const JUMP_THRESHOLD: u128 = 1 << 24;
+
+/// The 64-bit Mersenne Twister MT19937-64, by Takuji Nishimura and Makoto
+/// Matsumoto.
+///
+/// This is the generator of `std::mt19937_64` in C++. Like [`Mt19937`], it
+/// has a period of 2<sup>19937</sup> - 1 and a state of 2.5 KiB, and it is not
+/// cryptographically secure.
+///
+/// It can be seeded in the same ways as the reference implementation
+/// `mt19937-64.c`: with [`new`] like `init_genrand64`, and with
+/// [`new_by_array`] like `init_by_array64`. `SeedableRng::from_seed` uses
+/// `init_by_array64` with the seed as four little-endian words.
+///
+/// `next_u32` returns the upper half of an output of `next_u64`.
+///
+/// [`Mt19937`]: crate::Mt19937
+/// [`new`]: Mt19937_64::new
+/// [`new_by_array`]: Mt19937_64::new_by_array
+#[allow(non_camel_case_types)]
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Mt19937_64 {
+    #[cfg_attr(feature = "serde", serde(with = "serde_arrays"))]
+    state: [u64; N],
+    // The index of the next output in `state`, `N` or larger if all are used.
+    index: usize,
+}
+
+// Custom Debug implementation that does not expose the internal state
+impl fmt::Debug for Mt19937_64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Mt19937_64 {{}}")
+    }
+}
+
+impl Mt19937_64 {
+    /// Create a generator like `init_genrand64(seed)` of the reference
+    /// implementation, and `std::mt19937_64(seed)` in C++.
+    ///
+    /// The default seed of `std::mt19937_64` is 5489.
+    pub fn new(seed: u64) -> Self {
+        let mut state = [0; N];
+        state[0] = seed;
+        for i in 1..N {
+            let prev = state[i - 1];
+            state[i] = 6364136223846793005u64
+                .wrapping_mul(prev ^ (prev >> 62))
+                .wrapping_add(i as u64);
+        }
+        Mt19937_64 { state, index: N }
+    }
+
+    /// Create a generator like `init_by_array64(key)` of the reference
+    /// implementation.
+    ///
+    /// # Panics
+    ///
+    /// If `key` is empty.
+    pub fn new_by_array(key: &[u64]) -> Self {
+        assert!(!key.is_empty(), "key must not be empty");
+        let mut rng = Mt19937_64::new(19650218);
+        let mt = &mut rng.state;
+        let mut i = 1;
+        let mut j = 0;
+        for _ in 0..N.max(key.len()) {
+            let prev = mt[i - 1];
+            mt[i] = (mt[i] ^ (prev ^ (prev >> 62)).wrapping_mul(3935559000370003845))
+                .wrapping_add(key[j])
+                .wrapping_add(j as u64);
+            i += 1;
+            j += 1;
+            if i >= N {
+                mt[0] = mt[N - 1];
+                i = 1;
+            }
+            if j >= key.len() {
+                j = 0;
+            }
+        }
+        for _ in 0..N - 1 {
+            let prev = mt[i - 1];
+            mt[i] = (mt[i] ^ (prev ^ (prev >> 62)).wrapping_mul(2862933555777941757))
+                .wrapping_sub(i as u64);
+            i += 1;
+            if i >= N {
+                mt[0] = mt[N - 1];
+                i = 1;
+            }
+        }
+        // The MSB is 1, assuring a non-zero initial state.
+        mt[0] = 1 << 63;
+        rng
+    }
+
+    /// Advance the generator by `n` outputs of `next_u64`, like
+    /// `std::mt19937_64::discard(n)` in C++.
+    ///
+    /// Large jumps use the characteristic polynomial of the generator, and
+    /// take about the same time as generating 2<sup>24</sup> outputs
+    /// regardless of `n`.
+    pub fn discard(&mut self, n: u128) {
+        let remaining = (N - self.index.min(N)) as u128;
+        if n <= remaining {
+            self.index += n as usize;
+            return;
+        }
+        let n = n - remaining;
+        if n < JUMP_THRESHOLD {
+            for _ in 0..n / N as u128 {
+                self.twist();
+            }
+            self.index = N;
+            let rest = (n % N as u128) as usize;
+            if rest > 0 {
+                self.twist();
+                self.index = rest;
+            }
+        } else {
+            self.jump(n);
+        }
+    }
+
+    // Jump ahead by `n` words, starting with all outputs used.
+    fn jump(&mut self, n: u128) {
+        let window = Window {
+            words: self.state,
+            start: 0,
+        };
+        let window = jump::jump(&window, n, &CHAR_POLY);
+        for (k, w) in self.state.iter_mut().enumerate() {
+            *w = window.words[(window.start + k) % N];
+        }
+
+        // The jump only determines the upper bits of the oldest word, which
+        // are all the recurrence uses. Recover the lower bits from the last
+        // word, so that the state is the same as after stepping.
+        let mut z = self.state[N - 1] ^ self.state[M - 1];
+        let odd = z & (1 << 63) != 0;
+        if odd {
+            z ^= MATRIX_A;
+        }
+        let lower = ((z << 1) | odd as u64) & LOWER_MASK;
+        self.state[0] = (self.state[0] & UPPER_MASK) | lower;
+        self.index = N;
+    }
+
+    // Generate the next `N` words.
+    fn twist(&mut self) {
+        let mt = &mut self.state;
+        for i in 0..N {
+            let y = (mt[i] & UPPER_MASK) | (mt[(i + 1) % N] & LOWER_MASK);
+            let mag = if y & 1 != 0 { MATRIX_A } else { 0 };
+            mt[i] = mt[(i + M) % N] ^ (y >> 1) ^ mag;
+        }
+        self.index = 0;
+    }
+}
+
+impl RngCore for Mt19937_64 {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        if self.index >= N {
+            self.twist();
+        }
+        let mut y = self.state[self.index];
+        self.index += 1;
+
+        // Tempering
+        y ^= (y >> 29) & 0x5555_5555_5555_5555;
+        y ^= (y << 17) & 0x71D6_7FFF_EDA6_0000;
+        y ^= (y << 37) & 0xFFF7_EEE0_0000_0000;
+        y ^ (y >> 43)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        utils::fill_bytes_via_next_word(dest, || self.next_u64());
+    }
+}
+
+impl SeedableRng for Mt19937_64 {
+    type Seed = [u8; 32];
+
+    /// Create a generator with `init_by_array64`, using the seed as four
+    /// little-endian `u64` words.
+    fn from_seed(seed: [u8; 32]) -> Self {
+        let key: [u64; 4] = utils::read_words(&seed);
+        Mt19937_64::new_by_array(&key)
+    }
+}
+
+// The last `N` words of the sequence, in a ring buffer starting at `start`.
+#[derive(Clone)]
+struct Window {
+    words: [u64; N],
+    start: usize,
+}
+
+impl Recurrence for Window {
+    fn zero() -> Self {
+        Window {
+            words: [0; N],
+            start: 0,
+        }
+    }
+
+    #[inline]
+    fn step(&mut self) {
+        let i = self.start;
+        let w = &mut self.words;
+        let y = (w[i] & UPPER_MASK) | (w[(i + 1) % N] & LOWER_MASK);
+        let mag = if y & 1 != 0 { MATRIX_A } else { 0 };
+        w[i] = w[(i + M) % N] ^ (y >> 1) ^ mag;
+        self.start = (i + 1) % N;
+    }
+
+    fn add(&mut self, other: &Self) {
+        for k in 0..N {
+            self.words[(self.start + k) % N] ^= other.words[(other.start + k) % N];
+        }
+    }
+}
+
+// The exponents of the non-leading terms of the characteristic polynomial
+// of MT19937-64, computed with the Berlekamp-Massey algorithm from its
+// output.
+const CHAR_POLY: [u16; 284] = [
+    0, 312, 468, 1092, 1248, 1716, 1872, 2028, 2496, 2652, 2808, 3120, 3276, 3432, 3588, 3900,
+    4056, 4368, 4680, 4992, 5303, 5460, 5613, 5615, 5616, 6078, 6084, 6234, 6237, 6240, 6388, 6390,
+    6396, 6543, 6544, 6546, 6552, 6702, 6855, 6858, 6864, 7008, 7014, 7163, 7164, 7170, 7176, 7475,
+    7632, 7636, 7644, 7787, 7788, 7791, 7792, 7938, 7956, 8093, 8094, 8099, 8103, 8112, 8250, 8256,
+    8268, 8406, 8411, 8412, 8558, 8713, 8714, 8717, 8723, 8868, 8870, 8880, 9023, 9024, 9026, 9035,
+    9036, 9048, 9182, 9333, 9335, 9338, 9347, 9360, 9494, 9650, 9798, 9953, 9954, 9957, 9961, 9984,
+    10110, 10116, 10266, 10271, 10272, 10295, 10422, 10434, 10578, 10581, 10583, 10589, 10590,
+    10605, 10607, 10734, 10746, 10890, 10902, 11046, 11054, 11070, 11202, 11205, 11209, 11210,
+    11213, 11226, 11229, 11358, 11364, 11366, 11380, 11382, 11514, 11519, 11520, 11522, 11535,
+    11536, 11538, 11670, 11678, 11694, 11826, 11829, 11831, 11834, 11847, 11850, 11982, 11990,
+    12000, 12006, 12138, 12146, 12155, 12156, 12162, 12294, 12450, 12453, 12457, 12467, 12606,
+    12612, 12624, 12628, 12762, 12767, 12768, 12779, 12780, 12783, 12784, 12918, 12930, 13074,
+    13077, 13079, 13085, 13086, 13091, 13095, 13230, 13242, 13248, 13386, 13398, 13403, 13404,
+    13542, 13550, 13698, 13701, 13705, 13706, 13709, 13715, 13854, 13860, 13862, 13872, 14010,
+    14015, 14016, 14018, 14027, 14028, 14166, 14174, 14322, 14325, 14327, 14330, 14339, 14478,
+    14486, 14634, 14642, 14790, 14946, 14949, 14953, 15102, 15108, 15258, 15263, 15264, 15414,
+    15426, 15570, 15573, 15575, 15581, 15582, 15726, 15738, 15882, 15894, 16038, 16046, 16194,
+    16197, 16201, 16202, 16205, 16350, 16356, 16358, 16506, 16511, 16512, 16514, 16662, 16670,
+    16818, 16821, 16823, 16826, 16974, 16982, 17130, 17138, 17286, 17442, 17445, 17449, 17598,
+    17604, 17754, 17759, 17760, 17910, 18066, 18069, 18071, 18222, 18378, 18534, 18690, 18693,
+    18846, 19002, 19158, 19314, 19470, 19626,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference() {
+        // These values are the output of `std::mt19937_64` of libstdc++, whose
+        // 10000th output for the default seed is required by the C++ standard.
+        let mut rng = Mt19937_64::new(5489);
+        let expected = [
+            14514284786278117030,
+            4620546740167642908,
+            13109570281517897720,
+            17462938647148434322,
+        ];
+        for e in expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+        let mut rng = Mt19937_64::new(5489);
+        for _ in 0..9999 {
+            rng.next_u64();
+        }
+        assert_eq!(rng.next_u64(), 9981545732273789042);
+    }
+
+    #[test]
+    fn reference_by_array() {
+        // These values are the first outputs of the test program of the
+        // reference implementation `mt19937-64.c`:
+        // http://www.math.sci.hiroshima-u.ac.jp/m-mat/MT/VERSIONS/C-LANG/mt19937-64.c
+        let mut rng = Mt19937_64::new_by_array(&[0x12345, 0x23456, 0x34567, 0x45678]);
+        let expected = [
+            7266447313870364031,
+            4946485549665804864,
+            16945909448695747420,
+            16394063075524226720,
+            4873882236456199058,
+        ];
+        for e in expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // too slow
+    fn discard() {
+        // `std::mt19937_64 rng(1); rng.discard(1000000007);`
+        let mut rng = Mt19937_64::new(1);
+        rng.discard(1000000007);
+        assert_eq!(rng.next_u64(), 14065131118133303367);
+        assert_eq!(rng.next_u64(), 11360697862530192463);
+
+        let mut rng = Mt19937_64::new(5489);
+        rng.next_u64();
+        rng.discard(9998);
+        assert_eq!(rng.next_u64(), 9981545732273789042);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // too slow
+    fn jump() {
+        // Compare jumps to generating the output. After a multiple of N
+        // outputs, the state is the same.
+        for n in [1, 311, 312, 313, 5000, 3 * N, 40000] {
+            let mut stepped = Mt19937_64::new(7);
+            let mut jumped = stepped.clone();
+            for _ in 0..n {
+                stepped.next_u64();
+            }
+            jumped.jump(n as u128);
+            if n % N == 0 {
+                assert_eq!(jumped, stepped);
+            }
+            for _ in 0..2 * N {
+                assert_eq!(jumped.next_u64(), stepped.next_u64());
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // too slow
+    fn char_poly() {
+        // Check that the characteristic polynomial annihilates the output.
+        let mut rng = Mt19937_64::new(1);
+        let mut bits = [0u8; 2 * jump::DEGREE];
+        for b in bits.iter_mut() {
+            *b = (rng.next_u64() & 1) as u8;
+        }
+        for i in 0..jump::DEGREE {
+            let mut sum = bits[i + jump::DEGREE];
+            for &t in CHAR_POLY.iter() {
+                sum ^= bits[i + usize::from(t)];
+            }
+            assert_eq!(sum, 0);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let mut rng = Mt19937_64::new(5489);
+        rng.next_u64();
+        let buf = postcard::to_allocvec(&rng).unwrap();
+        let mut deserialized: Mt19937_64 = postcard::from_bytes(&buf).unwrap();
+        assert_eq!(deserialized, rng);
+        for _ in 0..1000 {
+            assert_eq!(rng.next_u64(), deserialized.next_u64());
+        }
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::well512::expand_seed;
+use core::fmt;
+use rand_core::{RngCore, SeedableRng, utils};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const R: usize = 32;
+
+/// The WELL1024a random number generator by Panneton, L'Ecuyer and Matsumoto.
+///
+/// This is the WELL generator with a state of 1024 bits and a period of
+/// 2<sup>1024</sup> - 1; see [`Well512a`] for the family. It is not
+/// cryptographically secure.
+///
+/// This implementation produces exactly the numbers of the reference
+/// implementation `WELL1024a.c` when given the same initial state with
+/// [`new`], like `InitWELLRNG1024a`. The reference returns each 32-bit output
+/// multiplied by 2<sup>-32</sup>; here it is the output of `next_u32`.
+/// `next_u64` combines two outputs, the first as the low word.
+///
+/// `SeedableRng::from_seed` uses the seed as the first eight little-endian
+/// words of the state, and derives the others like [`Well512a`] does.
+///
+/// [`new`]: Well1024a::new
+/// [`Well512a`]: crate::Well512a
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Well1024a {
+    state: [u32; R],
+    index: usize,
+}
+
+// Custom Debug implementation that does not expose the internal state
+impl fmt::Debug for Well1024a {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Well1024a {{}}")
+    }
+}
+
+impl Well1024a {
+    /// Create a generator with the given initial state, like
+    /// `InitWELLRNG1024a(init)` of the reference implementation.
+    ///
+    /// # Panics
+    ///
+    /// If `init` is all zero, as the generator would only return zeros.
+    pub fn new(init: [u32; R]) -> Self {
+        assert!(init.iter().any(|&w| w != 0), "state must not be all zero");
+        Well1024a {
+            state: init,
+            index: 0,
+        }
+    }
+}
+
+impl RngCore for Well1024a {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        let s = &mut self.state;
+        let i = self.index & (R - 1);
+        let v0 = s[i];
+        let vm1 = s[(i + 3) % R];
+        let vm2 = s[(i + 24) % R];
+        let vm3 = s[(i + 10) % R];
+        let vrm1 = s[(i + 31) % R];
+
+        let z0 = vrm1;
+        let z1 = v0 ^ (vm1 ^ (vm1 >> 8));
+        let z2 = (vm2 ^ (vm2 << 19)) ^ (vm3 ^ (vm3 << 14));
+        let new_v1 = z1 ^ z2;
+        let new_v0 = (z0 ^ (z0 << 11)) ^ (z1 ^ (z1 << 7)) ^ (z2 ^ (z2 << 13));
+        s[i] = new_v1;
+        self.index = (i + R - 1) % R;
+        s[self.index] = new_v0;
+        new_v0
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        utils::next_u64_via_u32(self)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        utils::fill_bytes_via_next_word(dest, || self.next_u32());
+    }
+}
+
+impl SeedableRng for Well1024a {
+    type Seed = [u8; 32];
+
+    /// Create a generator with the seed as the first eight little-endian
+    /// words of the state.
+    fn from_seed(seed: Self::Seed) -> Self {
+        let mut state = [0; R];
+        expand_seed(&mut state, seed);
+        Well1024a::new(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn init() -> [u32; R] {
+        core::array::from_fn(|j| j as u32 + 1)
+    }
+
+    #[test]
+    fn reference() {
+        // These values were produced with `WELL1024a.c`, with the initial
+        // state 1, 2, ..., 32.
+        let mut rng = Well1024a::new(init());
+        let expected = [1489601207, 1825104057, 1073859899, 1704532463, 3764999621];
+        for e in expected {
+            assert_eq!(rng.next_u32(), e);
+        }
+        for _ in 5..999 {
+            rng.next_u32();
+        }
+        assert_eq!(rng.next_u32(), 2947963143);
+    }
+
+    #[test]
+    fn seed() {
+        let mut seed = [0; 32];
+        for (i, word) in seed.chunks_mut(4).enumerate() {
+            word[0] = i as u8 + 1;
+        }
+        let mut rng = Well1024a::from_seed(seed);
+        assert_eq!(rng.next_u32(), 833327253);
+        assert_eq!(rng.next_u32(), 3622680079);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let mut rng = Well1024a::new(init());
+        rng.next_u32();
+        let buf = postcard::to_allocvec(&rng).unwrap();
+        let mut deserialized: Well1024a = postcard::from_bytes(&buf).unwrap();
+        assert_eq!(deserialized, rng);
+        for _ in 0..100 {
+            assert_eq!(rng.next_u32(), deserialized.next_u32());
+        }
+    }
+}
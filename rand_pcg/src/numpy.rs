@@ -0,0 +1,201 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Seeding compatible with NumPy.
+//!
+//! NumPy does not use a seed directly as the state of a bit generator. It
+//! first passes it through a [`SeedSequence`], which hashes the seed into a
+//! pool of entropy, and then fills the state of the bit generator from the
+//! pool. To reproduce the output of a NumPy generator, create the
+//! `SeedSequence` from the same seed, and construct the generator with
+//! `from_seed_sequence`:
+//!
+//! ```
+//! use rand_core::RngCore;
+//! use rand_pcg::{Pcg64, numpy::SeedSequence};
+//!
+//! // Like `numpy.random.PCG64(42)`, which `numpy.random.default_rng(42)` uses.
+//! let mut rng = Pcg64::from_seed_sequence(&SeedSequence::new(42));
+//! let x = rng.next_u64();
+//! ```
+
+// Constants of the hash functions of `numpy.random.SeedSequence`.
+const POOL_SIZE: usize = 4;
+const INIT_A: u32 = 0x43b0d7e5;
+const MULT_A: u32 = 0x931e8875;
+const INIT_B: u32 = 0x8b51f9dd;
+const MULT_B: u32 = 0x58f38ded;
+const MIX_MULT_L: u32 = 0xca01f9dd;
+const MIX_MULT_R: u32 = 0x4973f715;
+const XSHIFT: u32 = 16;
+
+/// The seed sequence of NumPy, `numpy.random.SeedSequence`.
+///
+/// This only keeps the pool of hashed entropy, which is all that is needed to
+/// seed generators. The entropy itself is not stored, and spawning child
+/// sequences is not supported: to reproduce a child, create it from the
+/// entropy and the spawn key of the child, see [`SeedSequence::from_words`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeedSequence {
+    pool: [u32; POOL_SIZE],
+}
+
+// A hash function which updates its multiplier on every call.
+struct HashMix(u32);
+
+impl HashMix {
+    fn hash(&mut self, value: u32) -> u32 {
+        let mut value = value ^ self.0;
+        self.0 = self.0.wrapping_mul(MULT_A);
+        value = value.wrapping_mul(self.0);
+        value ^ (value >> XSHIFT)
+    }
+}
+
+fn mix(x: u32, y: u32) -> u32 {
+    let result = MIX_MULT_L
+        .wrapping_mul(x)
+        .wrapping_sub(MIX_MULT_R.wrapping_mul(y));
+    result ^ (result >> XSHIFT)
+}
+
+impl SeedSequence {
+    /// Create a `SeedSequence` from a non-negative integer, like
+    /// `numpy.random.SeedSequence(entropy)`.
+    pub fn new(entropy: u128) -> Self {
+        // NumPy splits integers into 32-bit words, least significant first,
+        // and represents 0 as a single word.
+        let mut words = [0u32; 4];
+        let mut len = 0;
+        let mut n = entropy;
+        while len == 0 || n != 0 {
+            words[len] = n as u32;
+            n >>= 32;
+            len += 1;
+        }
+        SeedSequence::from_words(&words[..len], &[])
+    }
+
+    /// Create a `SeedSequence` from entropy and a spawn key given as 32-bit
+    /// words, like `numpy.random.SeedSequence(entropy, spawn_key=spawn_key)`.
+    ///
+    /// NumPy converts an integer to words least significant word first, see
+    /// [`SeedSequence::new`]. Each element of the spawn key must be smaller
+    /// than 2<sup>32</sup>, so that it converts to a single word; this holds
+    /// for the spawn keys assigned by `SeedSequence.spawn`.
+    pub fn from_words(entropy: &[u32], spawn_key: &[u32]) -> Self {
+        // With a spawn key, the entropy is padded with zeros to the pool size.
+        let entropy_len = if spawn_key.is_empty() {
+            entropy.len()
+        } else {
+            entropy.len().max(POOL_SIZE)
+        };
+        let word = |i: usize| -> u32 {
+            if i < entropy.len() {
+                entropy[i]
+            } else if i < entropy_len {
+                0
+            } else {
+                spawn_key[i - entropy_len]
+            }
+        };
+        let len = entropy_len + spawn_key.len();
+
+        let mut hash = HashMix(INIT_A);
+        let mut pool = [0u32; POOL_SIZE];
+        for (i, p) in pool.iter_mut().enumerate() {
+            *p = hash.hash(if i < len { word(i) } else { 0 });
+        }
+        for i_src in 0..POOL_SIZE {
+            for i_dst in 0..POOL_SIZE {
+                if i_src != i_dst {
+                    pool[i_dst] = mix(pool[i_dst], hash.hash(pool[i_src]));
+                }
+            }
+        }
+        for i_src in POOL_SIZE..len {
+            for p in pool.iter_mut() {
+                *p = mix(*p, hash.hash(word(i_src)));
+            }
+        }
+        SeedSequence { pool }
+    }
+
+    /// Fill `dest` with words derived from the pool, like
+    /// `SeedSequence.generate_state(len(dest), numpy.uint32)`.
+    pub fn generate_state(&self, dest: &mut [u32]) {
+        let mut hash_const = INIT_B;
+        for (word, &value) in dest.iter_mut().zip(self.pool.iter().cycle()) {
+            let mut value = value ^ hash_const;
+            hash_const = hash_const.wrapping_mul(MULT_B);
+            value = value.wrapping_mul(hash_const);
+            *word = value ^ (value >> XSHIFT);
+        }
+    }
+
+    /// Fill `dest` with words derived from the pool, like
+    /// `SeedSequence.generate_state(len(dest), numpy.uint64)`.
+    ///
+    /// Each `u64` combines two consecutive `u32` words of
+    /// [`generate_state`](Self::generate_state), least significant first.
+    pub fn generate_state_u64(&self, dest: &mut [u64]) {
+        let mut hash_const = INIT_B;
+        let mut words = self.pool.iter().cycle().map(|&value| {
+            let mut value = value ^ hash_const;
+            hash_const = hash_const.wrapping_mul(MULT_B);
+            value = value.wrapping_mul(hash_const);
+            value ^ (value >> XSHIFT)
+        });
+        for x in dest.iter_mut() {
+            let lo = u64::from(words.next().unwrap());
+            let hi = u64::from(words.next().unwrap());
+            *x = lo | (hi << 32);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn words() {
+        assert_eq!(SeedSequence::new(0), SeedSequence::from_words(&[0], &[]));
+        assert_eq!(
+            SeedSequence::new(0x1_0000_0002),
+            SeedSequence::from_words(&[2, 1], &[])
+        );
+
+        // The entropy is padded to the pool size before the spawn key.
+        assert_eq!(
+            SeedSequence::from_words(&[42], &[0]),
+            SeedSequence::from_words(&[42, 0, 0, 0, 0], &[])
+        );
+        assert_ne!(
+            SeedSequence::from_words(&[42], &[0]),
+            SeedSequence::from_words(&[42, 0], &[])
+        );
+    }
+
+    #[test]
+    fn generate_state() {
+        let seq = SeedSequence::new(42);
+        let mut words = [0u32; 10];
+        seq.generate_state(&mut words);
+        let mut words64 = [0u64; 5];
+        seq.generate_state_u64(&mut words64);
+        for (i, x) in words64.iter().enumerate() {
+            assert_eq!(
+                *x,
+                u64::from(words[2 * i]) | (u64::from(words[2 * i + 1]) << 32)
+            );
+        }
+        // The pool repeats, but the hash constant changes.
+        assert_ne!(words[0], words[4]);
+    }
+}
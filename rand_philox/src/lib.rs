@@ -0,0 +1,53 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! This crate implements the Philox family of counter-based random number
+//! generators from [Random123].
+//!
+//! Unlike sequential generators, the output of a counter-based generator is a
+//! pure function of a key and a counter. Any position in the stream can be
+//! computed directly, which allows skipping ahead in constant time and
+//! splitting work between threads or devices without coordination.
+//!
+//! This crate provides:
+//! - [`Philox4x32Rng`]: 32-bit words, 64-bit key and 128-bit counter.
+//! - [`Philox4x64Rng`]: 64-bit words, 128-bit key and 256-bit counter.
+//!
+//! Both use 10 rounds, the default of Random123. The block functions
+//! [`philox4x32`] and [`philox4x64`] are also available directly.
+//!
+//! ```
+//! use rand_core::RngCore;
+//! use rand_philox::Philox4x32Rng;
+//!
+//! let mut rng = Philox4x32Rng::new([1, 2], [0; 4]);
+//! let x = rng.next_u32();
+//!
+//! // Jump to block 1000, that is to output 4000.
+//! rng.set_counter([1000, 0, 0, 0]);
+//! let y = rng.next_u32();
+//! ```
+//!
+//! [Random123]: https://github.com/DEShawResearch/random123
+
+#![doc(
+    html_logo_url = "https://www.rust-lang.org/logos/rust-logo-128x128-blk.png",
+    html_favicon_url = "https://www.rust-lang.org/favicon.ico"
+)]
+#![forbid(unsafe_code)]
+#![deny(missing_docs)]
+#![deny(missing_debug_implementations)]
+#![doc(test(attr(allow(unused_variables), deny(warnings))))]
+#![no_std]
+
+mod philox4x32;
+mod philox4x64;
+
+pub use philox4x32::{Philox4x32Core, Philox4x32Rng, philox4x32};
+pub use philox4x64::{Philox4x64Core, Philox4x64Rng, philox4x64};
+pub use rand_core;
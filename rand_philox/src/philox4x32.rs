@@ -0,0 +1,351 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use rand_core::block::{BlockRng, Generator};
+use rand_core::{RngCore, SeedableRng, TryRngCore, utils};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// Multipliers and Weyl sequence constants for the key schedule, from
+// Random123.
+const M0: u32 = 0xD251_1F53;
+const M1: u32 = 0xCD9E_8D57;
+const W0: u32 = 0x9E37_79B9;
+const W1: u32 = 0xBB67_AE85;
+
+const ROUNDS: usize = 10;
+
+#[inline(always)]
+fn mulhilo(a: u32, b: u32) -> (u32, u32) {
+    let product = u64::from(a) * u64::from(b);
+    ((product >> 32) as u32, product as u32)
+}
+
+#[inline(always)]
+fn round(ctr: [u32; 4], key: [u32; 2]) -> [u32; 4] {
+    let (hi0, lo0) = mulhilo(M0, ctr[0]);
+    let (hi1, lo1) = mulhilo(M1, ctr[2]);
+    [hi1 ^ ctr[1] ^ key[0], lo1, hi0 ^ ctr[3] ^ key[1], lo0]
+}
+
+/// The Philox4x32-10 function: encrypt `counter` with `key`.
+///
+/// This is the block function of [`Philox4x32Rng`], which returns the words
+/// of the result in order, and then continues with `counter + 1`. Word 0 of
+/// `counter` is the least significant.
+pub fn philox4x32(key: [u32; 2], counter: [u32; 4]) -> [u32; 4] {
+    let mut key = key;
+    let mut ctr = round(counter, key);
+    for _ in 1..ROUNDS {
+        key[0] = key[0].wrapping_add(W0);
+        key[1] = key[1].wrapping_add(W1);
+        ctr = round(ctr, key);
+    }
+    ctr
+}
+
+// Add 1 to a counter with the least significant word first.
+#[inline]
+fn increment(mut counter: [u32; 4]) -> [u32; 4] {
+    for word in counter.iter_mut() {
+        *word = word.wrapping_add(1);
+        if *word != 0 {
+            break;
+        }
+    }
+    counter
+}
+
+// Subtract 1 from a counter with the least significant word first.
+#[inline]
+fn decrement(mut counter: [u32; 4]) -> [u32; 4] {
+    for word in counter.iter_mut() {
+        let old = *word;
+        *word = old.wrapping_sub(1);
+        if old != 0 {
+            break;
+        }
+    }
+    counter
+}
+
+/// A Philox4x32-10 random number generator.
+///
+/// Philox is a counter-based generator by Salmon et al.[^1]: the output is a
+/// pure function of a 64-bit key and a 128-bit counter, see [`philox4x32`].
+/// Each value of the counter gives a block of four `u32` outputs. This makes
+/// it possible to jump to any position in the stream in constant time with
+/// [`set_counter`], and to reproduce the numbers generated by other
+/// implementations of Random123's Philox4x32-10, such as the one in cuRAND,
+/// when given the same key and counter.
+///
+/// Philox4x32-10 passes BigCrush, and its period is 2<sup>130</sup> `u32`
+/// outputs per key. It is not cryptographically secure.
+///
+/// This implementation uses [`BlockRng`] to implement the [`RngCore`]
+/// methods. When seeded with [`SeedableRng`], the seed is the key and the
+/// counter starts at 0.
+///
+/// [^1]: John K. Salmon, Mark A. Moraes, Ron O. Dror and David E. Shaw (2011).
+///       ["Parallel Random Numbers: As Easy as 1, 2, 3"](
+///       https://www.thesalmons.org/john/random123/papers/random123sc11.pdf).
+///       *Proceedings of the International Conference for High Performance
+///       Computing, Networking, Storage and Analysis (SC11)*.
+///
+/// [`set_counter`]: Philox4x32Rng::set_counter
+/// [`BlockRng`]: rand_core::block::BlockRng
+#[derive(Debug, Clone)]
+pub struct Philox4x32Rng(BlockRng<Philox4x32Core>);
+
+impl Philox4x32Rng {
+    /// Create a generator with the given `key`, starting at the first output
+    /// of the block for `counter`.
+    pub fn new(key: [u32; 2], counter: [u32; 4]) -> Self {
+        Philox4x32Rng(BlockRng::new(Philox4x32Core { key, counter }))
+    }
+
+    /// Get the key.
+    pub fn key(&self) -> [u32; 2] {
+        self.0.core.key
+    }
+
+    /// Get the counter of the block the next output is taken from.
+    ///
+    /// If some outputs of this block were already used, they are generated
+    /// again after passing the result to [`set_counter`].
+    ///
+    /// [`set_counter`]: Philox4x32Rng::set_counter
+    pub fn get_counter(&self) -> [u32; 4] {
+        if self.0.index() < 4 {
+            decrement(self.0.core.counter)
+        } else {
+            self.0.core.counter
+        }
+    }
+
+    /// Continue at the first output of the block for `counter`.
+    pub fn set_counter(&mut self, counter: [u32; 4]) {
+        self.0.core.counter = counter;
+        self.0.reset();
+    }
+}
+
+impl RngCore for Philox4x32Rng {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_word()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64_from_u32()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+}
+
+impl SeedableRng for Philox4x32Rng {
+    type Seed = <Philox4x32Core as SeedableRng>::Seed;
+
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Self {
+        Philox4x32Rng(BlockRng::new(Philox4x32Core::from_seed(seed)))
+    }
+
+    #[inline]
+    fn from_rng<R>(rng: &mut R) -> Self
+    where
+        R: RngCore + ?Sized,
+    {
+        Philox4x32Rng(BlockRng::new(Philox4x32Core::from_rng(rng)))
+    }
+
+    #[inline]
+    fn try_from_rng<R>(rng: &mut R) -> Result<Self, R::Error>
+    where
+        R: TryRngCore + ?Sized,
+    {
+        Philox4x32Core::try_from_rng(rng).map(|core| Philox4x32Rng(BlockRng::new(core)))
+    }
+}
+
+impl PartialEq for Philox4x32Rng {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.0.core == rhs.0.core && self.0.index() == rhs.0.index()
+    }
+}
+impl Eq for Philox4x32Rng {}
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use super::Philox4x32Rng;
+    use serde::de::{Deserialize, Deserializer, Error};
+    use serde::ser::{Serialize, Serializer};
+
+    // The position in the stream: the block of the next output, and how many
+    // of its words were already used.
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(rename = "Philox4x32Rng")]
+    struct State {
+        key: [u32; 2],
+        counter: [u32; 4],
+        index: u8,
+    }
+
+    impl Serialize for Philox4x32Rng {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let index = self.0.index();
+            State {
+                key: self.key(),
+                counter: self.get_counter(),
+                index: if index < 4 { index as u8 } else { 0 },
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Philox4x32Rng {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let state = State::deserialize(deserializer)?;
+            if state.index >= 4 {
+                return Err(D::Error::custom("index out of range"));
+            }
+            let mut rng = Philox4x32Rng::new(state.key, state.counter);
+            rng.0.generate_and_set(state.index.into());
+            Ok(rng)
+        }
+    }
+}
+
+/// The core of `Philox4x32Rng`, used with `BlockRng`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Philox4x32Core {
+    key: [u32; 2],
+    counter: [u32; 4],
+}
+
+impl Generator for Philox4x32Core {
+    type Output = [u32; 4];
+
+    #[inline]
+    fn generate(&mut self, results: &mut Self::Output) {
+        *results = philox4x32(self.key, self.counter);
+        self.counter = increment(self.counter);
+    }
+}
+
+impl SeedableRng for Philox4x32Core {
+    type Seed = [u8; 8];
+
+    /// Create a new `Philox4x32Core` with `seed` as key, and the counter at 0.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Philox4x32Core {
+            key: utils::read_words(&seed),
+            counter: [0; 4],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference() {
+        // These values are the known-answer tests of Random123:
+        // https://github.com/DEShawResearch/random123/blob/main/tests/kat_vectors
+        assert_eq!(
+            philox4x32([0, 0], [0, 0, 0, 0]),
+            [0x6627e8d5, 0xe169c58d, 0xbc57ac4c, 0x9b00dbd8]
+        );
+        assert_eq!(
+            philox4x32([0xffffffff; 2], [0xffffffff; 4]),
+            [0x408f276d, 0x41c83b0e, 0xa20bc7c6, 0x6d5451fd]
+        );
+        assert_eq!(
+            philox4x32(
+                [0xa4093822, 0x299f31d0],
+                [0x243f6a88, 0x85a308d3, 0x13198a2e, 0x03707344]
+            ),
+            [0xd16cfe09, 0x94fdcceb, 0x5001e420, 0x24126ea1]
+        );
+    }
+
+    #[test]
+    fn blocks() {
+        let key = [0xa4093822, 0x299f31d0];
+        let mut rng = Philox4x32Rng::new(key, [0xffffffff, 0xffffffff, 7, 0]);
+        for counter in [[0xffffffff, 0xffffffff, 7, 0], [0, 0, 8, 0], [1, 0, 8, 0]] {
+            assert_eq!(rng.get_counter(), counter);
+            let block = philox4x32(key, counter);
+            assert_eq!(rng.next_u32(), block[0]);
+            assert_eq!(rng.get_counter(), counter);
+            assert_eq!(rng.next_u32(), block[1]);
+            assert_eq!(
+                rng.next_u64(),
+                u64::from(block[2]) | (u64::from(block[3]) << 32)
+            );
+        }
+
+        // The counter wraps around.
+        let mut rng = Philox4x32Rng::new(key, [0xffffffff; 4]);
+        rng.next_u32();
+        rng.next_u64();
+        rng.next_u32();
+        assert_eq!(rng.get_counter(), [0, 0, 0, 0]);
+        assert_eq!(rng.next_u32(), philox4x32(key, [0, 0, 0, 0])[0]);
+    }
+
+    #[test]
+    fn set_counter() {
+        let mut rng = Philox4x32Rng::seed_from_u64(0);
+        let mut a = [0u32; 40];
+        for x in a.iter_mut() {
+            *x = rng.next_u32();
+        }
+
+        rng.set_counter([5, 0, 0, 0]);
+        assert_eq!(rng.next_u32(), a[20]);
+        rng.next_u32();
+        let counter = rng.get_counter();
+        assert_eq!(counter, [5, 0, 0, 0]);
+        rng.set_counter([2, 0, 0, 0]);
+        assert_eq!(rng.next_u64(), u64::from(a[8]) | (u64::from(a[9]) << 32));
+        rng.set_counter(counter);
+        assert_eq!(rng.next_u32(), a[20]);
+    }
+
+    #[test]
+    fn seed() {
+        let rng = Philox4x32Rng::from_seed([1, 0, 0, 0, 2, 0, 0, 0]);
+        assert_eq!(rng, Philox4x32Rng::new([1, 2], [0; 4]));
+        assert_eq!(rng.key(), [1, 2]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let mut rng = Philox4x32Rng::seed_from_u64(42);
+        for skip in 0..6 {
+            let buf = postcard::to_allocvec(&rng).expect("Could not serialize");
+            let mut deserialized: Philox4x32Rng =
+                postcard::from_bytes(&buf).expect("Could not deserialize");
+            assert_eq!(rng, deserialized);
+            for _ in 0..10 {
+                assert_eq!(rng.next_u32(), deserialized.next_u32());
+            }
+            for _ in 0..skip {
+                rng.next_u32();
+            }
+        }
+    }
+}
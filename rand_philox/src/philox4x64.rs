@@ -0,0 +1,374 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use rand_core::block::{BlockRng, Generator};
+use rand_core::{RngCore, SeedableRng, TryRngCore, utils};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// Multipliers and Weyl sequence constants for the key schedule, from
+// Random123.
+const M0: u64 = 0xD2E7_470E_E14C_6C93;
+const M1: u64 = 0xCA5A_8263_9512_1157;
+const W0: u64 = 0x9E37_79B9_7F4A_7C15;
+const W1: u64 = 0xBB67_AE85_84CA_A73B;
+
+const ROUNDS: usize = 10;
+
+#[inline(always)]
+fn mulhilo(a: u64, b: u64) -> (u64, u64) {
+    let product = u128::from(a) * u128::from(b);
+    ((product >> 64) as u64, product as u64)
+}
+
+#[inline(always)]
+fn round(ctr: [u64; 4], key: [u64; 2]) -> [u64; 4] {
+    let (hi0, lo0) = mulhilo(M0, ctr[0]);
+    let (hi1, lo1) = mulhilo(M1, ctr[2]);
+    [hi1 ^ ctr[1] ^ key[0], lo1, hi0 ^ ctr[3] ^ key[1], lo0]
+}
+
+/// The Philox4x64-10 function: encrypt `counter` with `key`.
+///
+/// This is the block function of [`Philox4x64Rng`], which returns the words
+/// of the result in order, and then continues with `counter + 1`. Word 0 of
+/// `counter` is the least significant.
+pub fn philox4x64(key: [u64; 2], counter: [u64; 4]) -> [u64; 4] {
+    let mut key = key;
+    let mut ctr = round(counter, key);
+    for _ in 1..ROUNDS {
+        key[0] = key[0].wrapping_add(W0);
+        key[1] = key[1].wrapping_add(W1);
+        ctr = round(ctr, key);
+    }
+    ctr
+}
+
+// Add 1 to a counter with the least significant word first.
+#[inline]
+fn increment(mut counter: [u64; 4]) -> [u64; 4] {
+    for word in counter.iter_mut() {
+        *word = word.wrapping_add(1);
+        if *word != 0 {
+            break;
+        }
+    }
+    counter
+}
+
+// Subtract 1 from a counter with the least significant word first.
+#[inline]
+fn decrement(mut counter: [u64; 4]) -> [u64; 4] {
+    for word in counter.iter_mut() {
+        let old = *word;
+        *word = old.wrapping_sub(1);
+        if old != 0 {
+            break;
+        }
+    }
+    counter
+}
+
+/// A Philox4x64-10 random number generator.
+///
+/// Philox is a counter-based generator by Salmon et al.[^1]: the output is a
+/// pure function of a 128-bit key and a 256-bit counter, see [`philox4x64`].
+/// Each value of the counter gives a block of four `u64` outputs. This makes
+/// it possible to jump to any position in the stream in constant time with
+/// [`set_counter`], and to reproduce the numbers generated by other
+/// implementations of Random123's Philox4x64-10 when given the same key and
+/// counter.
+///
+/// Philox4x64-10 passes BigCrush, and its period is 2<sup>258</sup> `u64`
+/// outputs per key. It is not cryptographically secure. [`Philox4x32Rng`]
+/// is faster on platforms without a fast 64-bit multiplication.
+///
+/// `next_u32` uses the low half of a `u64` output and discards the rest.
+///
+/// This implementation uses [`BlockRng`] to implement the [`RngCore`]
+/// methods. When seeded with [`SeedableRng`], the seed is the key and the
+/// counter starts at 0.
+///
+/// [^1]: John K. Salmon, Mark A. Moraes, Ron O. Dror and David E. Shaw (2011).
+///       ["Parallel Random Numbers: As Easy as 1, 2, 3"](
+///       https://www.thesalmons.org/john/random123/papers/random123sc11.pdf).
+///       *Proceedings of the International Conference for High Performance
+///       Computing, Networking, Storage and Analysis (SC11)*.
+///
+/// [`set_counter`]: Philox4x64Rng::set_counter
+/// [`Philox4x32Rng`]: crate::Philox4x32Rng
+/// [`BlockRng`]: rand_core::block::BlockRng
+#[derive(Debug, Clone)]
+pub struct Philox4x64Rng(BlockRng<Philox4x64Core>);
+
+impl Philox4x64Rng {
+    /// Create a generator with the given `key`, starting at the first output
+    /// of the block for `counter`.
+    pub fn new(key: [u64; 2], counter: [u64; 4]) -> Self {
+        Philox4x64Rng(BlockRng::new(Philox4x64Core { key, counter }))
+    }
+
+    /// Get the key.
+    pub fn key(&self) -> [u64; 2] {
+        self.0.core.key
+    }
+
+    /// Get the counter of the block the next output is taken from.
+    ///
+    /// If some outputs of this block were already used, they are generated
+    /// again after passing the result to [`set_counter`].
+    ///
+    /// [`set_counter`]: Philox4x64Rng::set_counter
+    pub fn get_counter(&self) -> [u64; 4] {
+        if self.0.index() < 4 {
+            decrement(self.0.core.counter)
+        } else {
+            self.0.core.counter
+        }
+    }
+
+    /// Continue at the first output of the block for `counter`.
+    pub fn set_counter(&mut self, counter: [u64; 4]) {
+        self.0.core.counter = counter;
+        self.0.reset();
+    }
+}
+
+impl RngCore for Philox4x64Rng {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_word() as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_word()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+}
+
+impl SeedableRng for Philox4x64Rng {
+    type Seed = <Philox4x64Core as SeedableRng>::Seed;
+
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Self {
+        Philox4x64Rng(BlockRng::new(Philox4x64Core::from_seed(seed)))
+    }
+
+    #[inline]
+    fn from_rng<R>(rng: &mut R) -> Self
+    where
+        R: RngCore + ?Sized,
+    {
+        Philox4x64Rng(BlockRng::new(Philox4x64Core::from_rng(rng)))
+    }
+
+    #[inline]
+    fn try_from_rng<R>(rng: &mut R) -> Result<Self, R::Error>
+    where
+        R: TryRngCore + ?Sized,
+    {
+        Philox4x64Core::try_from_rng(rng).map(|core| Philox4x64Rng(BlockRng::new(core)))
+    }
+}
+
+impl PartialEq for Philox4x64Rng {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.0.core == rhs.0.core && self.0.index() == rhs.0.index()
+    }
+}
+impl Eq for Philox4x64Rng {}
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use super::Philox4x64Rng;
+    use serde::de::{Deserialize, Deserializer, Error};
+    use serde::ser::{Serialize, Serializer};
+
+    // The position in the stream: the block of the next output, and how many
+    // of its words were already used.
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(rename = "Philox4x64Rng")]
+    struct State {
+        key: [u64; 2],
+        counter: [u64; 4],
+        index: u8,
+    }
+
+    impl Serialize for Philox4x64Rng {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let index = self.0.index();
+            State {
+                key: self.key(),
+                counter: self.get_counter(),
+                index: if index < 4 { index as u8 } else { 0 },
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Philox4x64Rng {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let state = State::deserialize(deserializer)?;
+            if state.index >= 4 {
+                return Err(D::Error::custom("index out of range"));
+            }
+            let mut rng = Philox4x64Rng::new(state.key, state.counter);
+            rng.0.generate_and_set(state.index.into());
+            Ok(rng)
+        }
+    }
+}
+
+/// The core of `Philox4x64Rng`, used with `BlockRng`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Philox4x64Core {
+    key: [u64; 2],
+    counter: [u64; 4],
+}
+
+impl Generator for Philox4x64Core {
+    type Output = [u64; 4];
+
+    #[inline]
+    fn generate(&mut self, results: &mut Self::Output) {
+        *results = philox4x64(self.key, self.counter);
+        self.counter = increment(self.counter);
+    }
+}
+
+impl SeedableRng for Philox4x64Core {
+    type Seed = [u8; 16];
+
+    /// Create a new `Philox4x64Core` with `seed` as key, and the counter at 0.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Philox4x64Core {
+            key: utils::read_words(&seed),
+            counter: [0; 4],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference() {
+        // These values are the known-answer tests of Random123:
+        // https://github.com/DEShawResearch/random123/blob/main/tests/kat_vectors
+        assert_eq!(
+            philox4x64([0, 0], [0, 0, 0, 0]),
+            [
+                0x16554d9eca36314c,
+                0xdb20fe9d672d0fdc,
+                0xd7e772cee186176b,
+                0x7e68b68aec7ba23b
+            ]
+        );
+        assert_eq!(
+            philox4x64([0xffffffffffffffff; 2], [0xffffffffffffffff; 4]),
+            [
+                0x87b092c3013fe90b,
+                0x438c3c67be8d0224,
+                0x9cc7d7c69cd777b6,
+                0xa09caebf594f0ba0
+            ]
+        );
+        assert_eq!(
+            philox4x64(
+                [0x452821e638d01377, 0xbe5466cf34e90c6c],
+                [
+                    0x243f6a8885a308d3,
+                    0x13198a2e03707344,
+                    0xa4093822299f31d0,
+                    0x082efa98ec4e6c89
+                ]
+            ),
+            [
+                0xa528f45403e61d95,
+                0x38c72dbd566e9788,
+                0xa5a1610e72fd18b5,
+                0x57bd43b5e52b7fe6
+            ]
+        );
+    }
+
+    #[test]
+    fn blocks() {
+        let key = [0x452821e638d01377, 0xbe5466cf34e90c6c];
+        let max = u64::MAX;
+        let mut rng = Philox4x64Rng::new(key, [max, 7, 0, 0]);
+        for counter in [[max, 7, 0, 0], [0, 8, 0, 0], [1, 8, 0, 0]] {
+            assert_eq!(rng.get_counter(), counter);
+            let block = philox4x64(key, counter);
+            assert_eq!(rng.next_u64(), block[0]);
+            assert_eq!(rng.get_counter(), counter);
+            assert_eq!(rng.next_u32(), block[1] as u32);
+            assert_eq!(rng.next_u64(), block[2]);
+            assert_eq!(rng.next_u64(), block[3]);
+        }
+
+        // The counter wraps around.
+        let mut rng = Philox4x64Rng::new(key, [max; 4]);
+        for _ in 0..4 {
+            rng.next_u64();
+        }
+        assert_eq!(rng.get_counter(), [0, 0, 0, 0]);
+        assert_eq!(rng.next_u64(), philox4x64(key, [0, 0, 0, 0])[0]);
+    }
+
+    #[test]
+    fn set_counter() {
+        let mut rng = Philox4x64Rng::seed_from_u64(0);
+        let mut a = [0u64; 40];
+        for x in a.iter_mut() {
+            *x = rng.next_u64();
+        }
+
+        rng.set_counter([5, 0, 0, 0]);
+        assert_eq!(rng.next_u64(), a[20]);
+        rng.next_u64();
+        let counter = rng.get_counter();
+        assert_eq!(counter, [5, 0, 0, 0]);
+        rng.set_counter([2, 0, 0, 0]);
+        assert_eq!(rng.next_u64(), a[8]);
+        rng.set_counter(counter);
+        assert_eq!(rng.next_u64(), a[20]);
+    }
+
+    #[test]
+    fn seed() {
+        let rng = Philox4x64Rng::from_seed([1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(rng, Philox4x64Rng::new([1, 2], [0; 4]));
+        assert_eq!(rng.key(), [1, 2]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let mut rng = Philox4x64Rng::seed_from_u64(42);
+        for skip in 0..6 {
+            let buf = postcard::to_allocvec(&rng).expect("Could not serialize");
+            let mut deserialized: Philox4x64Rng =
+                postcard::from_bytes(&buf).expect("Could not deserialize");
+            assert_eq!(rng, deserialized);
+            for _ in 0..10 {
+                assert_eq!(rng.next_u64(), deserialized.next_u64());
+            }
+            for _ in 0..skip {
+                rng.next_u64();
+            }
+        }
+    }
+}
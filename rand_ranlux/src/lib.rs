@@ -0,0 +1,61 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! This crate implements the RANLUX family of random number generators.
+//!
+//! RANLUX is Lüscher's improvement of Marsaglia and Zaman's
+//! subtract-with-borrow generator: after using some numbers, it throws away
+//! the following ones, so that the numbers it returns are decorrelated. The
+//! *luxury level* chooses how many numbers are thrown away, trading speed for
+//! quality. Its theoretical foundation and long track record have made it a
+//! standard generator for Monte Carlo simulations in high-energy physics.
+//!
+//! This crate provides:
+//! - [`Ranlux24`]: 24-bit numbers, compatible with GSL's `ranlux` and
+//!   `ranlux389`, and with C++'s `std::ranlux24`.
+//! - [`Ranlux48`]: 48-bit numbers, compatible with C++'s `std::ranlux48`.
+//! - [`Ranluxpp`]: RANLUX++, which computes the state of RANLUX after many
+//!   steps at once as a linear congruential generator with 576-bit numbers.
+//!   It is much faster at high luxury levels, and can skip ahead quickly.
+//! - [`RanluxppRoot`]: RANLUX++ with 48-bit numbers, compatible with ROOT's
+//!   `RanluxppEngine`.
+//!
+//! None of these generators are suitable for cryptographic purposes.
+//!
+//! ```
+//! use rand_core::RngCore;
+//! use rand_ranlux::{Ranlux24, Ranluxpp};
+//!
+//! // Luxury level 4, like GSL's `ranlux389`
+//! let mut rng = Ranlux24::new(1, 4);
+//! let x = rng.next_u24();
+//!
+//! let mut rng = Ranluxpp::new(314159265);
+//! // Skip 2^40 outputs.
+//! rng.advance(1 << 40);
+//! let y = rng.next_u64();
+//! ```
+
+#![doc(
+    html_logo_url = "https://www.rust-lang.org/logos/rust-logo-128x128-blk.png",
+    html_favicon_url = "https://www.rust-lang.org/favicon.ico"
+)]
+#![forbid(unsafe_code)]
+#![deny(missing_docs)]
+#![deny(missing_debug_implementations)]
+#![doc(test(attr(allow(unused_variables), deny(warnings))))]
+#![no_std]
+
+mod ranlux24;
+mod ranlux48;
+mod ranluxpp;
+
+pub use rand_core;
+pub use ranlux24::Ranlux24;
+pub use ranlux48::Ranlux48;
+pub use ranluxpp::{Ranluxpp, RanluxppCore, RanluxppRoot};
@@ -0,0 +1,330 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::fmt;
+use rand_core::{RngCore, SeedableRng, utils};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// The long lag
+const R: usize = 24;
+// The short lag
+const S: usize = 10;
+const MASK: u32 = (1 << 24) - 1;
+// The block sizes of the luxury levels 0 to 4
+const LUXURY: [usize; 5] = [24, 48, 97, 223, 389];
+
+/// The modulus of the linear congruential generator used for seeding.
+pub(crate) const SEED_MODULUS: u64 = 2147483563;
+
+/// The states of the seeding generator `z = 40014 * z mod 2147483563`,
+/// starting from `seed`. Like `std::linear_congruential_engine` in C++, a seed
+/// that is a multiple of the modulus starts from 1 instead.
+pub(crate) fn seed_sequence(seed: u32) -> impl Iterator<Item = u32> {
+    let mut z = u64::from(seed) % SEED_MODULUS;
+    if z == 0 {
+        z = 1;
+    }
+    core::iter::from_fn(move || {
+        z = z * 40014 % SEED_MODULUS;
+        Some(z as u32)
+    })
+}
+
+/// The 24-bit RANLUX random number generator.
+///
+/// RANLUX is Lüscher's[^1] version of the subtract-with-borrow generator
+/// x<sub>n</sub> = x<sub>n-10</sub> - x<sub>n-24</sub> - c<sub>n-1</sub> mod
+/// 2<sup>24</sup> of Marsaglia and Zaman. Of every block of `p` numbers, it
+/// returns only the first ones and throws away the rest, which removes the
+/// correlations of the underlying generator. Its period is about
+/// 10<sup>171</sup>. It is not suitable for cryptographic purposes.
+///
+/// The luxury levels of F. James's implementation[^2] choose `p`:
+///
+/// | level | `p` |                                                     |
+/// |-------|-----|-----------------------------------------------------|
+/// | 0     | 24  | the plain subtract-with-borrow generator            |
+/// | 1     | 48  |                                                     |
+/// | 2     | 97  |                                                     |
+/// | 3     | 223 | GSL's `ranlux`                                      |
+/// | 4     | 389 | GSL's `ranlux389`, with all correlations removed    |
+///
+/// Levels 0 to 2 fail some statistical tests; level 3 is usually enough in
+/// practice.
+///
+/// This implementation produces exactly the numbers of:
+///
+/// - [`new`]: GSL's `ranlux` and `ranlux389` with the same seed, for levels 3
+///   and 4, and James's seeding and block layout for all levels: 24 numbers
+///   are returned of every block.
+/// - [`new_std`]: C++'s `std::ranlux24`, which returns 23 numbers of every
+///   block of 223, with its own seeding.
+///
+/// The numbers of the reference implementations are available with
+/// [`next_u24`]. For the [`RngCore`] methods, `next_u32` combines one value
+/// with the upper 8 bits of the next one, and `next_u64` combines two such
+/// `u32` values, the first as the low word.
+///
+/// When seeded with [`SeedableRng`], the seed is used as a little-endian
+/// `u32` for [`new`], at luxury level 3.
+///
+/// [^1]: Martin Lüscher (1994). ["A portable high-quality random number
+///       generator for lattice field theory simulations"](
+///       https://doi.org/10.1016/0010-4655(94)90232-1). *Computer Physics
+///       Communications* 79, pp. 100-110.
+///
+/// [^2]: F. James (1994). ["RANLUX: A Fortran implementation of the
+///       high-quality pseudorandom number generator of Lüscher"](
+///       https://doi.org/10.1016/0010-4655(94)90233-X). *Computer Physics
+///       Communications* 79, pp. 111-114.
+///
+/// [`new`]: Ranlux24::new
+/// [`new_std`]: Ranlux24::new_std
+/// [`next_u24`]: Ranlux24::next_u24
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Ranlux24 {
+    x: [u32; R],
+    // The index of x_{n-24}, the oldest number
+    i: usize,
+    carry: u32,
+    // The numbers returned of the current block
+    n: usize,
+    // The numbers returned of every block
+    used: usize,
+    // The block size
+    p: usize,
+}
+
+// Custom Debug implementation that does not expose the internal state
+impl fmt::Debug for Ranlux24 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Ranlux24 {{}}")
+    }
+}
+
+impl Ranlux24 {
+    /// Create a generator at the given luxury level, seeded like GSL's
+    /// `ranlux`.
+    ///
+    /// The state is filled with the values of a linear congruential generator
+    /// started at `seed`, following James. A seed of 0 is replaced by GSL's
+    /// default 314159265.
+    ///
+    /// This differs from GSL for the seeds 2147483563 and 4294967126, the
+    /// multiples of the modulus of the linear congruential generator: GSL
+    /// fills the state with zeros, after which it only returns 0, while here
+    /// they are seeded like 1.
+    ///
+    /// # Panics
+    ///
+    /// If `luxury` is larger than 4.
+    pub fn new(seed: u32, luxury: u8) -> Self {
+        assert!(luxury <= 4, "the luxury level must be at most 4");
+        let seed = if seed == 0 { 314159265 } else { seed };
+        let mut x = [0; R];
+        // The first value is the newest number
+        for (x, z) in x.iter_mut().rev().zip(seed_sequence(seed)) {
+            *x = z & MASK;
+        }
+        Ranlux24 {
+            x,
+            i: 0,
+            carry: 0,
+            n: 0,
+            used: R,
+            p: LUXURY[usize::from(luxury)],
+        }
+    }
+
+    /// Create a generator like `std::ranlux24 rng(seed)` in C++.
+    ///
+    /// A seed of 0 is replaced by the default seed of C++, 19780503, and the
+    /// seeds 2147483563 and 4294967126 are seeded like 1, as in C++.
+    pub fn new_std(seed: u32) -> Self {
+        let seed = if seed == 0 { 19780503 } else { seed };
+        let mut x = [0; R];
+        for (x, z) in x.iter_mut().zip(seed_sequence(seed)) {
+            *x = z & MASK;
+        }
+        Ranlux24 {
+            x,
+            i: 0,
+            carry: u32::from(x[R - 1] == 0),
+            n: 0,
+            used: R - 1,
+            p: 223,
+        }
+    }
+
+    // One step of the subtract-with-borrow generator
+    #[inline]
+    fn step(&mut self) -> u32 {
+        let j = if self.i < S {
+            self.i + (R - S)
+        } else {
+            self.i - S
+        };
+        let (x, borrow) = self.x[j].overflowing_sub(self.x[self.i] + self.carry);
+        let x = x & MASK;
+        self.carry = u32::from(borrow);
+        self.x[self.i] = x;
+        self.i = if self.i == R - 1 { 0 } else { self.i + 1 };
+        x
+    }
+
+    /// Return the next 24-bit number, like `gsl_rng_get` or `operator()` of
+    /// the reference implementations.
+    #[inline]
+    pub fn next_u24(&mut self) -> u32 {
+        if self.n == self.used {
+            for _ in self.used..self.p {
+                self.step();
+            }
+            self.n = 0;
+        }
+        self.n += 1;
+        self.step()
+    }
+}
+
+impl RngCore for Ranlux24 {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        let hi = self.next_u24();
+        let lo = self.next_u24();
+        (hi << 8) | (lo >> 16)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        utils::next_u64_via_u32(self)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        utils::fill_bytes_via_next_word(dest, || self.next_u32())
+    }
+}
+
+impl SeedableRng for Ranlux24 {
+    type Seed = [u8; 4];
+
+    /// Create a generator at luxury level 3, using the seed as a
+    /// little-endian `u32`.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Ranlux24::new(u32::from_le_bytes(seed), 3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gsl() {
+        // These values were produced with the algorithm of GSL's ranlux.c.
+        // The first block is the same at every level.
+        let mut rng = Ranlux24::new(1, 3);
+        for e in [15869483, 7943651, 15963989, 7209501, 1531321] {
+            assert_eq!(rng.next_u24(), e);
+        }
+
+        let expected = [
+            ([1132826, 15315765, 13326515, 4592430], 4160961),
+            ([13285442, 3630331, 9257424, 6608080], 12026963),
+            ([12185491, 478597, 4638141, 5034039], 1418241),
+            ([14820617, 122807, 11717144, 10929296], 10574637),
+            ([13934952, 7006785, 8399129, 15847573], 3274640),
+        ];
+        for (luxury, (second_block, value_1000)) in expected.into_iter().enumerate() {
+            let mut rng = Ranlux24::new(1, luxury as u8);
+            for _ in 0..24 {
+                rng.next_u24();
+            }
+            for e in second_block {
+                assert_eq!(rng.next_u24(), e);
+            }
+            for _ in 28..999 {
+                rng.next_u24();
+            }
+            assert_eq!(rng.next_u24(), value_1000);
+        }
+
+        // The default seed
+        let mut rng = Ranlux24::new(0, 3);
+        for _ in 0..999 {
+            rng.next_u24();
+        }
+        assert_eq!(rng.next_u24(), 2722331);
+
+        // Unlike GSL, the multiples of the seeding modulus do not give an
+        // all-zero state
+        assert_eq!(Ranlux24::new(2147483563, 3), Ranlux24::new(1, 3));
+        assert_eq!(Ranlux24::new(4294967126, 3), Ranlux24::new(1, 3));
+    }
+
+    #[test]
+    fn std() {
+        // The C++ standard requires this value for the 10000th invocation of
+        // a default-constructed `std::ranlux24`.
+        let mut rng = Ranlux24::new_std(0);
+        for _ in 0..9999 {
+            rng.next_u24();
+        }
+        assert_eq!(rng.next_u24(), 9901578);
+
+        // Produced with `std::ranlux24 rng(12345)` of libstdc++
+        let mut rng = Ranlux24::new_std(12345);
+        for e in [16448363, 11496357, 1838018, 11837769, 3375312] {
+            assert_eq!(rng.next_u24(), e);
+        }
+
+        // libstdc++ gives the same numbers for these seeds as for 1
+        assert_eq!(Ranlux24::new_std(2147483563), Ranlux24::new_std(1));
+        assert_eq!(Ranlux24::new_std(4294967126), Ranlux24::new_std(1));
+    }
+
+    #[test]
+    fn next_u32() {
+        let mut rng = Ranlux24::new(1, 3);
+        assert_eq!(rng.next_u32(), 4062587769);
+        assert_eq!(rng.next_u32(), 4086781294);
+
+        let mut rng = Ranlux24::new(1, 3);
+        assert_eq!(rng.next_u64(), 4062587769 | (4086781294 << 32));
+    }
+
+    #[test]
+    fn seed() {
+        let rng = Ranlux24::from_seed([1, 0, 0, 0]);
+        assert_eq!(rng, Ranlux24::new(1, 3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn luxury_out_of_range() {
+        Ranlux24::new(1, 5);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let mut rng = Ranlux24::seed_from_u64(42);
+        for _ in 0..30 {
+            rng.next_u24();
+        }
+        let buf = postcard::to_allocvec(&rng).expect("Could not serialize");
+        let mut deserialized: Ranlux24 = postcard::from_bytes(&buf).expect("Could not deserialize");
+        assert_eq!(rng, deserialized);
+        for _ in 0..100 {
+            assert_eq!(rng.next_u32(), deserialized.next_u32());
+        }
+    }
+}
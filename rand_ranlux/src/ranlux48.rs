@@ -0,0 +1,241 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::ranlux24::seed_sequence;
+use core::fmt;
+use rand_core::{RngCore, SeedableRng, utils};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// The long lag
+const R: usize = 12;
+// The short lag
+const S: usize = 5;
+const MASK: u64 = (1 << 48) - 1;
+
+/// The 48-bit RANLUX random number generator.
+///
+/// This is RANLUX on the subtract-with-borrow generator x<sub>n</sub> =
+/// x<sub>n-5</sub> - x<sub>n-12</sub> - c<sub>n-1</sub> mod 2<sup>48</sup>,
+/// as standardized by C++11: of every block of `p` numbers, the first `r` are
+/// returned and the rest are thrown away. The luxury level of [`new`] is `p`
+/// = 389 with `r` = 11, and [`with_block_size`] chooses others. Its period is
+/// about 10<sup>171</sup>. It is not suitable for cryptographic purposes.
+///
+/// This implementation produces exactly the numbers of C++'s
+/// `std::ranlux48`, and of `std::discard_block_engine<std::ranlux48_base, p,
+/// r>` with [`with_block_size`], when given the same seed. These numbers are
+/// available with [`next_u48`]. For the [`RngCore`] methods, `next_u64`
+/// combines one value with the upper 16 bits of the next one, and `next_u32`
+/// uses the upper 32 bits of one value.
+///
+/// When seeded with [`SeedableRng`], the seed is used as a little-endian
+/// `u32` for [`new`].
+///
+/// [`new`]: Ranlux48::new
+/// [`with_block_size`]: Ranlux48::with_block_size
+/// [`next_u48`]: Ranlux48::next_u48
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Ranlux48 {
+    x: [u64; R],
+    // The index of x_{n-12}, the oldest number
+    i: usize,
+    carry: u64,
+    // The numbers returned of the current block
+    n: usize,
+    // The numbers returned of every block
+    used: usize,
+    // The block size
+    p: usize,
+}
+
+// Custom Debug implementation that does not expose the internal state
+impl fmt::Debug for Ranlux48 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Ranlux48 {{}}")
+    }
+}
+
+impl Ranlux48 {
+    /// Create a generator like `std::ranlux48 rng(seed)` in C++.
+    ///
+    /// A seed of 0 is replaced by the default seed of C++, 19780503, and the
+    /// seeds 2147483563 and 4294967126, the multiples of the modulus of the
+    /// seeding generator, are seeded like 1, as in C++.
+    pub fn new(seed: u32) -> Self {
+        Ranlux48::with_block_size(seed, 389, 11)
+    }
+
+    /// Create a generator which returns `r` numbers of every block of `p`,
+    /// like `std::discard_block_engine<std::ranlux48_base, p, r> rng(seed)`
+    /// in C++.
+    ///
+    /// # Panics
+    ///
+    /// If `r` is 0 or larger than `p`.
+    pub fn with_block_size(seed: u32, p: usize, r: usize) -> Self {
+        assert!(0 < r && r <= p, "invalid block size");
+        let seed = if seed == 0 { 19780503 } else { seed };
+        let mut x = [0; R];
+        let mut z = seed_sequence(seed);
+        for x in x.iter_mut() {
+            let lo = u64::from(z.next().unwrap());
+            let hi = u64::from(z.next().unwrap());
+            *x = (lo | (hi << 32)) & MASK;
+        }
+        Ranlux48 {
+            x,
+            i: 0,
+            carry: u64::from(x[R - 1] == 0),
+            n: 0,
+            used: r,
+            p,
+        }
+    }
+
+    // One step of the subtract-with-borrow generator
+    #[inline]
+    fn step(&mut self) -> u64 {
+        let j = if self.i < S {
+            self.i + (R - S)
+        } else {
+            self.i - S
+        };
+        let (x, borrow) = self.x[j].overflowing_sub(self.x[self.i] + self.carry);
+        let x = x & MASK;
+        self.carry = u64::from(borrow);
+        self.x[self.i] = x;
+        self.i = if self.i == R - 1 { 0 } else { self.i + 1 };
+        x
+    }
+
+    /// Return the next 48-bit number, like `operator()` of the reference
+    /// implementation.
+    #[inline]
+    pub fn next_u48(&mut self) -> u64 {
+        if self.n == self.used {
+            for _ in self.used..self.p {
+                self.step();
+            }
+            self.n = 0;
+        }
+        self.n += 1;
+        self.step()
+    }
+}
+
+impl RngCore for Ranlux48 {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u48() >> 16) as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let hi = self.next_u48();
+        let lo = self.next_u48();
+        (hi << 16) | (lo >> 32)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        utils::fill_bytes_via_next_word(dest, || self.next_u64())
+    }
+}
+
+impl SeedableRng for Ranlux48 {
+    type Seed = [u8; 4];
+
+    /// Create a generator like `std::ranlux48`, using the seed as a
+    /// little-endian `u32`.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Ranlux48::new(u32::from_le_bytes(seed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn std() {
+        // The C++ standard requires this value for the 10000th invocation of
+        // a default-constructed `std::ranlux48`.
+        let mut rng = Ranlux48::new(0);
+        for _ in 0..9999 {
+            rng.next_u48();
+        }
+        assert_eq!(rng.next_u48(), 249142670248501);
+
+        // Produced with `std::ranlux48 rng(12345)` of libstdc++
+        let mut rng = Ranlux48::new(12345);
+        let expected = [
+            118360775523179,
+            177334856190914,
+            224501953691856,
+            139954681321388,
+            195902800817777,
+        ];
+        for e in expected {
+            assert_eq!(rng.next_u48(), e);
+        }
+
+        // libstdc++ gives the same numbers for these seeds as for 1
+        assert_eq!(Ranlux48::new(2147483563), Ranlux48::new(1));
+        assert_eq!(Ranlux48::new(4294967126), Ranlux48::new(1));
+    }
+
+    #[test]
+    fn block_size() {
+        // Produced with `std::discard_block_engine<std::ranlux48_base, 218,
+        // 12> rng(7)` of libstdc++, from the 25th number
+        let mut rng = Ranlux48::with_block_size(7, 218, 12);
+        for _ in 0..24 {
+            rng.next_u48();
+        }
+        let expected = [
+            125069224918953,
+            2532878015634,
+            25992722482463,
+            77148098772175,
+            233267847532176,
+            67889584608203,
+        ];
+        for e in expected {
+            assert_eq!(rng.next_u48(), e);
+        }
+    }
+
+    #[test]
+    fn next_u64() {
+        let mut rng = Ranlux48::new(0);
+        assert_eq!(rng.next_u64(), 1537412910361090572);
+    }
+
+    #[test]
+    fn seed() {
+        let rng = Ranlux48::from_seed([57, 48, 0, 0]);
+        assert_eq!(rng, Ranlux48::new(12345));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let mut rng = Ranlux48::seed_from_u64(42);
+        for _ in 0..20 {
+            rng.next_u48();
+        }
+        let buf = postcard::to_allocvec(&rng).expect("Could not serialize");
+        let mut deserialized: Ranlux48 = postcard::from_bytes(&buf).expect("Could not deserialize");
+        assert_eq!(rng, deserialized);
+        for _ in 0..100 {
+            assert_eq!(rng.next_u64(), deserialized.next_u64());
+        }
+    }
+}
@@ -0,0 +1,848 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::fmt;
+use rand_core::block::{BlockRng, Generator};
+use rand_core::{RngCore, SeedableRng, TryRngCore, utils};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// Numbers modulo m are stored as nine little-endian `u64` words.
+type U576 = [u64; 9];
+
+const ONE: U576 = [1, 0, 0, 0, 0, 0, 0, 0, 0];
+
+// The modulus m = 2^576 - 2^240 + 1 = b^24 - b^10 + 1 of the LCG, with the
+// base b = 2^24 of RANLUX
+const M: U576 = [
+    1,
+    0,
+    0,
+    0xffff000000000000,
+    u64::MAX,
+    u64::MAX,
+    u64::MAX,
+    u64::MAX,
+    u64::MAX,
+];
+
+// The multiplier a = m - (m - 1) / b = b^-1 mod m, which does one step of
+// RANLUX
+const A: U576 = [
+    1,
+    0,
+    0,
+    0xffff000001000000,
+    u64::MAX,
+    u64::MAX,
+    u64::MAX,
+    u64::MAX,
+    0xfffffeffffffffff,
+];
+
+// a^2048 mod m
+const A_2048: U576 = [
+    0xed7faa90747aaad9,
+    0x4cec2c78af55c101,
+    0xe64dcb31c48228ec,
+    0x6d8a15a13bee7cb0,
+    0x20b2ca60cb78c509,
+    0x256c3d3c662ea36c,
+    0xff74e54107684ed2,
+    0x492edfcc0cc8e753,
+    0xb48c187cf5b22097,
+];
+
+fn less_than(x: &U576, y: &U576) -> bool {
+    for (x, y) in x.iter().zip(y).rev() {
+        if x != y {
+            return x < y;
+        }
+    }
+    false
+}
+
+// Reduce a number below 2^1152 modulo m, using 2^576 = 2^240 - 1 mod m.
+fn reduce(mut v: [u64; 18]) -> U576 {
+    while v[9..].iter().any(|&w| w != 0) {
+        // v = lo + hi * 2^240 - hi, where 240 = 3 * 64 + 48
+        let mut r = [0; 18];
+        let mut carry: i128 = 0;
+        for (k, r) in r.iter_mut().enumerate() {
+            let hi = |i: usize| if (9..18).contains(&i) { v[i] } else { 0 };
+            let lo = if k < 9 { v[k] } else { 0 };
+            let shifted = (hi(k + 6) << 48) | (hi(k + 5) >> 16);
+            let t = carry + i128::from(lo) + i128::from(shifted) - i128::from(hi(k + 9));
+            *r = t as u64;
+            carry = t >> 64;
+        }
+        v = r;
+    }
+
+    let mut x = [0; 9];
+    x.copy_from_slice(&v[..9]);
+    if !less_than(&x, &M) {
+        let mut borrow = false;
+        for (x, m) in x.iter_mut().zip(M) {
+            let (d, b1) = x.overflowing_sub(m);
+            let (d, b2) = d.overflowing_sub(u64::from(borrow));
+            *x = d;
+            borrow = b1 || b2;
+        }
+    }
+    x
+}
+
+fn mulmod(x: &U576, y: &U576) -> U576 {
+    let mut prod = [0; 18];
+    for (i, &x) in x.iter().enumerate() {
+        let mut carry = 0;
+        for (j, &y) in y.iter().enumerate() {
+            let t = u128::from(x) * u128::from(y) + u128::from(prod[i + j]) + carry;
+            prod[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        prod[i + 9] = carry as u64;
+    }
+    reduce(prod)
+}
+
+fn powmod(base: &U576, mut exp: u128) -> U576 {
+    let mut result = ONE;
+    let mut base = *base;
+    while exp != 0 {
+        if exp & 1 == 1 {
+            result = mulmod(&result, &base);
+        }
+        base = mulmod(&base, &base);
+        exp >>= 1;
+    }
+    result
+}
+
+// The 10 newest 24-bit numbers of a RANLUX state, where 336 = 5 * 64 + 16
+fn shr336(x: &U576) -> [u64; 4] {
+    [
+        (x[5] >> 16) | (x[6] << 48),
+        (x[6] >> 16) | (x[7] << 48),
+        (x[7] >> 16) | (x[8] << 48),
+        x[8] >> 16,
+    ]
+}
+
+// Convert the LCG state `x` to the RANLUX state, with the oldest number in the
+// lowest 24 bits.
+//
+// A RANLUX state r with carry c corresponds to the LCG state
+// r - (r >> 336) + c mod m. Several states correspond to the same LCG state,
+// but they only differ in the oldest number and the carry, and give the same
+// numbers afterwards. This returns the smallest r with a carry of 0.
+fn to_ranlux(x: &U576) -> U576 {
+    let mut t = shr336(x);
+    loop {
+        let mut r = *x;
+        let mut carry = false;
+        for (k, r) in r.iter_mut().enumerate() {
+            let (s, c1) = r.overflowing_add(t.get(k).copied().unwrap_or(0));
+            let (s, c2) = s.overflowing_add(u64::from(carry));
+            *r = s;
+            carry = c1 || c2;
+        }
+        let t_r = shr336(&r);
+        if t_r == t {
+            return r;
+        }
+        t = t_r;
+    }
+}
+
+/// The RANLUX++ random number generator.
+///
+/// Sibidanov[^1] showed that the subtract-with-borrow generator of
+/// [`Ranlux24`] is equivalent to a linear congruential generator (LCG) with
+/// modulus m = 2<sup>576</sup> - 2<sup>240</sup> + 1: one step of RANLUX is a
+/// multiplication by a = 2<sup>-24</sup> mod m. RANLUX++ uses this to skip
+/// `p` steps at once, by multiplying the LCG state with a<sup>p</sup> mod m,
+/// and returns the complete RANLUX state of 24 numbers after every skip.
+///
+/// This takes the same time for any `p`, so that RANLUX++ can use a much
+/// higher luxury level than is practical for [`Ranlux24`]: [`new`] uses `p` =
+/// 2048, as recommended by Sibidanov, and [`with_luxury`] chooses others.
+/// Skipping ahead any distance is also fast, see [`advance`]. Its period is
+/// about 10<sup>171</sup>. It is not suitable for cryptographic purposes.
+///
+/// Every skip produces a block of 576 bits, or nine `u64` words; the first
+/// word holds the oldest RANLUX number in its lowest 24 bits. `next_u32` uses
+/// the low half of a `u64` output and discards the rest. When several
+/// RANLUX states correspond to an LCG state, the one with a carry of 0 is
+/// used; they only differ in the oldest number.
+///
+/// The LCG state for `seed` is the one `2^96 * seed` skips after 1, so that
+/// the streams of different seeds don't overlap in practice. When seeded
+/// with [`SeedableRng`], the seed is used as a little-endian `u64` for
+/// [`new`].
+///
+/// This is the seeding of `RanluxppEngine::SetSeed` in ROOT, but unlike ROOT,
+/// this skips before the first block and returns `u64` words. Use
+/// [`RanluxppRoot`] for the numbers of ROOT.
+///
+/// This implementation uses [`BlockRng`] to implement the [`RngCore`]
+/// methods.
+///
+/// [^1]: Alexei Sibidanov (2017). "A revision of the subtract-with-borrow
+///       random number generators". *Computer Physics Communications* 221,
+///       pp. 299-303.
+///
+/// [`Ranlux24`]: crate::Ranlux24
+/// [`RanluxppRoot`]: crate::RanluxppRoot
+/// [`new`]: Ranluxpp::new
+/// [`with_luxury`]: Ranluxpp::with_luxury
+/// [`advance`]: Ranluxpp::advance
+/// [`BlockRng`]: rand_core::block::BlockRng
+#[derive(Debug, Clone)]
+pub struct Ranluxpp(BlockRng<RanluxppCore>);
+
+impl Ranluxpp {
+    /// Create a generator at luxury level `p` = 2048 for `seed`.
+    pub fn new(seed: u64) -> Self {
+        Ranluxpp(BlockRng::new(RanluxppCore::new(seed, A_2048)))
+    }
+
+    /// Create a generator which skips `p` steps of RANLUX for every block.
+    ///
+    /// # Panics
+    ///
+    /// If `p` is less than 24: the blocks would overlap.
+    pub fn with_luxury(seed: u64, p: u64) -> Self {
+        assert!(p >= 24, "p must be at least 24");
+        Ranluxpp(BlockRng::new(RanluxppCore::new(seed, powmod(&A, p.into()))))
+    }
+
+    /// Advance the generator by `delta` `u64` outputs.
+    ///
+    /// This takes time proportional to the logarithm of `delta`.
+    pub fn advance(&mut self, delta: u128) {
+        let position = self.0.index() + (delta % 9) as usize;
+        let blocks = delta / 9 + (position / 9) as u128;
+        let index = position % 9;
+
+        let mut core = self.0.core.clone();
+        if index == 0 {
+            // The next output starts block `blocks`, which is generated next.
+            // `blocks` is not 0, as the index of `BlockRng` is at least 1.
+            core.x = mulmod(&powmod(&core.a, blocks - 1), &core.x);
+            self.0.core = core;
+            self.0.reset();
+        } else {
+            core.x = mulmod(&powmod(&core.a, blocks), &core.x);
+            let results = to_ranlux(&core.x);
+            self.0 = BlockRng::reconstruct(core, &results[index..]).unwrap();
+        }
+    }
+}
+
+impl RngCore for Ranluxpp {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_word() as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_word()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+}
+
+impl SeedableRng for Ranluxpp {
+    type Seed = <RanluxppCore as SeedableRng>::Seed;
+
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Self {
+        Ranluxpp(BlockRng::new(RanluxppCore::from_seed(seed)))
+    }
+
+    #[inline]
+    fn from_rng<R>(rng: &mut R) -> Self
+    where
+        R: RngCore + ?Sized,
+    {
+        Ranluxpp(BlockRng::new(RanluxppCore::from_rng(rng)))
+    }
+
+    #[inline]
+    fn try_from_rng<R>(rng: &mut R) -> Result<Self, R::Error>
+    where
+        R: TryRngCore + ?Sized,
+    {
+        RanluxppCore::try_from_rng(rng).map(|core| Ranluxpp(BlockRng::new(core)))
+    }
+}
+
+impl PartialEq for Ranluxpp {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.0.core == rhs.0.core && self.0.index() == rhs.0.index()
+    }
+}
+impl Eq for Ranluxpp {}
+
+/// The RANLUX++ random number generator with the numbers of ROOT.
+///
+/// This is the generator of [`Ranluxpp`], but returns the numbers of
+/// `ROOT::Math::RanluxppEngine2048` of ROOT, and of `RanluxppEngine<p>` with
+/// [`with_luxury`], when given the same seed. The first block is the seeded
+/// state itself, before the first skip, and every block of 576 bits gives
+/// twelve 48-bit numbers, each with the older of two RANLUX numbers in its
+/// lower 24 bits.
+///
+/// These numbers are available with [`next_u48`], like `IntRndm`, and as
+/// floating-point numbers in [0, 1) with [`next_f64`], like `Rndm`. For the
+/// [`RngCore`] methods, `next_u64` combines one value with the upper 16 bits
+/// of the next one, and `next_u32` uses the upper 32 bits of one value. When
+/// seeded with [`SeedableRng`], the seed is used as a little-endian `u64` for
+/// [`new`].
+///
+/// [`new`]: RanluxppRoot::new
+/// [`with_luxury`]: RanluxppRoot::with_luxury
+/// [`next_u48`]: RanluxppRoot::next_u48
+/// [`next_f64`]: RanluxppRoot::next_f64
+#[derive(Clone, PartialEq, Eq)]
+pub struct RanluxppRoot {
+    // The LCG state of the current block, less than m
+    x: U576,
+    // The multiplier a^p mod m
+    a: U576,
+    // The current block as RANLUX numbers
+    block: U576,
+    // The numbers returned of the current block, at most 12
+    position: u8,
+}
+
+// Custom Debug implementation that does not expose the internal state
+impl fmt::Debug for RanluxppRoot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RanluxppRoot {{}}")
+    }
+}
+
+impl RanluxppRoot {
+    /// Create a generator at luxury level `p` = 2048 like
+    /// `RanluxppEngine2048 rng(seed)` in ROOT.
+    pub fn new(seed: u64) -> Self {
+        RanluxppRoot::from_core(RanluxppCore::new(seed, A_2048))
+    }
+
+    /// Create a generator which skips `p` steps of RANLUX for every block,
+    /// like `RanluxppEngine<p> rng(seed)` in ROOT.
+    ///
+    /// # Panics
+    ///
+    /// If `p` is less than 24: the blocks would overlap.
+    pub fn with_luxury(seed: u64, p: u64) -> Self {
+        assert!(p >= 24, "p must be at least 24");
+        RanluxppRoot::from_core(RanluxppCore::new(seed, powmod(&A, p.into())))
+    }
+
+    fn from_core(RanluxppCore { x, a }: RanluxppCore) -> Self {
+        RanluxppRoot {
+            x,
+            a,
+            block: to_ranlux(&x),
+            position: 0,
+        }
+    }
+
+    /// Return the next 48-bit number, like `IntRndm` of ROOT.
+    #[inline]
+    pub fn next_u48(&mut self) -> u64 {
+        if self.position == 12 {
+            self.x = mulmod(&self.a, &self.x);
+            self.block = to_ranlux(&self.x);
+            self.position = 0;
+        }
+        let bit = 48 * usize::from(self.position);
+        let mut v = self.block[bit / 64] >> (bit % 64);
+        if bit % 64 > 16 {
+            v |= self.block[bit / 64 + 1] << (64 - bit % 64);
+        }
+        self.position += 1;
+        v & ((1 << 48) - 1)
+    }
+
+    /// Return the next 48-bit number as a floating-point number in [0, 1),
+    /// like `Rndm` of ROOT.
+    #[inline]
+    pub fn next_f64(&mut self) -> f64 {
+        self.next_u48() as f64 * (1.0 / (1u64 << 48) as f64)
+    }
+
+    /// Advance the generator by `delta` 48-bit numbers, like `Skip` of ROOT.
+    ///
+    /// This takes time proportional to the logarithm of `delta`.
+    pub fn advance(&mut self, delta: u128) {
+        let mut blocks = delta / 12;
+        let mut position = (delta % 12) as u8 + self.position;
+        // Like `next_u48`, only generate a block when a number of it is used.
+        if position > 12 {
+            blocks += 1;
+            position -= 12;
+        } else if position == 0 && blocks > 0 {
+            blocks -= 1;
+            position = 12;
+        }
+        if blocks > 0 {
+            self.x = mulmod(&powmod(&self.a, blocks), &self.x);
+            self.block = to_ranlux(&self.x);
+        }
+        self.position = position;
+    }
+}
+
+impl RngCore for RanluxppRoot {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u48() >> 16) as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let hi = self.next_u48();
+        let lo = self.next_u48();
+        (hi << 16) | (lo >> 32)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        utils::fill_bytes_via_next_word(dest, || self.next_u64())
+    }
+}
+
+impl SeedableRng for RanluxppRoot {
+    type Seed = [u8; 8];
+
+    /// Create a generator like `RanluxppEngine2048`, using the seed as a
+    /// little-endian `u64`.
+    fn from_seed(seed: Self::Seed) -> Self {
+        let [seed] = utils::read_words(&seed);
+        RanluxppRoot::new(seed)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use super::{BlockRng, M, Ranluxpp, RanluxppCore, RanluxppRoot, less_than, to_ranlux};
+    use serde::de::{Deserialize, Deserializer, Error};
+    use serde::ser::{Serialize, Serializer};
+
+    // The buffered block is a function of the LCG state, so only the index of
+    // the next output in it is stored.
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(rename = "Ranluxpp")]
+    struct State {
+        core: RanluxppCore,
+        index: u8,
+    }
+
+    impl Serialize for Ranluxpp {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            State {
+                core: self.0.core.clone(),
+                index: self.0.index() as u8,
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Ranluxpp {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let State { core, index } = State::deserialize(deserializer)?;
+            if !(1..=9).contains(&index) {
+                return Err(D::Error::custom("index out of range"));
+            }
+            if !less_than(&core.x, &M) || !less_than(&core.a, &M) {
+                return Err(D::Error::custom("state out of range"));
+            }
+            let results = to_ranlux(&core.x);
+            Ok(Ranluxpp(
+                BlockRng::reconstruct(core, &results[index.into()..]).unwrap(),
+            ))
+        }
+    }
+    // The current block is a function of the LCG state, and is not stored.
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(rename = "RanluxppRoot")]
+    struct RootState {
+        core: RanluxppCore,
+        position: u8,
+    }
+
+    impl Serialize for RanluxppRoot {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            RootState {
+                core: RanluxppCore {
+                    x: self.x,
+                    a: self.a,
+                },
+                position: self.position,
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for RanluxppRoot {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let RootState { core, position } = RootState::deserialize(deserializer)?;
+            if position > 12 {
+                return Err(D::Error::custom("position out of range"));
+            }
+            if !less_than(&core.x, &M) || !less_than(&core.a, &M) {
+                return Err(D::Error::custom("state out of range"));
+            }
+            Ok(RanluxppRoot {
+                position,
+                ..RanluxppRoot::from_core(core)
+            })
+        }
+    }
+}
+
+/// The core of `Ranluxpp`, used with `BlockRng`.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RanluxppCore {
+    // The LCG state, less than m
+    x: U576,
+    // The multiplier a^p mod m
+    a: U576,
+}
+
+// Custom Debug implementation that does not expose the internal state
+impl fmt::Debug for RanluxppCore {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RanluxppCore {{}}")
+    }
+}
+
+impl RanluxppCore {
+    fn new(seed: u64, a: U576) -> Self {
+        let mut skip = a;
+        for _ in 0..96 {
+            skip = mulmod(&skip, &skip);
+        }
+        RanluxppCore {
+            x: powmod(&skip, seed.into()),
+            a,
+        }
+    }
+}
+
+impl Generator for RanluxppCore {
+    type Output = U576;
+
+    #[inline]
+    fn generate(&mut self, results: &mut Self::Output) {
+        self.x = mulmod(&self.a, &self.x);
+        *results = to_ranlux(&self.x);
+    }
+}
+
+impl SeedableRng for RanluxppCore {
+    type Seed = [u8; 8];
+
+    /// Create a new `RanluxppCore` at luxury level `p` = 2048, using the seed
+    /// as a little-endian `u64`.
+    fn from_seed(seed: Self::Seed) -> Self {
+        let [seed] = utils::read_words(&seed);
+        RanluxppCore::new(seed, A_2048)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The LCG state for a RANLUX state r with carry c
+    fn to_lcg(r: &U576, c: bool) -> U576 {
+        let t = shr336(r);
+        let mut v = [0; 18];
+        let mut carry: i128 = i128::from(c);
+        for (k, v) in v[..9].iter_mut().enumerate() {
+            let s = carry + i128::from(r[k]) - i128::from(t.get(k).copied().unwrap_or(0));
+            *v = s as u64;
+            carry = s >> 64;
+        }
+        reduce(v)
+    }
+
+    // `p` steps of the subtract-with-borrow generator on a RANLUX state
+    fn ranlux_steps(r: &U576, p: usize) -> (U576, bool) {
+        let mask = (1 << 24) - 1;
+        let mut x = [0u32; 24];
+        for (k, x) in x.iter_mut().enumerate() {
+            let bit = 24 * k;
+            let mut v = r[bit / 64] >> (bit % 64);
+            if bit % 64 > 40 {
+                v |= r[bit / 64 + 1] << (64 - bit % 64);
+            }
+            *x = v as u32 & mask;
+        }
+        let mut carry = 0;
+        for n in 0..p {
+            let (new, borrow) = x[(n + 14) % 24].overflowing_sub(x[n % 24] + carry);
+            x[n % 24] = new & mask;
+            carry = u32::from(borrow);
+        }
+        let mut r = [0; 9];
+        for k in 0..24 {
+            let bit = 24 * k;
+            let v = u64::from(x[(p + k) % 24]);
+            r[bit / 64] |= v << (bit % 64);
+            if bit % 64 > 40 {
+                r[bit / 64 + 1] |= v >> (64 - bit % 64);
+            }
+        }
+        (r, carry == 1)
+    }
+
+    #[test]
+    fn multiplier() {
+        assert_eq!(mulmod(&A, &[1 << 24, 0, 0, 0, 0, 0, 0, 0, 0]), ONE);
+        assert_eq!(powmod(&A, 2048), A_2048);
+    }
+
+    #[test]
+    fn lcg_is_ranlux() {
+        let mut rng = Ranluxpp::new(1);
+        for p in [1, 10, 24, 100, 389] {
+            let x = rng.0.core.x;
+            let r = to_ranlux(&x);
+            assert_eq!(to_lcg(&r, false), x);
+
+            let (r, c) = ranlux_steps(&r, p);
+            assert_eq!(to_lcg(&r, c), mulmod(&powmod(&A, p as u128), &x));
+            rng.advance(9);
+        }
+    }
+
+    #[test]
+    fn seeding() {
+        // The LCG state (a^2048)^(2^96 * seed) mod m, computed with Python's
+        // integers following `RanluxppEngineImpl::SetSeed` of ROOT, for its
+        // default seed and for 1
+        let rng = Ranluxpp::new(314159265);
+        let expected = [
+            0x5ff1ea4f887d693c,
+            0x2081f4e4b9d1cab3,
+            0x77282c012fdebe77,
+            0xcd2e45c6b5069c45,
+            0x7ce7d57ba8b98182,
+            0x3980ec83b845f4a0,
+            0x9059d8491278f705,
+            0xd94264c5634e05dd,
+            0x2b0a44aaf5502f7e,
+        ];
+        assert_eq!(rng.0.core.x, expected);
+
+        let rng = Ranluxpp::new(1);
+        let expected = [
+            0x9f1c67142c84c502,
+            0x024d94e3c4b490e8,
+            0xe9d460859f0659b6,
+            0xd697d9321e8373b1,
+            0x1164275f61142884,
+            0xd644d1bd1837c737,
+            0xad4191bcf0926c6b,
+            0x2624a1b9ef2c42c0,
+            0xf671bbcee85222ab,
+        ];
+        assert_eq!(rng.0.core.x, expected);
+    }
+
+    #[test]
+    fn reference() {
+        // These values were computed with Python's integers, as the RANLUX
+        // states of the LCG states (a^p)^(2^96 * seed + n) mod m for n = 1, 2,
+        // ..., with the carry of 0. See `root_blocks` for their relation to
+        // the numbers of ROOT.
+        let mut rng = Ranluxpp::new(314159265);
+        let expected = [
+            9393567928033133726,
+            689115134357300085,
+            3927948048422666204,
+            10513118315584970153,
+            6769249276926196752,
+            15421700371935404230,
+            1733929486444986343,
+            11478715969800748622,
+            13507781455622543379,
+            15819545878966503015,
+            5298572178641979701,
+            2008717950536750676,
+        ];
+        for e in expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+
+        let mut rng = Ranluxpp::with_luxury(1, 24);
+        let expected = [
+            954886891077224176,
+            1830413285273790087,
+            15324303948992480410,
+        ];
+        for e in expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn advance() {
+        let mut rng = Ranluxpp::new(42);
+        rng.advance(9000);
+        assert_eq!(rng.next_u64(), 16384764544604544730);
+        assert_eq!(rng.next_u64(), 18148400602177471237);
+        assert_eq!(rng.next_u64(), 9957969821492442276);
+
+        for start in 0..10 {
+            for delta in [0, 1, 5, 8, 9, 10, 17, 18, 100] {
+                let mut rng1 = Ranluxpp::new(7);
+                for _ in 0..start {
+                    rng1.next_u64();
+                }
+                let mut rng2 = rng1.clone();
+                rng1.advance(delta);
+                for _ in 0..delta {
+                    rng2.next_u64();
+                }
+                assert_eq!(rng1, rng2);
+                assert_eq!(rng1.next_u64(), rng2.next_u64());
+            }
+        }
+    }
+
+    #[test]
+    fn seed() {
+        let rng = Ranluxpp::from_seed([42, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(rng, Ranluxpp::new(42));
+        assert_eq!(Ranluxpp::with_luxury(42, 2048), Ranluxpp::new(42));
+    }
+
+    #[test]
+    fn root() {
+        // The values of the unit tests of `RanluxppEngine2048` in ROOT
+        let mut rng = RanluxppRoot::new(314159265);
+        assert_eq!(rng.next_u48(), 39378223178113);
+        assert_eq!(rng.next_f64(), 0.5707224114657627);
+        rng.advance(8);
+        assert_eq!(rng.next_u48(), 52221857391813);
+
+        // These values were computed with Python's integers, following
+        // `RanluxppEngineImpl` of ROOT, across the end of the first block.
+        assert_eq!(rng.next_f64(), 0.16812543081078957);
+        let expected = [185005245121694, 79948189893212, 281320518335120];
+        for e in expected {
+            assert_eq!(rng.next_u48(), e);
+        }
+
+        let mut rng = RanluxppRoot::with_luxury(1, 24);
+        rng.advance(11);
+        let expected = [238461455939364, 123770074679024, 102144632360256];
+        for e in expected {
+            assert_eq!(rng.next_u48(), e);
+        }
+    }
+
+    #[test]
+    fn root_blocks() {
+        // The blocks of `RanluxppRoot` are the seeded state and then those of
+        // `Ranluxpp`, and follow each other like RANLUX.
+        let mut root = RanluxppRoot::with_luxury(42, 389);
+        let mut rng = Ranluxpp::with_luxury(42, 389);
+        let block = |root: &mut RanluxppRoot| {
+            let mut r = [0; 9];
+            for k in 0..12 {
+                let bit = 48 * k;
+                let v = root.next_u48();
+                r[bit / 64] |= v << (bit % 64);
+                if bit % 64 > 16 {
+                    r[bit / 64 + 1] |= v >> (64 - bit % 64);
+                }
+            }
+            r
+        };
+        let mut prev = block(&mut root);
+        assert_eq!(prev, to_ranlux(&RanluxppCore::new(42, rng.0.core.a).x));
+        for _ in 0..4 {
+            let r = block(&mut root);
+            for w in r {
+                assert_eq!(rng.next_u64(), w);
+            }
+            let (stepped, c) = ranlux_steps(&prev, 389);
+            assert_eq!(to_lcg(&r, false), to_lcg(&stepped, c));
+            prev = r;
+        }
+    }
+
+    #[test]
+    fn root_advance() {
+        for start in 0..14 {
+            for delta in [0, 1, 5, 11, 12, 13, 23, 24, 100] {
+                let mut rng1 = RanluxppRoot::new(7);
+                for _ in 0..start {
+                    rng1.next_u48();
+                }
+                let mut rng2 = rng1.clone();
+                rng1.advance(delta);
+                for _ in 0..delta {
+                    rng2.next_u48();
+                }
+                assert_eq!(rng1, rng2);
+                assert_eq!(rng1.next_u48(), rng2.next_u48());
+            }
+        }
+
+        let rng = RanluxppRoot::from_seed([42, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(rng, RanluxppRoot::new(42));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let mut rng = Ranluxpp::seed_from_u64(42);
+        for skip in 0..10 {
+            let buf = postcard::to_allocvec(&rng).expect("Could not serialize");
+            let mut deserialized: Ranluxpp =
+                postcard::from_bytes(&buf).expect("Could not deserialize");
+            assert_eq!(rng, deserialized);
+            for _ in 0..10 {
+                assert_eq!(rng.next_u64(), deserialized.next_u64());
+            }
+            for _ in 0..skip {
+                rng.next_u64();
+            }
+        }
+    }
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_root() {
+        let mut rng = RanluxppRoot::new(42);
+        for skip in 0..14 {
+            let buf = postcard::to_allocvec(&rng).expect("Could not serialize");
+            let mut deserialized: RanluxppRoot =
+                postcard::from_bytes(&buf).expect("Could not deserialize");
+            assert_eq!(rng, deserialized);
+            for _ in 0..14 {
+                assert_eq!(rng.next_u48(), deserialized.next_u48());
+            }
+            rng.advance(skip);
+        }
+    }
+}
@@ -0,0 +1,56 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Romu family of pseudorandom number generators.
+//!
+//! The [Romu] generators designed by Mark A. Overton combine a multiplication
+//! with rotations, additions and subtractions. They are nonlinear and have a
+//! very short dependency chain per output, so they make good use of
+//! instruction-level parallelism and are among the fastest generators
+//! available. Their period depends on the seed; instead of a guaranteed
+//! minimum, each generator has a *capacity*, the number of bytes it can
+//! produce before a cycle is expected to be too short. Like most generators
+//! intended for non-cryptographic use, they can be predicted from their
+//! output.
+//!
+//! This crate provides:
+//! - [`RomuQuad`]: 256 bits of state, for massively parallel applications.
+//! - [`RomuTrio`]: 192 bits of state, recommended for general use.
+//! - [`RomuDuo`]: 128 bits of state, faster than `RomuTrio` in some loops.
+//! - [`RomuDuoJr`]: 128 bits of state, the fastest and lowest-capacity member.
+//! - [`RomuQuad32`] and [`RomuTrio32`]: variants using 32-bit arithmetic.
+//!
+//! The all-zero state is a fixed point of every Romu generator, so an
+//! all-zero seed is mapped to a different one.
+//!
+//! [Romu]: https://www.romu-random.org/
+
+#![doc(
+    html_logo_url = "https://www.rust-lang.org/logos/rust-logo-128x128-blk.png",
+    html_favicon_url = "https://www.rust-lang.org/favicon.ico"
+)]
+#![forbid(unsafe_code)]
+#![deny(missing_docs)]
+#![deny(missing_debug_implementations)]
+#![doc(test(attr(allow(unused_variables), deny(warnings))))]
+#![no_std]
+
+mod romu_duo;
+mod romu_duo_jr;
+mod romu_quad;
+mod romu_quad32;
+mod romu_trio;
+mod romu_trio32;
+
+pub use rand_core;
+pub use romu_duo::RomuDuo;
+pub use romu_duo_jr::RomuDuoJr;
+pub use romu_quad::RomuQuad;
+pub use romu_quad32::RomuQuad32;
+pub use romu_trio::RomuTrio;
+pub use romu_trio32::RomuTrio32;
@@ -0,0 +1,116 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use rand_core::{RngCore, SeedableRng, utils};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const MUL: u64 = 15241094284759029579;
+
+/// A RomuDuo random number generator.
+///
+/// RomuDuo has 128 bits of state. It is slightly faster than [`RomuTrio`] in
+/// some loops, but has a lower capacity of about 2^61 bytes, so it is best
+/// suited to applications that generate moderate amounts of output.
+///
+/// The algorithm used here is translated from the reference source code in
+/// [the Romu paper](https://www.romu-random.org/) by Mark A. Overton.
+///
+/// [`RomuTrio`]: crate::RomuTrio
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RomuDuo {
+    x: u64,
+    y: u64,
+}
+
+impl RngCore for RomuDuo {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let xp = self.x;
+        self.x = MUL.wrapping_mul(self.y);
+        self.y = self
+            .y
+            .rotate_left(36)
+            .wrapping_add(self.y.rotate_left(15))
+            .wrapping_sub(xp);
+        xp
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        utils::fill_bytes_via_next_word(dest, || self.next_u64());
+    }
+}
+
+impl SeedableRng for RomuDuo {
+    type Seed = [u8; 16];
+
+    /// Create a new `RomuDuo`. If `seed` is entirely 0, it will be mapped to
+    /// a different seed, since the all-zero state is a fixed point.
+    fn from_seed(seed: [u8; 16]) -> RomuDuo {
+        if seed == [0; 16] {
+            return RomuDuo::seed_from_u64(0);
+        }
+        let [x, y] = utils::read_words(&seed);
+        RomuDuo { x, y }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference() {
+        let mut rng = RomuDuo::from_seed([1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+        // These values were produced with the reference implementation:
+        // `romuDuo_random` from https://www.romu-random.org/
+        let expected = [
+            1,
+            12035444495808507542,
+            6091112088061520053,
+            15247473810760332814,
+            4016093660068235111,
+            4041301874668610437,
+            1544744609702868527,
+            9070204474037005789,
+            8832412314208222048,
+            4071963928619261017,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn zero_seed() {
+        let mut rng = RomuDuo::from_seed([0; 16]);
+        assert_ne!(rng, RomuDuo { x: 0, y: 0 });
+        assert_ne!(rng.next_u64(), rng.next_u64());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let mut rng = RomuDuo::seed_from_u64(0);
+        rng.next_u32();
+        let buf = postcard::to_allocvec(&rng).unwrap();
+        let mut deserialized: RomuDuo = postcard::from_bytes(&buf).unwrap();
+        assert_eq!(deserialized, rng);
+        for _ in 0..16 {
+            assert_eq!(rng.next_u64(), deserialized.next_u64());
+        }
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use rand_core::{RngCore, SeedableRng, utils};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const MUL: u32 = 3323815723;
+
+/// A RomuTrio32 random number generator.
+///
+/// RomuTrio32 is the 32-bit variant of [`RomuTrio`], with 96 bits of state
+/// and 32-bit arithmetic, for 32-bit processors. Its capacity is about 2^53
+/// bytes.
+///
+/// The algorithm used here is translated from the reference source code in
+/// [the Romu paper](https://www.romu-random.org/) by Mark A. Overton.
+///
+/// [`RomuTrio`]: crate::RomuTrio
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RomuTrio32 {
+    x: u32,
+    y: u32,
+    z: u32,
+}
+
+impl RngCore for RomuTrio32 {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        let (xp, yp, zp) = (self.x, self.y, self.z);
+        self.x = MUL.wrapping_mul(zp);
+        self.y = yp.wrapping_sub(xp).rotate_left(6);
+        self.z = zp.wrapping_sub(yp).rotate_left(22);
+        xp
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        utils::next_u64_via_u32(self)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        utils::fill_bytes_via_next_word(dest, || self.next_u32());
+    }
+}
+
+impl SeedableRng for RomuTrio32 {
+    type Seed = [u8; 12];
+
+    /// Create a new `RomuTrio32`. If `seed` is entirely 0, it will be mapped to
+    /// a different seed, since the all-zero state is a fixed point.
+    fn from_seed(seed: [u8; 12]) -> RomuTrio32 {
+        if seed == [0; 12] {
+            return RomuTrio32::seed_from_u64(0);
+        }
+        let [x, y, z] = utils::read_words(&seed);
+        RomuTrio32 { x, y, z }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference() {
+        let mut rng = RomuTrio32::from_seed([1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0]);
+        // These values were produced with the reference implementation:
+        // `romuTrio32_random` from https://www.romu-random.org/
+        let expected = [
+            1, 1381512577, 3401580544, 1616201941, 1232173000, 1326144886, 1037456162, 930870068,
+            2085970664, 1346945208,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u32(), e);
+        }
+    }
+
+    #[test]
+    fn zero_seed() {
+        let mut rng = RomuTrio32::from_seed([0; 12]);
+        assert_ne!(rng, RomuTrio32 { x: 0, y: 0, z: 0 });
+        assert_ne!(rng.next_u32(), rng.next_u32());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let mut rng = RomuTrio32::seed_from_u64(0);
+        rng.next_u32();
+        let buf = postcard::to_allocvec(&rng).unwrap();
+        let mut deserialized: RomuTrio32 = postcard::from_bytes(&buf).unwrap();
+        assert_eq!(deserialized, rng);
+        for _ in 0..16 {
+            assert_eq!(rng.next_u64(), deserialized.next_u64());
+        }
+    }
+}
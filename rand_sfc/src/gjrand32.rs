@@ -0,0 +1,121 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use rand_core::{RngCore, SeedableRng, utils};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const SEED_C: u32 = 2000001;
+const SEED_ROUNDS: u32 = 14;
+const D_INC: u32 = 0x96a5;
+
+/// A gjrand32 random number generator.
+///
+/// A 32-bit variant of [`Gjrand64`], known as `gjrand32` in PractRand; it is
+/// not part of the gjrand library itself. It has 32-bit words, rotates scaled
+/// to the smaller word size and a smaller Weyl increment, which guarantees a
+/// minimum period of 2^32. It is meant for platforms without fast 64-bit
+/// arithmetic, and is not cryptographically secure.
+///
+/// [`Gjrand32::new`] seeds it like `gjrand32::seed` of PractRand, which splits
+/// the seed into the first two words, with the high half first, sets the
+/// others to fixed values and discards the first 14 outputs.
+///
+/// [`Gjrand64`]: crate::Gjrand64
+#[allow(missing_copy_implementations)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Gjrand32 {
+    a: u32,
+    b: u32,
+    c: u32,
+    d: u32,
+}
+
+impl Gjrand32 {
+    /// Create a new `Gjrand32` like `gjrand32::seed` of PractRand.
+    pub fn new(seed: u64) -> Gjrand32 {
+        let mut rng = Gjrand32 {
+            a: (seed >> 32) as u32,
+            b: seed as u32,
+            c: SEED_C,
+            d: 0,
+        };
+        for _ in 0..SEED_ROUNDS {
+            rng.next_u32();
+        }
+        rng
+    }
+}
+
+impl RngCore for Gjrand32 {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.b = self.b.wrapping_add(self.c);
+        self.a = self.a.rotate_left(16);
+        self.c ^= self.b;
+        self.d = self.d.wrapping_add(D_INC);
+        self.a = self.a.wrapping_add(self.b);
+        self.c = self.c.rotate_left(11);
+        self.b ^= self.a;
+        self.a = self.a.wrapping_add(self.c);
+        self.b = self.b.rotate_left(19);
+        self.c = self.c.wrapping_add(self.a);
+        self.b = self.b.wrapping_add(self.d);
+        self.a
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        utils::next_u64_via_u32(self)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        utils::fill_bytes_via_next_word(dest, || self.next_u32());
+    }
+}
+
+impl SeedableRng for Gjrand32 {
+    type Seed = [u8; 8];
+
+    /// Create a new `Gjrand32`, like [`Gjrand32::new`] with the little-endian
+    /// `seed`.
+    fn from_seed(seed: [u8; 8]) -> Gjrand32 {
+        Gjrand32::new(u64::from_le_bytes(seed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference() {
+        // These values were produced with a C transcription of
+        // `gjrand32::seed` and `gjrand32::raw32` of PractRand.
+        let mut rng = Gjrand32::new(0);
+        let expected = [
+            3538648542, 1037414560, 2143380698, 2410659076, 2831175193, 274967298, 1521677454,
+            3207737349,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u32(), e);
+        }
+
+        let mut rng = Gjrand32::from_seed(0xdeadbeefcafef00d_u64.to_le_bytes());
+        let expected = [
+            3343534207, 2371878791, 4291630872, 637498471, 1593164521, 2224903365, 1464114820,
+            3438153268,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u32(), e);
+        }
+    }
+}
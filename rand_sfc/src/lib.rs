@@ -20,7 +20,24 @@
 //! - [`Sfc32`]: 32 bit output, seed space 96 bits, worst-case period 2^32,
 //!   and expected period ~2^127.
 //!
-//! The implementations provided are derived from PractRand.
+//! It also provides Bob Jenkins' small fast generators (jsf), which PractRand
+//! includes as well:
+//! - [`Jsf64`]: 64 bit output, seed space 64 bits.
+//! - [`Jsf32`] and [`Jsf32Rot3`]: 32 bit output, seed space 32 bits, with two
+//!   and three rotates per round respectively.
+//!
+//! The jsf generators have no guaranteed minimum period, but no short cycles
+//! are known to be reachable from their canonical seeding, which is what
+//! `SeedableRng` uses.
+//!
+//! Other small nonlinear generators with a similar design:
+//! - [`Gjrand64`]: David Blackman's gjrand, with a Weyl sequence which
+//!   guarantees a minimum period of 2^64, and [`Gjrand32`], its 32-bit
+//!   variant from PractRand with a minimum period of 2^32.
+//! - [`Tyche`] and [`TycheI`]: iterate the ChaCha quarter-round and its
+//!   inverse, with 2^32 streams selected on construction.
+//!
+//! The sfc implementations provided are derived from PractRand.
 //!
 //! [PractRand]: https://pracrand.sourceforge.net/
 //! [predicted]: https://github.com/michaelni/randomtests/blob/main/sfc64-breach.c
@@ -31,9 +48,19 @@
 #![deny(missing_debug_implementations)]
 #![no_std]
 
+mod gjrand32;
+mod gjrand64;
+mod jsf32;
+mod jsf64;
 mod sfc32;
 mod sfc64;
+mod tyche;
 
+pub use gjrand32::Gjrand32;
+pub use gjrand64::Gjrand64;
+pub use jsf32::{Jsf32, Jsf32Rot3};
+pub use jsf64::Jsf64;
 pub use rand_core;
 pub use sfc32::Sfc32;
 pub use sfc64::Sfc64;
+pub use tyche::{Tyche, TycheI};
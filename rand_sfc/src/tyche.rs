@@ -0,0 +1,254 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use rand_core::{RngCore, SeedableRng, utils};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// floor(2^32 / golden ratio)
+const SEED_C: u32 = 2654435769;
+// floor(2^32 / pi)
+const SEED_D: u32 = 1367130551;
+const SEED_ROUNDS: u32 = 20;
+
+/// A Tyche random number generator by Samuel Neves and Filipe Araujo.
+///
+/// Tyche[^1] iterates the quarter-round of ChaCha on a state of four 32-bit
+/// words, and returns one of them. This is a nonlinear invertible map, so the
+/// period depends on the seed, and is expected to be about 2^127. It is not
+/// cryptographically secure.
+///
+/// [`Tyche::new`] uses the initialization of the paper, which selects one of
+/// 2^32 streams with `idx`, and discards the first 20 outputs. See
+/// [`TycheI`] for the variant with the inverse map, which is faster on most
+/// CPUs.
+///
+/// [^1]: Samuel Neves and Filipe Araujo (2012). "Fast and Small Nonlinear
+///       Pseudorandom Number Generators for Computer Simulation". *Parallel
+///       Processing and Applied Mathematics*, LNCS 7203.
+#[allow(missing_copy_implementations)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Tyche {
+    a: u32,
+    b: u32,
+    c: u32,
+    d: u32,
+}
+
+impl Tyche {
+    /// Create a new `Tyche` with the given seed and stream index.
+    pub fn new(seed: u64, idx: u32) -> Tyche {
+        let mut rng = Tyche {
+            a: (seed >> 32) as u32,
+            b: seed as u32,
+            c: SEED_C,
+            d: SEED_D ^ idx,
+        };
+        for _ in 0..SEED_ROUNDS {
+            rng.mix();
+        }
+        rng
+    }
+
+    #[inline]
+    fn mix(&mut self) {
+        self.a = self.a.wrapping_add(self.b);
+        self.d = (self.d ^ self.a).rotate_left(16);
+        self.c = self.c.wrapping_add(self.d);
+        self.b = (self.b ^ self.c).rotate_left(12);
+        self.a = self.a.wrapping_add(self.b);
+        self.d = (self.d ^ self.a).rotate_left(8);
+        self.c = self.c.wrapping_add(self.d);
+        self.b = (self.b ^ self.c).rotate_left(7);
+    }
+}
+
+impl RngCore for Tyche {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.mix();
+        self.b
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        utils::next_u64_via_u32(self)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        utils::fill_bytes_via_next_word(dest, || self.next_u32());
+    }
+}
+
+impl SeedableRng for Tyche {
+    /// The seed as a little-endian `u64`, followed by the stream index as a
+    /// little-endian `u32`.
+    type Seed = [u8; 12];
+
+    /// Create a new `Tyche`, like [`Tyche::new`].
+    fn from_seed(seed: [u8; 12]) -> Tyche {
+        let [lo, hi, idx]: [u32; 3] = utils::read_words(&seed);
+        Tyche::new(u64::from(lo) | (u64::from(hi) << 32), idx)
+    }
+}
+
+/// A Tyche-i random number generator by Samuel Neves and Filipe Araujo.
+///
+/// The variant of [`Tyche`] which iterates the inverse of its map. This has
+/// more instruction-level parallelism, which makes it faster on most CPUs,
+/// and it has the same statistical properties. It is not cryptographically
+/// secure.
+///
+/// [`TycheI::new`] uses the same initialization as [`Tyche::new`], with the
+/// inverse map.
+#[allow(missing_copy_implementations)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TycheI {
+    a: u32,
+    b: u32,
+    c: u32,
+    d: u32,
+}
+
+impl TycheI {
+    /// Create a new `TycheI` with the given seed and stream index.
+    pub fn new(seed: u64, idx: u32) -> TycheI {
+        let mut rng = TycheI {
+            a: (seed >> 32) as u32,
+            b: seed as u32,
+            c: SEED_C,
+            d: SEED_D ^ idx,
+        };
+        for _ in 0..SEED_ROUNDS {
+            rng.mix();
+        }
+        rng
+    }
+
+    #[inline]
+    fn mix(&mut self) {
+        self.b = self.b.rotate_right(7) ^ self.c;
+        self.c = self.c.wrapping_sub(self.d);
+        self.d = self.d.rotate_right(8) ^ self.a;
+        self.a = self.a.wrapping_sub(self.b);
+        self.b = self.b.rotate_right(12) ^ self.c;
+        self.c = self.c.wrapping_sub(self.d);
+        self.d = self.d.rotate_right(16) ^ self.a;
+        self.a = self.a.wrapping_sub(self.b);
+    }
+}
+
+impl RngCore for TycheI {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.mix();
+        self.a
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        utils::next_u64_via_u32(self)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        utils::fill_bytes_via_next_word(dest, || self.next_u32());
+    }
+}
+
+impl SeedableRng for TycheI {
+    /// The seed as a little-endian `u64`, followed by the stream index as a
+    /// little-endian `u32`.
+    type Seed = [u8; 12];
+
+    /// Create a new `TycheI`, like [`TycheI::new`].
+    fn from_seed(seed: [u8; 12]) -> TycheI {
+        let [lo, hi, idx]: [u32; 3] = utils::read_words(&seed);
+        TycheI::new(u64::from(lo) | (u64::from(hi) << 32), idx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // These values were produced with a C transcription of the algorithms of
+    // the paper.
+
+    #[test]
+    fn reference() {
+        let mut rng = Tyche::new(0, 0);
+        let expected = [
+            48616349, 1095258080, 2315158576, 3886718522, 3881204654, 1162654570, 1016679259,
+            3075699397,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u32(), e);
+        }
+
+        let mut rng = Tyche::new(0xdeadbeefcafef00d, 7);
+        let expected = [
+            3921181519, 1106050633, 499236131, 2579835719, 1055301890, 1826999635, 3573304392,
+            3972683765,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u32(), e);
+        }
+    }
+
+    #[test]
+    fn reference_i() {
+        let mut rng = TycheI::new(0, 0);
+        let expected = [
+            699550068, 1915753960, 1728359319, 3386243413, 2946823904, 1973207123, 3326894554,
+            3191394185,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u32(), e);
+        }
+
+        let mut rng = TycheI::new(0xdeadbeefcafef00d, 7);
+        let expected = [
+            3966942037, 3019033067, 2943662132, 2929614906, 147887819, 2939177727, 141777799,
+            2961586859,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u32(), e);
+        }
+    }
+
+    #[test]
+    fn inverse() {
+        // Tyche-i undoes the steps of Tyche.
+        let mut rng = Tyche::new(42, 1);
+        let start = rng.clone();
+        for _ in 0..10 {
+            rng.mix();
+        }
+        let Tyche { a, b, c, d } = rng;
+        let mut inverse = TycheI { a, b, c, d };
+        for _ in 0..10 {
+            inverse.mix();
+        }
+        let Tyche { a, b, c, d } = start;
+        assert_eq!(inverse, TycheI { a, b, c, d });
+    }
+
+    #[test]
+    fn seed() {
+        let mut seed = [0; 12];
+        seed[..8].copy_from_slice(&0xdeadbeefcafef00d_u64.to_le_bytes());
+        seed[8] = 7;
+        assert_eq!(Tyche::from_seed(seed), Tyche::new(0xdeadbeefcafef00d, 7));
+        assert_eq!(TycheI::from_seed(seed), TycheI::new(0xdeadbeefcafef00d, 7));
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use rand_core::{RngCore, utils};
+
+/// Generate a key for [`squares32`] and [`squares64`] from `rng`.
+///
+/// The quality of Squares depends on the key having an irregular bit
+/// pattern, so keys are built one hexadecimal digit at a time, following the
+/// rules of Widynski's key generator:
+/// - every digit is non-zero,
+/// - the eight digits of each 32-bit half are all different,
+/// - the most significant digit of the low half differs from the least
+///   significant digit of the high half,
+/// - the key is odd, so that different counters never give the same product
+///   `counter * key`.
+///
+/// [`squares32`]: crate::squares32
+/// [`squares64`]: crate::squares64
+pub fn generate_key<R: RngCore + ?Sized>(rng: &mut R) -> u64 {
+    let mut key = 0;
+    for half in 0..2 {
+        // Bit `d` is set once digit `d` was used in this half.
+        let mut used = 1u16;
+        for i in 0..8 {
+            let digit = loop {
+                let d = rng.next_u32() >> 28;
+                let repeat = used & (1 << d) != 0;
+                let adjacent = half == 1 && i == 0 && u64::from(d) == key & 0xf;
+                let even = half == 1 && i == 7 && d % 2 == 0;
+                if !(repeat || adjacent || even) {
+                    break d;
+                }
+            };
+            used |= 1 << digit;
+            key = key << 4 | u64::from(digit);
+        }
+    }
+    key
+}
+
+/// SplitMix64, used to expand a seed to a key.
+pub(crate) struct SplitMix64(pub(crate) u64);
+
+impl RngCore for SplitMix64 {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        utils::fill_bytes_via_next_word(dest, || self.next_u64());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_valid(key: u64) -> bool {
+        let digits: [u64; 16] = core::array::from_fn(|i| (key >> (60 - 4 * i)) & 0xf);
+        let unique = |half: &[u64]| {
+            half.iter()
+                .enumerate()
+                .all(|(i, d)| *d != 0 && !half[..i].contains(d))
+        };
+        unique(&digits[..8]) && unique(&digits[8..]) && digits[7] != digits[8] && key % 2 == 1
+    }
+
+    #[test]
+    fn valid_keys() {
+        let mut rng = SplitMix64(0);
+        for _ in 0..1000 {
+            assert!(is_valid(generate_key(&mut rng)));
+        }
+        assert!(is_valid(0x9d1a3b7c5e2f4861));
+        assert!(!is_valid(0x548c9decbce65297));
+    }
+}
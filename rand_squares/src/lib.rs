@@ -0,0 +1,59 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! This crate implements Widynski's [Squares] counter-based random number
+//! generators.
+//!
+//! The output of Squares is a function of a 64-bit counter and a 64-bit key,
+//! computed with a few rounds of squaring and swapping halves. It needs only
+//! 128 bits of state, which makes it suited for random access in
+//! constrained environments, such as generating the numbers for the `n`-th
+//! item of a grid or the `n`-th frame of an animation directly.
+//!
+//! This crate provides:
+//! - [`Squares32Rng`]: 32-bit output, four rounds.
+//! - [`Squares64Rng`]: 64-bit output, five rounds.
+//!
+//! The output functions [`squares32`] and [`squares64`] are also available
+//! directly. Not every key gives good output; use [`generate_key`] to create
+//! one.
+//!
+//! ```
+//! use rand_core::{RngCore, SeedableRng};
+//! use rand_squares::{Squares32Rng, Squares64Rng, generate_key};
+//!
+//! // Any generator can provide the randomness for a key.
+//! let key = generate_key(&mut Squares64Rng::seed_from_u64(1));
+//! let mut rng = Squares32Rng::new(key, 0);
+//! let x = rng.next_u32();
+//!
+//! // Jump to output 1000.
+//! rng.set_position(1000);
+//! let y = rng.next_u32();
+//! ```
+//!
+//! [Squares]: https://arxiv.org/abs/2004.06278
+
+#![doc(
+    html_logo_url = "https://www.rust-lang.org/logos/rust-logo-128x128-blk.png",
+    html_favicon_url = "https://www.rust-lang.org/favicon.ico"
+)]
+#![forbid(unsafe_code)]
+#![deny(missing_docs)]
+#![deny(missing_debug_implementations)]
+#![doc(test(attr(allow(unused_variables), deny(warnings))))]
+#![no_std]
+
+mod key;
+mod squares32;
+mod squares64;
+
+pub use key::generate_key;
+pub use rand_core;
+pub use squares32::{Squares32Rng, squares32};
+pub use squares64::{Squares64Rng, squares64};
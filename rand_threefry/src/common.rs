@@ -0,0 +1,38 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/// Parity constant of the Threefish key schedule, for 64-bit words.
+pub(crate) const PARITY64: u64 = 0x1BD1_1BDA_A9FC_1A22;
+
+/// Parity constant of the Threefish key schedule, for 32-bit words.
+pub(crate) const PARITY32: u32 = 0x1BD1_1BDA;
+
+/// Add 1 to a counter with the least significant word first.
+#[inline]
+pub(crate) fn increment<const N: usize>(mut counter: [u64; N]) -> [u64; N] {
+    for word in counter.iter_mut() {
+        *word = word.wrapping_add(1);
+        if *word != 0 {
+            break;
+        }
+    }
+    counter
+}
+
+/// Subtract 1 from a counter with the least significant word first.
+#[inline]
+pub(crate) fn decrement<const N: usize>(mut counter: [u64; N]) -> [u64; N] {
+    for word in counter.iter_mut() {
+        let old = *word;
+        *word = old.wrapping_sub(1);
+        if old != 0 {
+            break;
+        }
+    }
+    counter
+}
@@ -0,0 +1,192 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Keys and random bits of [`jax.random`] with its default `threefry2x32`
+//! implementation.
+//!
+//! A JAX key is a pair of `u32` words, and everything JAX generates from a
+//! key is derived from [`threefry2x32`] with 20 rounds. The functions here
+//! reproduce the results of the corresponding `jax.random` functions, so that
+//! random numbers drawn in JAX can be regenerated without it:
+//!
+//! ```
+//! use rand_threefry::jax;
+//!
+//! let key = jax::key(0);
+//! let mut keys = [[0; 2]; 2];
+//! jax::split(key, &mut keys);
+//! assert_eq!(keys, [[4146024105, 967050713], [2718843009, 1272950319]]);
+//! ```
+//!
+//! JAX changed how keys are split and bits are generated with the
+//! `jax_threefry_partitionable` option, which is enabled by default since JAX
+//! 0.5.0. [`split`] and [`random_bits`] implement the behaviour without it,
+//! [`split_partitionable`] and [`random_bits_partitionable`] the behaviour with
+//! it. [`key`] and [`fold_in`] are not affected by the option.
+//!
+//! [`jax.random`]: https://docs.jax.dev/en/latest/jax.random.html
+//! [`threefry2x32`]: crate::threefry2x32
+
+use crate::threefry2x32;
+
+// JAX uses Threefry2x32 with 20 rounds.
+#[inline]
+fn hash(key: [u32; 2], counter: [u32; 2]) -> [u32; 2] {
+    threefry2x32::<20>(key, counter)
+}
+
+// Compute `threefry_2x32(key, iota(n))` as JAX does without the partitionable
+// option: the first and second half of the counts form the two words of the
+// counters, and the results are the first words followed by the second words.
+// An odd number of counts is padded with a 0. Calls `f` with each index and
+// result.
+fn hash_iota(key: [u32; 2], n: usize, mut f: impl FnMut(usize, u32)) {
+    assert!(n < u32::MAX as usize, "too many values");
+    let half = n.div_ceil(2);
+    for i in 0..half {
+        let j = half + i;
+        let y = hash(key, [i as u32, if j < n { j as u32 } else { 0 }]);
+        f(i, y[0]);
+        if j < n {
+            f(j, y[1]);
+        }
+    }
+}
+
+// The counter for index `i` with the partitionable option: the high and low
+// word of the index.
+#[inline]
+fn counter(i: usize) -> [u32; 2] {
+    let i = i as u64;
+    [(i >> 32) as u32, i as u32]
+}
+
+/// Create a key from a seed, like `jax.random.key(seed)` and
+/// `jax.random.PRNGKey(seed)`.
+///
+/// Without `jax_enable_x64`, JAX only accepts seeds that fit in 32 bits.
+pub fn key(seed: u64) -> [u32; 2] {
+    [(seed >> 32) as u32, seed as u32]
+}
+
+/// Split `key` into `keys.len()` new keys, like `jax.random.split(key, num)`
+/// without the `jax_threefry_partitionable` option.
+///
+/// The new keys depend on how many keys are requested.
+///
+/// # Panics
+///
+/// If `keys.len()` is `2^31 - 1` or larger.
+pub fn split(key: [u32; 2], keys: &mut [[u32; 2]]) {
+    hash_iota(key, 2 * keys.len(), |i, y| keys[i / 2][i % 2] = y);
+}
+
+/// Split `key` into `keys.len()` new keys, like `jax.random.split(key, num)`
+/// with the `jax_threefry_partitionable` option.
+///
+/// Each new key only depends on its index, so this gives the same keys as
+/// splitting into more keys and taking the first `keys.len()`.
+pub fn split_partitionable(key: [u32; 2], keys: &mut [[u32; 2]]) {
+    for (i, k) in keys.iter_mut().enumerate() {
+        *k = hash(key, counter(i));
+    }
+}
+
+/// Derive a new key from `key` and `data`, like `jax.random.fold_in`.
+pub fn fold_in(key: [u32; 2], data: u32) -> [u32; 2] {
+    hash(key, [0, data])
+}
+
+/// Fill `dest` with random bits, like `jax.random.bits(key, shape, jnp.uint32)`
+/// without the `jax_threefry_partitionable` option. Multi-dimensional shapes
+/// are filled in row-major order.
+///
+/// The values depend on the length of `dest`.
+///
+/// # Panics
+///
+/// If `dest.len()` is `2^32 - 1` or larger.
+pub fn random_bits(key: [u32; 2], dest: &mut [u32]) {
+    hash_iota(key, dest.len(), |i, y| dest[i] = y);
+}
+
+/// Fill `dest` with random bits, like `jax.random.bits(key, shape, jnp.uint32)`
+/// with the `jax_threefry_partitionable` option. Multi-dimensional shapes are
+/// filled in row-major order.
+///
+/// Each value only depends on its index, so a longer `dest` extends the
+/// sequence.
+pub fn random_bits_partitionable(key: [u32; 2], dest: &mut [u32]) {
+    for (i, x) in dest.iter_mut().enumerate() {
+        let y = hash(key, counter(i));
+        *x = y[0] ^ y[1];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference() {
+        // This is the test of `threefry_2x32` in JAX:
+        // https://github.com/jax-ml/jax/blob/main/tests/random_test.py
+        assert_eq!(
+            hash([0x13198a2e, 0x03707344], [0x243f6a88, 0x85a308d3]),
+            [0xc4923a9c, 0x483df7a0]
+        );
+
+        // `jax.random.split(jax.random.PRNGKey(0))`
+        let mut keys = [[0; 2]; 2];
+        split(key(0), &mut keys);
+        assert_eq!(keys, [[4146024105, 967050713], [2718843009, 1272950319]]);
+    }
+
+    #[test]
+    fn iota() {
+        let k = key(42);
+        assert_eq!(k, [0, 42]);
+
+        // Even and odd lengths.
+        let mut bits = [0; 4];
+        random_bits(k, &mut bits);
+        let (a, b) = (hash(k, [0, 2]), hash(k, [1, 3]));
+        assert_eq!(bits, [a[0], b[0], a[1], b[1]]);
+
+        let mut bits = [0; 3];
+        random_bits(k, &mut bits);
+        let (a, b) = (hash(k, [0, 2]), hash(k, [1, 0]));
+        assert_eq!(bits, [a[0], b[0], a[1]]);
+
+        // Splitting is the same as generating bits in pairs.
+        let mut keys = [[0; 2]; 3];
+        split(k, &mut keys);
+        let mut bits = [0; 6];
+        random_bits(k, &mut bits);
+        assert_eq!(
+            keys,
+            [[bits[0], bits[1]], [bits[2], bits[3]], [bits[4], bits[5]]]
+        );
+    }
+
+    #[test]
+    fn partitionable() {
+        let k = key(7);
+        let mut keys = [[0; 2]; 5];
+        split_partitionable(k, &mut keys);
+        let mut first = [[0; 2]; 2];
+        split_partitionable(k, &mut first);
+        assert_eq!(first, keys[..2]);
+        assert_eq!(keys[3], hash(k, [0, 3]));
+
+        let mut bits = [0; 5];
+        random_bits_partitionable(k, &mut bits);
+        assert_eq!(bits[4], keys[4][0] ^ keys[4][1]);
+        assert_eq!(fold_in(k, 4), keys[4]);
+    }
+}
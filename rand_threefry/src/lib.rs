@@ -0,0 +1,61 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! This crate implements the Threefry family of counter-based random number
+//! generators from [Random123].
+//!
+//! Threefry is derived from the Threefish block cipher. Like all
+//! counter-based generators, its output is a pure function of a key and a
+//! counter. Any position in the stream can be computed directly, which allows
+//! skipping ahead in constant time and splitting work between threads or
+//! devices without coordination.
+//!
+//! This crate provides:
+//! - [`Threefry2x64Rng`]: 128-bit key and 128-bit counter.
+//! - [`Threefry4x64Rng`]: 256-bit key and 256-bit counter.
+//! - The block functions [`threefry2x64`], [`threefry4x64`] and
+//!   [`threefry2x32`].
+//! - The [`jax`] module, to derive keys and random bits like `jax.random`.
+//!
+//! The number of rounds is a const parameter; Random123 uses 20 by default,
+//! and 13 rounds is a faster alternative which also passes BigCrush.
+//!
+//! ```
+//! use rand_core::RngCore;
+//! use rand_threefry::Threefry2x64Rng;
+//!
+//! let mut rng = Threefry2x64Rng::<13>::new([1, 2], [0, 0]);
+//! let x = rng.next_u64();
+//!
+//! // Jump to block 1000, that is to output 2000.
+//! rng.set_counter([1000, 0]);
+//! let y = rng.next_u64();
+//! ```
+//!
+//! [Random123]: https://github.com/DEShawResearch/random123
+
+#![doc(
+    html_logo_url = "https://www.rust-lang.org/logos/rust-logo-128x128-blk.png",
+    html_favicon_url = "https://www.rust-lang.org/favicon.ico"
+)]
+#![forbid(unsafe_code)]
+#![deny(missing_docs)]
+#![deny(missing_debug_implementations)]
+#![doc(test(attr(allow(unused_variables), deny(warnings))))]
+#![no_std]
+
+mod common;
+pub mod jax;
+mod threefry2x32;
+mod threefry2x64;
+mod threefry4x64;
+
+pub use rand_core;
+pub use threefry2x32::threefry2x32;
+pub use threefry2x64::{Threefry2x64Core, Threefry2x64Rng, threefry2x64};
+pub use threefry4x64::{Threefry4x64Core, Threefry4x64Rng, threefry4x64};
@@ -0,0 +1,58 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::common::PARITY32;
+
+// Rotation constants, from Random123.
+const ROTATIONS: [u32; 8] = [13, 15, 26, 6, 17, 29, 16, 24];
+
+/// The Threefry2x32 function with `ROUNDS` rounds: encrypt `counter` with
+/// `key`.
+///
+/// With 20 rounds this is the function used by JAX, see the [`jax`] module.
+///
+/// [`jax`]: crate::jax
+pub fn threefry2x32<const ROUNDS: usize>(key: [u32; 2], counter: [u32; 2]) -> [u32; 2] {
+    let ks = [key[0], key[1], PARITY32 ^ key[0] ^ key[1]];
+    let mut x = [
+        counter[0].wrapping_add(ks[0]),
+        counter[1].wrapping_add(ks[1]),
+    ];
+    for r in 0..ROUNDS {
+        x[0] = x[0].wrapping_add(x[1]);
+        x[1] = x[1].rotate_left(ROTATIONS[r % 8]) ^ x[0];
+
+        // Inject the key after every four rounds.
+        if r % 4 == 3 {
+            let s = (r + 1) / 4;
+            x[0] = x[0].wrapping_add(ks[s % 3]);
+            x[1] = x[1].wrapping_add(ks[(s + 1) % 3]).wrapping_add(s as u32);
+        }
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference() {
+        // These values are the known-answer tests of Random123:
+        // https://github.com/DEShawResearch/random123/blob/main/tests/kat_vectors
+        assert_eq!(threefry2x32::<20>([0, 0], [0, 0]), [0x6b200159, 0x99ba4efe]);
+        assert_eq!(
+            threefry2x32::<20>([0xffffffff; 2], [0xffffffff; 2]),
+            [0x1cb996fc, 0xbb002be7]
+        );
+        assert_eq!(
+            threefry2x32::<20>([0x13198a2e, 0x03707344], [0x243f6a88, 0x85a308d3]),
+            [0xc4923a9c, 0x483df7a0]
+        );
+    }
+}
@@ -0,0 +1,335 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::common::{PARITY64, decrement, increment};
+use rand_core::block::{BlockRng, Generator};
+use rand_core::{RngCore, SeedableRng, TryRngCore, utils};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// Rotation constants, from Random123.
+const ROTATIONS: [u32; 8] = [16, 42, 12, 31, 16, 32, 24, 21];
+
+/// The Threefry2x64 function with `ROUNDS` rounds: encrypt `counter` with
+/// `key`.
+///
+/// This is the block function of [`Threefry2x64Rng`], which returns the words
+/// of the result in order, and then continues with `counter + 1`. Word 0 of
+/// `counter` is the least significant.
+///
+/// Random123 uses 20 rounds by default; 13 is the smallest number of rounds
+/// that passes BigCrush.
+pub fn threefry2x64<const ROUNDS: usize>(key: [u64; 2], counter: [u64; 2]) -> [u64; 2] {
+    let ks = [key[0], key[1], PARITY64 ^ key[0] ^ key[1]];
+    let mut x = [
+        counter[0].wrapping_add(ks[0]),
+        counter[1].wrapping_add(ks[1]),
+    ];
+    for r in 0..ROUNDS {
+        x[0] = x[0].wrapping_add(x[1]);
+        x[1] = x[1].rotate_left(ROTATIONS[r % 8]) ^ x[0];
+
+        // Inject the key after every four rounds.
+        if r % 4 == 3 {
+            let s = (r + 1) / 4;
+            x[0] = x[0].wrapping_add(ks[s % 3]);
+            x[1] = x[1].wrapping_add(ks[(s + 1) % 3]).wrapping_add(s as u64);
+        }
+    }
+    x
+}
+
+/// A Threefry2x64 random number generator.
+///
+/// Threefry is a counter-based generator by Salmon et al.[^1], derived from
+/// the Threefish block cipher: the output is a pure function of a 128-bit key
+/// and a 128-bit counter, see [`threefry2x64`]. Each value of the counter
+/// gives a block of two `u64` outputs. This makes it possible to jump to any
+/// position in the stream in constant time with [`set_counter`], and to
+/// reproduce the numbers generated by other implementations of Random123's
+/// Threefry2x64 when given the same key, counter and number of rounds.
+///
+/// The number of rounds is `ROUNDS`, 20 by default. `Threefry2x64Rng<13>` is
+/// faster, and still passes BigCrush. Threefry is not cryptographically
+/// secure.
+///
+/// `next_u32` uses the low half of a `u64` output and discards the rest.
+///
+/// This implementation uses [`BlockRng`] to implement the [`RngCore`]
+/// methods. When seeded with [`SeedableRng`], the seed is the key and the
+/// counter starts at 0.
+///
+/// [^1]: John K. Salmon, Mark A. Moraes, Ron O. Dror and David E. Shaw (2011).
+///       ["Parallel Random Numbers: As Easy as 1, 2, 3"](
+///       https://www.thesalmons.org/john/random123/papers/random123sc11.pdf).
+///       *Proceedings of the International Conference for High Performance
+///       Computing, Networking, Storage and Analysis (SC11)*.
+///
+/// [`set_counter`]: Threefry2x64Rng::set_counter
+/// [`BlockRng`]: rand_core::block::BlockRng
+#[derive(Debug, Clone)]
+pub struct Threefry2x64Rng<const ROUNDS: usize = 20>(BlockRng<Threefry2x64Core<ROUNDS>>);
+
+impl<const ROUNDS: usize> Threefry2x64Rng<ROUNDS> {
+    /// Create a generator with the given `key`, starting at the first output
+    /// of the block for `counter`.
+    pub fn new(key: [u64; 2], counter: [u64; 2]) -> Self {
+        Threefry2x64Rng(BlockRng::new(Threefry2x64Core { key, counter }))
+    }
+
+    /// Get the key.
+    pub fn key(&self) -> [u64; 2] {
+        self.0.core.key
+    }
+
+    /// Get the counter of the block the next output is taken from.
+    ///
+    /// If some outputs of this block were already used, they are generated
+    /// again after passing the result to [`set_counter`].
+    ///
+    /// [`set_counter`]: Threefry2x64Rng::set_counter
+    pub fn get_counter(&self) -> [u64; 2] {
+        if self.0.index() < 2 {
+            decrement(self.0.core.counter)
+        } else {
+            self.0.core.counter
+        }
+    }
+
+    /// Continue at the first output of the block for `counter`.
+    pub fn set_counter(&mut self, counter: [u64; 2]) {
+        self.0.core.counter = counter;
+        self.0.reset();
+    }
+}
+
+impl<const ROUNDS: usize> RngCore for Threefry2x64Rng<ROUNDS> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_word() as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_word()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+}
+
+impl<const ROUNDS: usize> SeedableRng for Threefry2x64Rng<ROUNDS> {
+    type Seed = <Threefry2x64Core<ROUNDS> as SeedableRng>::Seed;
+
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Self {
+        Threefry2x64Rng(BlockRng::new(Threefry2x64Core::from_seed(seed)))
+    }
+
+    #[inline]
+    fn from_rng<R>(rng: &mut R) -> Self
+    where
+        R: RngCore + ?Sized,
+    {
+        Threefry2x64Rng(BlockRng::new(Threefry2x64Core::from_rng(rng)))
+    }
+
+    #[inline]
+    fn try_from_rng<R>(rng: &mut R) -> Result<Self, R::Error>
+    where
+        R: TryRngCore + ?Sized,
+    {
+        Threefry2x64Core::try_from_rng(rng).map(|core| Threefry2x64Rng(BlockRng::new(core)))
+    }
+}
+
+impl<const ROUNDS: usize> PartialEq for Threefry2x64Rng<ROUNDS> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.0.core == rhs.0.core && self.0.index() == rhs.0.index()
+    }
+}
+impl<const ROUNDS: usize> Eq for Threefry2x64Rng<ROUNDS> {}
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use super::Threefry2x64Rng;
+    use serde::de::{Deserialize, Deserializer, Error};
+    use serde::ser::{Serialize, Serializer};
+
+    // The position in the stream: the block of the next output, and how many
+    // of its words were already used.
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(rename = "Threefry2x64Rng")]
+    struct State {
+        key: [u64; 2],
+        counter: [u64; 2],
+        index: u8,
+    }
+
+    impl<const ROUNDS: usize> Serialize for Threefry2x64Rng<ROUNDS> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let index = self.0.index();
+            State {
+                key: self.key(),
+                counter: self.get_counter(),
+                index: if index < 2 { index as u8 } else { 0 },
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de, const ROUNDS: usize> Deserialize<'de> for Threefry2x64Rng<ROUNDS> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let state = State::deserialize(deserializer)?;
+            if state.index >= 2 {
+                return Err(D::Error::custom("index out of range"));
+            }
+            let mut rng = Threefry2x64Rng::new(state.key, state.counter);
+            rng.0.generate_and_set(state.index.into());
+            Ok(rng)
+        }
+    }
+}
+
+/// The core of `Threefry2x64Rng`, used with `BlockRng`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Threefry2x64Core<const ROUNDS: usize = 20> {
+    key: [u64; 2],
+    counter: [u64; 2],
+}
+
+impl<const ROUNDS: usize> Generator for Threefry2x64Core<ROUNDS> {
+    type Output = [u64; 2];
+
+    #[inline]
+    fn generate(&mut self, results: &mut Self::Output) {
+        *results = threefry2x64::<ROUNDS>(self.key, self.counter);
+        self.counter = increment(self.counter);
+    }
+}
+
+impl<const ROUNDS: usize> SeedableRng for Threefry2x64Core<ROUNDS> {
+    type Seed = [u8; 16];
+
+    /// Create a new `Threefry2x64Core` with `seed` as key, and the counter at
+    /// 0.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Threefry2x64Core {
+            key: utils::read_words(&seed),
+            counter: [0; 2],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference() {
+        // These values are the known-answer tests of Random123:
+        // https://github.com/DEShawResearch/random123/blob/main/tests/kat_vectors
+        let max = u64::MAX;
+        let pi_key = [0xa4093822299f31d0, 0x082efa98ec4e6c89];
+        let pi_ctr = [0x243f6a8885a308d3, 0x13198a2e03707344];
+
+        assert_eq!(
+            threefry2x64::<13>([0, 0], [0, 0]),
+            [0xf167b032c3b480bd, 0xe91f9fee4b7a6fb5]
+        );
+        assert_eq!(
+            threefry2x64::<13>([max, max], [max, max]),
+            [0xccdec5c917a874b1, 0x4df53abca26ceb01]
+        );
+        assert_eq!(
+            threefry2x64::<13>(pi_key, pi_ctr),
+            [0xc3aac71561042993, 0x3fe7ae8801aff316]
+        );
+
+        assert_eq!(
+            threefry2x64::<20>([0, 0], [0, 0]),
+            [0xc2b6e3a8c2c69865, 0x6f81ed42f350084d]
+        );
+        assert_eq!(
+            threefry2x64::<20>([max, max], [max, max]),
+            [0xe02cb7c4d95d277a, 0xd06633d0893b8b68]
+        );
+        assert_eq!(
+            threefry2x64::<20>(pi_key, pi_ctr),
+            [0x263c7d30bb0f0af1, 0x56be8361d3311526]
+        );
+    }
+
+    #[test]
+    fn blocks() {
+        let key = [0xa4093822299f31d0, 0x082efa98ec4e6c89];
+        let max = u64::MAX;
+        let mut rng = Threefry2x64Rng::<13>::new(key, [max, 7]);
+        for counter in [[max, 7], [0, 8], [1, 8]] {
+            assert_eq!(rng.get_counter(), counter);
+            let block = threefry2x64::<13>(key, counter);
+            assert_eq!(rng.next_u64(), block[0]);
+            assert_eq!(rng.get_counter(), counter);
+            assert_eq!(rng.next_u32(), block[1] as u32);
+        }
+
+        // The counter wraps around.
+        let mut rng = Threefry2x64Rng::<20>::new(key, [max; 2]);
+        rng.next_u64();
+        rng.next_u64();
+        assert_eq!(rng.get_counter(), [0, 0]);
+        assert_eq!(rng.next_u64(), threefry2x64::<20>(key, [0, 0])[0]);
+    }
+
+    #[test]
+    fn set_counter() {
+        let mut rng = Threefry2x64Rng::<20>::seed_from_u64(0);
+        let mut a = [0u64; 20];
+        for x in a.iter_mut() {
+            *x = rng.next_u64();
+        }
+
+        rng.set_counter([5, 0]);
+        assert_eq!(rng.next_u64(), a[10]);
+        let counter = rng.get_counter();
+        assert_eq!(counter, [5, 0]);
+        rng.set_counter([2, 0]);
+        assert_eq!(rng.next_u64(), a[4]);
+        rng.set_counter(counter);
+        assert_eq!(rng.next_u64(), a[10]);
+    }
+
+    #[test]
+    fn seed() {
+        let rng =
+            Threefry2x64Rng::<20>::from_seed([1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(rng, Threefry2x64Rng::new([1, 2], [0; 2]));
+        assert_eq!(rng.key(), [1, 2]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let mut rng = Threefry2x64Rng::<13>::seed_from_u64(42);
+        for skip in 0..4 {
+            let buf = postcard::to_allocvec(&rng).expect("Could not serialize");
+            let mut deserialized: Threefry2x64Rng<13> =
+                postcard::from_bytes(&buf).expect("Could not deserialize");
+            assert_eq!(rng, deserialized);
+            for _ in 0..10 {
+                assert_eq!(rng.next_u64(), deserialized.next_u64());
+            }
+            for _ in 0..skip {
+                rng.next_u64();
+            }
+        }
+    }
+}
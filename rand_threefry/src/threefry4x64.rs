@@ -0,0 +1,373 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::common::{PARITY64, decrement, increment};
+use rand_core::block::{BlockRng, Generator};
+use rand_core::{RngCore, SeedableRng, TryRngCore, utils};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// Rotation constants, from Random123.
+const ROTATIONS: [[u32; 2]; 8] = [
+    [14, 16],
+    [52, 57],
+    [23, 40],
+    [5, 37],
+    [25, 33],
+    [46, 12],
+    [58, 22],
+    [32, 32],
+];
+
+/// The Threefry4x64 function with `ROUNDS` rounds: encrypt `counter` with
+/// `key`.
+///
+/// This is the block function of [`Threefry4x64Rng`], which returns the words
+/// of the result in order, and then continues with `counter + 1`. Word 0 of
+/// `counter` is the least significant.
+///
+/// Random123 uses 20 rounds by default; 13 rounds also pass BigCrush.
+pub fn threefry4x64<const ROUNDS: usize>(key: [u64; 4], counter: [u64; 4]) -> [u64; 4] {
+    let ks = [
+        key[0],
+        key[1],
+        key[2],
+        key[3],
+        PARITY64 ^ key[0] ^ key[1] ^ key[2] ^ key[3],
+    ];
+    let mut x = [0; 4];
+    for i in 0..4 {
+        x[i] = counter[i].wrapping_add(ks[i]);
+    }
+    for r in 0..ROUNDS {
+        let [r0, r1] = ROTATIONS[r % 8];
+        // Even rounds mix words (0, 1) and (2, 3), odd rounds (0, 3) and
+        // (2, 1).
+        let (a, b) = if r % 2 == 0 { (1, 3) } else { (3, 1) };
+        x[0] = x[0].wrapping_add(x[a]);
+        x[a] = x[a].rotate_left(r0) ^ x[0];
+        x[2] = x[2].wrapping_add(x[b]);
+        x[b] = x[b].rotate_left(r1) ^ x[2];
+
+        // Inject the key after every four rounds.
+        if r % 4 == 3 {
+            let s = (r + 1) / 4;
+            for i in 0..4 {
+                x[i] = x[i].wrapping_add(ks[(s + i) % 5]);
+            }
+            x[3] = x[3].wrapping_add(s as u64);
+        }
+    }
+    x
+}
+
+/// A Threefry4x64 random number generator.
+///
+/// Threefry is a counter-based generator by Salmon et al.[^1], derived from
+/// the Threefish block cipher: the output is a pure function of a 256-bit key
+/// and a 256-bit counter, see [`threefry4x64`]. Each value of the counter
+/// gives a block of four `u64` outputs. This makes it possible to jump to any
+/// position in the stream in constant time with [`set_counter`], and to
+/// reproduce the numbers generated by other implementations of Random123's
+/// Threefry4x64 when given the same key, counter and number of rounds.
+///
+/// The number of rounds is `ROUNDS`, 20 by default. `Threefry4x64Rng<13>` is
+/// faster, and still passes BigCrush. Threefry is not cryptographically
+/// secure.
+///
+/// `next_u32` uses the low half of a `u64` output and discards the rest.
+///
+/// This implementation uses [`BlockRng`] to implement the [`RngCore`]
+/// methods. When seeded with [`SeedableRng`], the seed is the key and the
+/// counter starts at 0.
+///
+/// [^1]: John K. Salmon, Mark A. Moraes, Ron O. Dror and David E. Shaw (2011).
+///       ["Parallel Random Numbers: As Easy as 1, 2, 3"](
+///       https://www.thesalmons.org/john/random123/papers/random123sc11.pdf).
+///       *Proceedings of the International Conference for High Performance
+///       Computing, Networking, Storage and Analysis (SC11)*.
+///
+/// [`set_counter`]: Threefry4x64Rng::set_counter
+/// [`BlockRng`]: rand_core::block::BlockRng
+#[derive(Debug, Clone)]
+pub struct Threefry4x64Rng<const ROUNDS: usize = 20>(BlockRng<Threefry4x64Core<ROUNDS>>);
+
+impl<const ROUNDS: usize> Threefry4x64Rng<ROUNDS> {
+    /// Create a generator with the given `key`, starting at the first output
+    /// of the block for `counter`.
+    pub fn new(key: [u64; 4], counter: [u64; 4]) -> Self {
+        Threefry4x64Rng(BlockRng::new(Threefry4x64Core { key, counter }))
+    }
+
+    /// Get the key.
+    pub fn key(&self) -> [u64; 4] {
+        self.0.core.key
+    }
+
+    /// Get the counter of the block the next output is taken from.
+    ///
+    /// If some outputs of this block were already used, they are generated
+    /// again after passing the result to [`set_counter`].
+    ///
+    /// [`set_counter`]: Threefry4x64Rng::set_counter
+    pub fn get_counter(&self) -> [u64; 4] {
+        if self.0.index() < 4 {
+            decrement(self.0.core.counter)
+        } else {
+            self.0.core.counter
+        }
+    }
+
+    /// Continue at the first output of the block for `counter`.
+    pub fn set_counter(&mut self, counter: [u64; 4]) {
+        self.0.core.counter = counter;
+        self.0.reset();
+    }
+}
+
+impl<const ROUNDS: usize> RngCore for Threefry4x64Rng<ROUNDS> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_word() as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_word()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+}
+
+impl<const ROUNDS: usize> SeedableRng for Threefry4x64Rng<ROUNDS> {
+    type Seed = <Threefry4x64Core<ROUNDS> as SeedableRng>::Seed;
+
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Self {
+        Threefry4x64Rng(BlockRng::new(Threefry4x64Core::from_seed(seed)))
+    }
+
+    #[inline]
+    fn from_rng<R>(rng: &mut R) -> Self
+    where
+        R: RngCore + ?Sized,
+    {
+        Threefry4x64Rng(BlockRng::new(Threefry4x64Core::from_rng(rng)))
+    }
+
+    #[inline]
+    fn try_from_rng<R>(rng: &mut R) -> Result<Self, R::Error>
+    where
+        R: TryRngCore + ?Sized,
+    {
+        Threefry4x64Core::try_from_rng(rng).map(|core| Threefry4x64Rng(BlockRng::new(core)))
+    }
+}
+
+impl<const ROUNDS: usize> PartialEq for Threefry4x64Rng<ROUNDS> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.0.core == rhs.0.core && self.0.index() == rhs.0.index()
+    }
+}
+impl<const ROUNDS: usize> Eq for Threefry4x64Rng<ROUNDS> {}
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use super::Threefry4x64Rng;
+    use serde::de::{Deserialize, Deserializer, Error};
+    use serde::ser::{Serialize, Serializer};
+
+    // The position in the stream: the block of the next output, and how many
+    // of its words were already used.
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(rename = "Threefry4x64Rng")]
+    struct State {
+        key: [u64; 4],
+        counter: [u64; 4],
+        index: u8,
+    }
+
+    impl<const ROUNDS: usize> Serialize for Threefry4x64Rng<ROUNDS> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let index = self.0.index();
+            State {
+                key: self.key(),
+                counter: self.get_counter(),
+                index: if index < 4 { index as u8 } else { 0 },
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de, const ROUNDS: usize> Deserialize<'de> for Threefry4x64Rng<ROUNDS> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let state = State::deserialize(deserializer)?;
+            if state.index >= 4 {
+                return Err(D::Error::custom("index out of range"));
+            }
+            let mut rng = Threefry4x64Rng::new(state.key, state.counter);
+            rng.0.generate_and_set(state.index.into());
+            Ok(rng)
+        }
+    }
+}
+
+/// The core of `Threefry4x64Rng`, used with `BlockRng`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Threefry4x64Core<const ROUNDS: usize = 20> {
+    key: [u64; 4],
+    counter: [u64; 4],
+}
+
+impl<const ROUNDS: usize> Generator for Threefry4x64Core<ROUNDS> {
+    type Output = [u64; 4];
+
+    #[inline]
+    fn generate(&mut self, results: &mut Self::Output) {
+        *results = threefry4x64::<ROUNDS>(self.key, self.counter);
+        self.counter = increment(self.counter);
+    }
+}
+
+impl<const ROUNDS: usize> SeedableRng for Threefry4x64Core<ROUNDS> {
+    type Seed = [u8; 32];
+
+    /// Create a new `Threefry4x64Core` with `seed` as key, and the counter at
+    /// 0.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Threefry4x64Core {
+            key: utils::read_words(&seed),
+            counter: [0; 4],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference() {
+        // These values are the known-answer tests of Random123:
+        // https://github.com/DEShawResearch/random123/blob/main/tests/kat_vectors
+        let max = u64::MAX;
+
+        assert_eq!(
+            threefry4x64::<13>([0; 4], [0; 4]),
+            [
+                0x4071fabee1dc8e05,
+                0x02ed3113695c9c62,
+                0x397311b5b89f9d49,
+                0xe21292c3258024bc
+            ]
+        );
+        assert_eq!(
+            threefry4x64::<13>([max; 4], [max; 4]),
+            [
+                0x7eaed935479722b5,
+                0x90994358c429f31c,
+                0x496381083e07a75b,
+                0x627ed0d746821121
+            ]
+        );
+
+        assert_eq!(
+            threefry4x64::<20>([0; 4], [0; 4]),
+            [
+                0x09218ebde6c85537,
+                0x55941f5266d86105,
+                0x4bd25e16282434dc,
+                0xee29ec846bd2e40b
+            ]
+        );
+        assert_eq!(
+            threefry4x64::<20>([max; 4], [max; 4]),
+            [
+                0x29c24097942bba1b,
+                0x0371bbfb0f6f4e11,
+                0x3c231ffa33f83a1c,
+                0xcd29113fde32d168
+            ]
+        );
+    }
+
+    #[test]
+    fn blocks() {
+        let key = [1, 2, 3, 4];
+        let max = u64::MAX;
+        let mut rng = Threefry4x64Rng::<13>::new(key, [max, 7, 0, 0]);
+        for counter in [[max, 7, 0, 0], [0, 8, 0, 0], [1, 8, 0, 0]] {
+            assert_eq!(rng.get_counter(), counter);
+            let block = threefry4x64::<13>(key, counter);
+            assert_eq!(rng.next_u64(), block[0]);
+            assert_eq!(rng.get_counter(), counter);
+            assert_eq!(rng.next_u32(), block[1] as u32);
+            assert_eq!(rng.next_u64(), block[2]);
+            assert_eq!(rng.next_u64(), block[3]);
+        }
+
+        // The counter wraps around.
+        let mut rng = Threefry4x64Rng::<20>::new(key, [max; 4]);
+        for _ in 0..4 {
+            rng.next_u64();
+        }
+        assert_eq!(rng.get_counter(), [0; 4]);
+        assert_eq!(rng.next_u64(), threefry4x64::<20>(key, [0; 4])[0]);
+    }
+
+    #[test]
+    fn set_counter() {
+        let mut rng = Threefry4x64Rng::<20>::seed_from_u64(0);
+        let mut a = [0u64; 40];
+        for x in a.iter_mut() {
+            *x = rng.next_u64();
+        }
+
+        rng.set_counter([5, 0, 0, 0]);
+        assert_eq!(rng.next_u64(), a[20]);
+        let counter = rng.get_counter();
+        assert_eq!(counter, [5, 0, 0, 0]);
+        rng.set_counter([2, 0, 0, 0]);
+        assert_eq!(rng.next_u64(), a[8]);
+        rng.set_counter(counter);
+        assert_eq!(rng.next_u64(), a[20]);
+    }
+
+    #[test]
+    fn seed() {
+        let mut seed = [0u8; 32];
+        for i in 0..4 {
+            seed[8 * i] = i as u8 + 1;
+        }
+        let rng = Threefry4x64Rng::<20>::from_seed(seed);
+        assert_eq!(rng, Threefry4x64Rng::new([1, 2, 3, 4], [0; 4]));
+        assert_eq!(rng.key(), [1, 2, 3, 4]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let mut rng = Threefry4x64Rng::<20>::seed_from_u64(42);
+        for skip in 0..6 {
+            let buf = postcard::to_allocvec(&rng).expect("Could not serialize");
+            let mut deserialized: Threefry4x64Rng =
+                postcard::from_bytes(&buf).expect("Could not deserialize");
+            assert_eq!(rng, deserialized);
+            for _ in 0..10 {
+                assert_eq!(rng.next_u64(), deserialized.next_u64());
+            }
+            for _ in 0..skip {
+                rng.next_u64();
+            }
+        }
+    }
+}
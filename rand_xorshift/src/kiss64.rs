@@ -0,0 +1,105 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use rand_core::{RngCore, SeedableRng, utils};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// The initial values of Marsaglia's implementation
+const X: u64 = 1234567890987654321;
+const C: u64 = 123456123456123456;
+const Y: u64 = 362436362436362436;
+
+/// Marsaglia's 64-bit KISS random number generator.
+///
+/// This version of KISS ("Keep It Simple Stupid") was posted by George
+/// Marsaglia in 2009[^1]. It adds the outputs of a multiply-with-carry
+/// generator with multiplier 2<sup>58</sup> + 1, a 3-shift xorshift generator
+/// and a linear congruential generator, each with 64 bits of state. Its
+/// period is about 2<sup>250</sup>. It is not suitable for cryptographic
+/// purposes.
+///
+/// This implementation produces exactly the numbers of the `KISS` macro of
+/// the reference implementation, with the state given to [`new`].
+/// `next_u32` returns the lower half of an output.
+///
+/// The multiply-with-carry generator gets stuck when `x` and `c` are both 0,
+/// and the xorshift generator when `y` is 0. `SeedableRng::from_seed`
+/// replaces those by Marsaglia's initial values, and only uses 58 bits of
+/// `c`, as larger values are not valid carries.
+///
+/// [^1]: George Marsaglia (February 2009). "64-bit KISS RNGs".
+///       Usenet newsgroups *sci.math* and *comp.lang.c*.
+///
+/// [`new`]: Kiss64::new
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Kiss64 {
+    x: u64,
+    c: u64,
+    y: u64,
+    z: u64,
+}
+
+impl Kiss64 {
+    /// Create a generator with the given state.
+    ///
+    /// The initial state of the reference implementation is
+    /// `Kiss64::new(1234567890987654321, 123456123456123456,
+    /// 362436362436362436, 1066149217761810)`.
+    pub fn new(x: u64, c: u64, y: u64, z: u64) -> Self {
+        Kiss64 { x, c, y, z }
+    }
+}
+
+impl RngCore for Kiss64 {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        // MWC
+        let t = (self.x << 58).wrapping_add(self.c);
+        self.c = self.x >> 6;
+        self.x = self.x.wrapping_add(t);
+        self.c += u64::from(self.x < t);
+        // XSH
+        self.y ^= self.y << 13;
+        self.y ^= self.y >> 17;
+        self.y ^= self.y << 43;
+        // CNG
+        self.z = self.z.wrapping_mul(6906969069).wrapping_add(1234567);
+        self.x.wrapping_add(self.y).wrapping_add(self.z)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        utils::fill_bytes_via_next_word(dest, || self.next_u64())
+    }
+}
+
+impl SeedableRng for Kiss64 {
+    type Seed = [u8; 32];
+
+    /// Create a `Kiss64` from a seed, read as the little-endian words `x`,
+    /// `c`, `y` and `z`.
+    fn from_seed(seed: Self::Seed) -> Self {
+        let [mut x, mut c, mut y, z]: [u64; 4] = utils::read_words(&seed);
+        c &= (1 << 58) - 1;
+        if x == 0 && c == 0 {
+            x = X;
+            c = C;
+        }
+        if y == 0 {
+            y = Y;
+        }
+        Kiss64::new(x, c, y, z)
+    }
+}
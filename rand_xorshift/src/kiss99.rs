@@ -0,0 +1,107 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use rand_core::{RngCore, SeedableRng, utils};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// The initial values of Marsaglia's implementation
+const Z: u32 = 362436069;
+const W: u32 = 521288629;
+const JSR: u32 = 123456789;
+
+/// Marsaglia's KISS random number generator, in the version of 1999.
+///
+/// KISS ("Keep It Simple Stupid") combines three simple generators: two
+/// 16-bit multiply-with-carry generators, a 3-shift xorshift generator, and a
+/// linear congruential generator. It was posted by George Marsaglia to
+/// `sci.stat.math` in January 1999[^1], and was widely copied from there. It
+/// is not suitable for cryptographic purposes.
+///
+/// This implementation produces exactly the numbers of the `KISS` macro of
+/// that post, with the state set by `settable` or [`new`]. Beware that later
+/// versions of KISS by Marsaglia use different components. `next_u64`
+/// combines two outputs, the first as the low word.
+///
+/// The reference implementation gets stuck with some values of the
+/// multiply-with-carry generators (`z` or `w` of 0, or `0x9068FFFF` and
+/// `0x464FFFFF` respectively), and the xorshift generator with `jsr` of 0.
+/// [`new`] uses them as they are, for compatibility, but
+/// `SeedableRng::from_seed` replaces them by Marsaglia's initial values.
+///
+/// [^1]: George Marsaglia (January 1999). "Random numbers for C: The END?".
+///       Usenet newsgroup *sci.stat.math*.
+///
+/// [`new`]: Kiss99::new
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Kiss99 {
+    z: u32,
+    w: u32,
+    jsr: u32,
+    jcong: u32,
+}
+
+impl Kiss99 {
+    /// Create a generator with the given state, like `settable(z, w, jsr,
+    /// jcong, ...)` of the reference implementation.
+    ///
+    /// The initial state of the reference implementation is
+    /// `Kiss99::new(362436069, 521288629, 123456789, 380116160)`.
+    pub fn new(z: u32, w: u32, jsr: u32, jcong: u32) -> Self {
+        Kiss99 { z, w, jsr, jcong }
+    }
+}
+
+impl RngCore for Kiss99 {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        // MWC
+        self.z = 36969 * (self.z & 0xFFFF) + (self.z >> 16);
+        self.w = 18000 * (self.w & 0xFFFF) + (self.w >> 16);
+        let mwc = (self.z << 16).wrapping_add(self.w);
+        // CONG
+        self.jcong = self.jcong.wrapping_mul(69069).wrapping_add(1234567);
+        // SHR3
+        self.jsr ^= self.jsr << 17;
+        self.jsr ^= self.jsr >> 13;
+        self.jsr ^= self.jsr << 5;
+        (mwc ^ self.jcong).wrapping_add(self.jsr)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        utils::next_u64_via_u32(self)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        utils::fill_bytes_via_next_word(dest, || self.next_u32())
+    }
+}
+
+impl SeedableRng for Kiss99 {
+    type Seed = [u8; 16];
+
+    /// Create a `Kiss99` from a seed, read as the little-endian words `z`,
+    /// `w`, `jsr` and `jcong`.
+    fn from_seed(seed: Self::Seed) -> Self {
+        let [mut z, mut w, mut jsr, jcong]: [u32; 4] = utils::read_words(&seed);
+        // Avoid the fixed points of the components
+        if z == 0 || z == 0x9068FFFF {
+            z = Z;
+        }
+        if w == 0 || w == 0x464FFFFF {
+            w = W;
+        }
+        if jsr == 0 {
+            jsr = JSR;
+        }
+        Kiss99::new(z, w, jsr, jcong)
+    }
+}
@@ -6,7 +6,19 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-//! The xorshift random number generator.
+//! The xorshift random number generator, and other classic generators kept
+//! for reproducing historical results.
+//!
+//! This crate provides:
+//! - [`XorShiftRng`]: Marsaglia's 128-bit xorshift generator.
+//! - [`Kiss99`] and [`Kiss64`]: Marsaglia's KISS generators of 1999 and 2009,
+//!   combining a multiply-with-carry, a xorshift and a linear congruential
+//!   generator.
+//! - [`RanArray`]: Knuth's lagged Fibonacci generator `ran_array` from *The
+//!   Art of Computer Programming*.
+//!
+//! These generators are not suitable for cryptographic purposes, and better
+//! generators are available for new code.
 //!
 //! # Example
 //!
@@ -29,6 +41,14 @@
 #![deny(missing_debug_implementations)]
 #![no_std]
 
+mod kiss64;
+mod kiss99;
+mod ran_array;
+
+pub use kiss64::Kiss64;
+pub use kiss99::Kiss99;
+pub use ran_array::RanArray;
+
 use core::fmt;
 use core::num::Wrapping as w;
 use rand_core::{RngCore, SeedableRng, TryRngCore, utils};
@@ -0,0 +1,214 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::fmt;
+use rand_core::{RngCore, SeedableRng, utils};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// The long lag
+const KK: usize = 100;
+// The short lag
+const LL: usize = 37;
+// The modulus, 2^30
+const MM: u32 = 1 << 30;
+// Guaranteed separation between streams of `ran_start`
+const TT: u32 = 70;
+// The number of values generated at once by `ran_arr_next`, of which only the
+// first `KK` are used
+const QUALITY: usize = 1009;
+
+#[inline(always)]
+fn mod_diff(x: u32, y: u32) -> u32 {
+    x.wrapping_sub(y) & (MM - 1)
+}
+
+/// Knuth's lagged Fibonacci generator `ran_array`.
+///
+/// This is the generator from section 3.6 of *The Art of Computer
+/// Programming*[^1], using the recurrence X<sub>j</sub> = (X<sub>j-100</sub> -
+/// X<sub>j-37</sub>) mod 2<sup>30</sup>. It is not suitable for cryptographic
+/// purposes, and has known statistical weaknesses when all of its output is
+/// used; Knuth recommends discarding most of it, as [`ran_arr_next`] does.
+///
+/// This implementation follows Knuth's reference `rng.c`, with the improved
+/// initialization `ran_start` of 2002[^2], and produces exactly the same
+/// numbers:
+///
+/// - [`new`] is `ran_start(seed)`.
+/// - [`ran_array`] is `ran_array(aa, n)`, which fills an array with raw
+///   outputs.
+/// - [`ran_arr_next`] is `ran_arr_next()`, which takes the first 100 of every
+///   1009 outputs.
+///
+/// The values of the reference implementation are only 30 bits. For the
+/// [`RngCore`] methods, `next_u32` combines the upper 30 bits of a value of
+/// `ran_arr_next` with the upper two bits of the next one, and `next_u64`
+/// combines two such `u32` values, the first as the low word.
+///
+/// [^1]: Donald E. Knuth (1997). *The Art of Computer Programming, Volume 2:
+///       Seminumerical Algorithms*, 3rd edition, section 3.6.
+///       Addison-Wesley.
+///
+/// [^2]: Donald E. Knuth, [`rng.c`](https://www-cs-faculty.stanford.edu/~knuth/programs/rng.c).
+///
+/// [`new`]: RanArray::new
+/// [`ran_array`]: RanArray::ran_array
+/// [`ran_arr_next`]: RanArray::ran_arr_next
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RanArray {
+    #[cfg_attr(feature = "serde", serde(with = "serde_arrays"))]
+    ran_x: [u32; KK],
+    // The values for `ran_arr_next`
+    #[cfg_attr(feature = "serde", serde(with = "serde_arrays"))]
+    buf: [u32; KK],
+    // The index of the next value in `buf`, `KK` if all are used.
+    index: usize,
+}
+
+// Custom Debug implementation that does not expose the internal state
+impl fmt::Debug for RanArray {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RanArray {{}}")
+    }
+}
+
+impl RanArray {
+    /// Create a generator like `ran_start(seed)` of the reference
+    /// implementation.
+    ///
+    /// Knuth allows seeds from 0 to 2<sup>30</sup> - 3, which each give a
+    /// distinct stream. Only the lower 30 bits of `seed` are used.
+    pub fn new(seed: u32) -> Self {
+        let mut x = [0u32; KK + KK - 1];
+
+        // Bootstrap the buffer
+        let mut ss = seed.wrapping_add(2) & (MM - 2);
+        for x in x[..KK].iter_mut() {
+            *x = ss;
+            ss <<= 1;
+            if ss >= MM {
+                ss -= MM - 2;
+            }
+        }
+        // Make x[1] (and only x[1]) odd
+        x[1] += 1;
+
+        let mut ss = seed & (MM - 1);
+        let mut t = TT - 1;
+        while t != 0 {
+            // "Square"
+            for j in (1..KK).rev() {
+                x[j + j] = x[j];
+                x[j + j - 1] = 0;
+            }
+            for j in (KK..=KK + KK - 2).rev() {
+                x[j - (KK - LL)] = mod_diff(x[j - (KK - LL)], x[j]);
+                x[j - KK] = mod_diff(x[j - KK], x[j]);
+            }
+            // "Multiply by z"
+            if ss & 1 == 1 {
+                for j in (1..=KK).rev() {
+                    x[j] = x[j - 1];
+                }
+                // Shift the buffer cyclically
+                x[0] = x[KK];
+                x[LL] = mod_diff(x[LL], x[KK]);
+            }
+            if ss != 0 {
+                ss >>= 1;
+            } else {
+                t -= 1;
+            }
+        }
+
+        let mut rng = RanArray {
+            ran_x: [0; KK],
+            buf: [0; KK],
+            index: KK,
+        };
+        rng.ran_x[KK - LL..].copy_from_slice(&x[..LL]);
+        rng.ran_x[..KK - LL].copy_from_slice(&x[LL..KK]);
+        // Warm things up
+        for _ in 0..10 {
+            rng.ran_array(&mut x);
+        }
+        rng
+    }
+
+    /// Fill `aa` with the next `aa.len()` raw outputs of the generator, like
+    /// `ran_array(aa, n)` of the reference implementation.
+    ///
+    /// This does not use or modify the values buffered for
+    /// [`ran_arr_next`](RanArray::ran_arr_next).
+    ///
+    /// # Panics
+    ///
+    /// If `aa` has less than 100 elements.
+    pub fn ran_array(&mut self, aa: &mut [u32]) {
+        let n = aa.len();
+        assert!(n >= KK, "ran_array needs at least 100 elements");
+        aa[..KK].copy_from_slice(&self.ran_x);
+        for j in KK..n {
+            aa[j] = mod_diff(aa[j - KK], aa[j - LL]);
+        }
+        for i in 0..LL {
+            let j = n + i;
+            self.ran_x[i] = mod_diff(aa[j - KK], aa[j - LL]);
+        }
+        for i in LL..KK {
+            let j = n + i;
+            self.ran_x[i] = mod_diff(aa[j - KK], self.ran_x[i - LL]);
+        }
+    }
+
+    /// Return the next 30-bit value, like `ran_arr_next()` of the reference
+    /// implementation.
+    #[inline]
+    pub fn ran_arr_next(&mut self) -> u32 {
+        if self.index >= KK {
+            let mut aa = [0u32; QUALITY];
+            self.ran_array(&mut aa);
+            self.buf.copy_from_slice(&aa[..KK]);
+            self.index = 0;
+        }
+        let value = self.buf[self.index];
+        self.index += 1;
+        value
+    }
+}
+
+impl RngCore for RanArray {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        let hi = self.ran_arr_next();
+        let lo = self.ran_arr_next();
+        (hi << 2) | (lo >> 28)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        utils::next_u64_via_u32(self)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        utils::fill_bytes_via_next_word(dest, || self.next_u32())
+    }
+}
+
+impl SeedableRng for RanArray {
+    type Seed = [u8; 4];
+
+    /// Create a generator with `ran_start`, using the seed as a little-endian
+    /// `u32`. Only the lower 30 bits are used.
+    fn from_seed(seed: Self::Seed) -> Self {
+        RanArray::new(u32::from_le_bytes(seed))
+    }
+}
@@ -1,5 +1,5 @@
 use rand_core::{RngCore, SeedableRng};
-use rand_xorshift::XorShiftRng;
+use rand_xorshift::{Kiss64, Kiss99, RanArray, XorShiftRng};
 
 #[test]
 fn test_xorshift_construction() {
@@ -91,3 +91,163 @@ fn test_xorshift_serde() {
         assert_eq!(rng.next_u64(), deserialized.next_u64());
     }
 }
+
+#[test]
+fn test_ran_array_true_values() {
+    // The test of Knuth's rng.c
+    let mut rng = RanArray::new(310952);
+    let mut a = [0u32; 2009];
+    for _ in 0..=2009 {
+        rng.ran_array(&mut a[..1009]);
+    }
+    assert_eq!(a[0], 995235265);
+
+    let mut rng = RanArray::new(310952);
+    for _ in 0..=1009 {
+        rng.ran_array(&mut a);
+    }
+    assert_eq!(a[0], 995235265);
+
+    // These values were produced with the reference implementation:
+    // https://www-cs-faculty.stanford.edu/~knuth/programs/rng.c
+    let mut rng = RanArray::new(314159);
+    let mut results = [0u32; 12];
+    for i in results.iter_mut() {
+        *i = rng.ran_arr_next();
+    }
+    let expected = [
+        512263819, 254049029, 667424266, 250983279, 84386153, 208871668, 530886531, 1064357929,
+        698897936, 498160000, 876970608, 1056594563,
+    ];
+    assert_eq!(results, expected);
+
+    // Continue in the next cycle of `ran_arr_next`
+    for _ in 0..190 {
+        rng.ran_arr_next();
+    }
+    let mut results = [0u32; 6];
+    for i in results.iter_mut() {
+        *i = rng.ran_arr_next();
+    }
+    let expected = [
+        323478233, 815517790, 275716660, 578520095, 435138923, 130892649,
+    ];
+    assert_eq!(results, expected);
+
+    let mut rng = RanArray::new(0);
+    for i in results.iter_mut() {
+        *i = rng.ran_arr_next();
+    }
+    let expected = [
+        58678820, 248994738, 419973050, 73488693, 261011187, 659354105,
+    ];
+    assert_eq!(results, expected);
+
+    // The largest seed
+    let mut rng = RanArray::from_seed(1073741821u32.to_le_bytes());
+    for i in results.iter_mut() {
+        *i = rng.ran_arr_next();
+    }
+    let expected = [
+        250120752, 944010350, 717770188, 420620283, 180021144, 258377777,
+    ];
+    assert_eq!(results, expected);
+}
+
+#[test]
+fn test_ran_array_next_u32() {
+    let mut rng = RanArray::new(314159);
+    // 512263819 << 2 | 254049029 >> 28, and 667424266 << 2 | 250983279 >> 28
+    assert_eq!(rng.next_u32(), 2049055276);
+    assert_eq!(rng.next_u32(), 2669697064);
+}
+
+#[test]
+fn test_kiss99_true_values() {
+    // These values were produced with the macros of Marsaglia's post of 1999
+    let mut rng = Kiss99::new(362436069, 521288629, 123456789, 380116160);
+    let mut results = [0u32; 8];
+    for i in results.iter_mut() {
+        *i = rng.next_u32();
+    }
+    let expected = [
+        769445856, 742012328, 2121196314, 2805620942, 3214428071, 3762104941, 2038049824,
+        2598763430,
+    ];
+    assert_eq!(results, expected);
+
+    // The test program of the post seeds the state with `settable`, which
+    // draws 256 values to fill its table, and checks the millionth value
+    // after that.
+    let mut rng = Kiss99::new(12345, 65435, 34221, 12345);
+    for _ in 0..256 {
+        rng.next_u32();
+    }
+    for _ in 1..1000000 {
+        rng.next_u32();
+    }
+    assert_eq!(rng.next_u32(), 1372460312);
+}
+
+#[test]
+fn test_kiss99_zero_seed() {
+    let mut a = Kiss99::from_seed([0; 16]);
+    let mut b = Kiss99::new(362436069, 521288629, 123456789, 0);
+    for _ in 0..16 {
+        assert_eq!(a.next_u32(), b.next_u32());
+    }
+}
+
+#[test]
+fn test_kiss64_true_values() {
+    // These values were produced with the reference implementation, which
+    // gives 1666297717051644203 after 10^8 outputs.
+    let mut rng = Kiss64::new(
+        1234567890987654321,
+        123456123456123456,
+        362436362436362436,
+        1066149217761810,
+    );
+    let mut results = [0u64; 6];
+    for i in results.iter_mut() {
+        *i = rng.next_u64();
+    }
+    let expected = [
+        8932985056925012148,
+        5710300428094272059,
+        18342510866933518593,
+        14303636270573868250,
+        542381058189297533,
+        14201812252854837425,
+    ];
+    assert_eq!(results, expected);
+}
+
+#[test]
+fn test_kiss64_zero_seed() {
+    let mut a = Kiss64::from_seed([0; 32]);
+    let mut b = Kiss64::new(
+        1234567890987654321,
+        123456123456123456,
+        362436362436362436,
+        0,
+    );
+    for _ in 0..16 {
+        assert_eq!(a.next_u64(), b.next_u64());
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_ran_array_serde() {
+    let mut rng = RanArray::new(314159);
+    rng.ran_arr_next();
+
+    let buf = postcard::to_allocvec(&rng).expect("Could not serialize");
+
+    let mut deserialized: RanArray = postcard::from_bytes(&buf).expect("Could not deserialize");
+
+    for _ in 0..200 {
+        assert_eq!(rng.next_u64(), deserialized.next_u64());
+    }
+}
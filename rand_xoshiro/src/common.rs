@@ -237,6 +237,80 @@ macro_rules! deal_with_zero_seed {
     };
 }
 
+/// Map a carry outside of the valid range `0 < c < a - 1` of an MWC
+/// generator with multiplier `a` to a carry inside of it.
+pub(crate) fn mwc_carry(c: u64, a: u64) -> u64 {
+    if c == 0 || c >= a - 1 {
+        c.wrapping_sub(a - 2)
+    } else {
+        c
+    }
+}
+
+/// Jump forward an MWC generator with multiplier `a`.
+///
+/// `state` are the words of the generator from the oldest to the carry,
+/// which as a little-endian number is the state of the equivalent
+/// multiplicative LCG with modulus `m = a * 2^(64 * (N - 1)) - 1`. Jumping
+/// multiplies it by `jump`, a power of the LCG multiplier, modulo `m`.
+pub(crate) fn mwc_jump<const N: usize>(state: &mut [u64; N], a: u64, jump: &[u64; N]) {
+    let mut m = [u64::MAX; N];
+    m[N - 1] = a - 1;
+
+    // (x + y) mod m, for x, y < m
+    let add_mod = |x: &[u64; N], y: &[u64; N]| -> [u64; N] {
+        let mut sum = [0; N];
+        let mut carry = false;
+        for i in 0..N {
+            let (s, c1) = x[i].overflowing_add(y[i]);
+            let (s, c2) = s.overflowing_add(carry as u64);
+            sum[i] = s;
+            carry = c1 || c2;
+        }
+        if carry || sum.iter().rev().cmp(m.iter().rev()).is_ge() {
+            let mut borrow = false;
+            for i in 0..N {
+                let (d, b1) = sum[i].overflowing_sub(m[i]);
+                let (d, b2) = d.overflowing_sub(borrow as u64);
+                sum[i] = d;
+                borrow = b1 || b2;
+            }
+        }
+        sum
+    };
+
+    // Double and add, from the most significant bit of `jump`.
+    let mut result = [0; N];
+    for word in jump.iter().rev() {
+        for b in (0..64).rev() {
+            result = add_mod(&result, &result);
+            if (word >> b) & 1 != 0 {
+                result = add_mod(&result, state);
+            }
+        }
+    }
+    *state = result;
+}
+
+/// Return the multiplier and increment of `delta` steps of the LCG `x ->
+/// m * x + c` modulo 2^128, with Brown's algorithm for arbitrary strides.
+pub(crate) fn lcg128_jump(m: u128, c: u128, mut delta: u128) -> (u128, u128) {
+    let mut acc_mult: u128 = 1;
+    let mut acc_plus: u128 = 0;
+    let mut cur_mult = m;
+    let mut cur_plus = c;
+    while delta > 0 {
+        if delta & 1 != 0 {
+            acc_mult = acc_mult.wrapping_mul(cur_mult);
+            acc_plus = acc_plus.wrapping_mul(cur_mult).wrapping_add(cur_plus);
+        }
+        cur_plus = cur_mult.wrapping_add(1).wrapping_mul(cur_plus);
+        cur_mult = cur_mult.wrapping_mul(cur_mult);
+        delta >>= 1;
+    }
+    (acc_mult, acc_plus)
+}
+
 /// 512-bit seed for a generator.
 ///
 /// This wrapper is necessary, because some traits required for a seed are not
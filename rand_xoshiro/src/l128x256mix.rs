@@ -0,0 +1,221 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::Seed512;
+use crate::random_support::{
+    GOLDEN_RATIO_64, SILVER_RATIO_64, convert_seed_bytes, fix_zero_lxm_state, mix_lea64,
+    mix_murmur64, mix_stafford13,
+};
+use rand_core::{RngCore, SeedableRng, utils};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The LCG multiplier, `2^64 + 0xd605bbb58c8abbfd`.
+const M: u128 = (1 << 64) | 0xd605bbb58c8abbfd;
+
+/// An L128X256MixRandom random number generator.
+///
+/// Like [`L64X256Mix`], but with a 128-bit LCG, of which the high word is
+/// added to the xoshiro256 state before mixing. This gives 2^127 streams and
+/// a period of 2^128 · (2^256 − 1). It is not suitable for cryptographic
+/// purposes.
+///
+/// The implementation is bit-compatible with `L128X256MixRandom` from Java
+/// 17's `java.util.random`, including seeding from a `u64` and `split()`.
+/// Java 17's `byte[]` constructor for this generator always throws (and
+/// `RandomGeneratorFactory` silently replaces it with random seeding), so
+/// [`from_seed`](SeedableRng::from_seed) follows the byte conversion used by
+/// the other LXM generators instead.
+///
+/// [`L64X256Mix`]: crate::L64X256Mix
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct L128X256Mix {
+    a: u128,
+    lcg: u128,
+    s: [u64; 4],
+}
+
+impl L128X256Mix {
+    /// Create a generator from the LCG additive parameter `a`, the LCG state
+    /// `s` and the xoshiro256 state `x`.
+    ///
+    /// `a` is made odd. If `x` is all zero, it is derived from the high word
+    /// of `s` instead. This is the JDK's eight-argument constructor, with the
+    /// high and low words of `a` and `s` combined.
+    pub fn new(a: u128, s: u128, mut x: [u64; 4]) -> L128X256Mix {
+        fix_zero_lxm_state(&mut x, (s >> 64) as u64);
+        L128X256Mix {
+            a: a | 1,
+            lcg: s,
+            s: x,
+        }
+    }
+
+    /// Create a generator from a seed of any length. Only the first 64 bytes
+    /// are used.
+    pub fn from_seed_bytes(seed: &[u8]) -> L128X256Mix {
+        let [ah, al, sh, sl, x0, x1, x2, x3] = convert_seed_bytes(seed, 4);
+        L128X256Mix {
+            a: (u128::from(ah) << 64 | u128::from(al)) | 1,
+            lcg: u128::from(sh) << 64 | u128::from(sl),
+            s: [x0, x1, x2, x3],
+        }
+    }
+
+    /// Split off a new generator, like `split()` in the JDK.
+    ///
+    /// The first output of `self` selects the low word of the stream of the
+    /// new generator, the next one its high word, and the next six are its
+    /// initial state.
+    ///
+    /// ```
+    /// use rand_xoshiro::rand_core::{RngCore, SeedableRng};
+    /// use rand_xoshiro::L128X256Mix;
+    ///
+    /// let mut rng1 = L128X256Mix::seed_from_u64(0);
+    /// let mut rng2 = rng1.split();
+    /// assert_ne!(rng1.next_u64(), rng2.next_u64());
+    /// ```
+    pub fn split(&mut self) -> L128X256Mix {
+        let brine = self.next_u64();
+        let ah = self.next_u64();
+        let sh = self.next_u64();
+        let sl = self.next_u64();
+        let x = [(); 4].map(|()| self.next_u64());
+        L128X256Mix::new(
+            u128::from(ah) << 64 | u128::from(brine << 1),
+            u128::from(sh) << 64 | u128::from(sl),
+            x,
+        )
+    }
+}
+
+impl SeedableRng for L128X256Mix {
+    /// The LCG additive parameter and state, as high and low words, followed
+    /// by the xoshiro256 state, all as big-endian words.
+    type Seed = Seed512;
+
+    /// Create a new `L128X256Mix`. If the xoshiro256 part of `seed` is all
+    /// zero, it will be mapped to a different seed.
+    fn from_seed(seed: Seed512) -> L128X256Mix {
+        L128X256Mix::from_seed_bytes(&seed.0)
+    }
+
+    /// Seed a `L128X256Mix` from a `u64`, like the JDK's `long` constructor.
+    fn seed_from_u64(seed: u64) -> L128X256Mix {
+        let seed = seed ^ SILVER_RATIO_64;
+        let ah = mix_murmur64(seed);
+        let seed = seed.wrapping_add(GOLDEN_RATIO_64);
+        let al = mix_murmur64(seed);
+        let mut x = [0; 4];
+        for (i, w) in x.iter_mut().enumerate() {
+            *w = mix_stafford13(seed.wrapping_add(GOLDEN_RATIO_64.wrapping_mul(i as u64)));
+        }
+        L128X256Mix::new(u128::from(ah) << 64 | u128::from(al), 1, x)
+    }
+}
+
+impl RngCore for L128X256Mix {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let result = mix_lea64(((self.lcg >> 64) as u64).wrapping_add(self.s[0]));
+        self.lcg = M.wrapping_mul(self.lcg).wrapping_add(self.a);
+        impl_xoshiro_u64!(self);
+        result
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        utils::fill_bytes_via_next_word(dest, || self.next_u64());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // These values were produced with OpenJDK 17 using
+    // `RandomGeneratorFactory.of("L128X256MixRandom")`.
+
+    #[test]
+    fn reference() {
+        let mut rng = L128X256Mix::seed_from_u64(0);
+        let expected = [
+            3660353619932124769,
+            1230148694182519244,
+            9866940683304511007,
+            2379854245611497910,
+            9258290484139581220,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+
+        let mut rng = L128X256Mix::seed_from_u64(12345);
+        let expected = [
+            4077522493232700897,
+            9982106337221010696,
+            89819699932612037,
+            11502219782572770091,
+            798592742486076961,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn split() {
+        let mut parent = L128X256Mix::seed_from_u64(42);
+        let mut child = parent.split();
+        let expected = [
+            14241219062941166746,
+            4658305193470719943,
+            10866794558713739071,
+            4440900818272989145,
+            649978941467527129,
+        ];
+        for &e in &expected {
+            assert_eq!(child.next_u64(), e);
+        }
+        for &e in &[
+            10832419534300355436,
+            6072735663636576527,
+            5369620566125762121,
+        ] {
+            assert_eq!(parent.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn next_u32_and_bytes() {
+        let mut rng = L128X256Mix::seed_from_u64(7);
+        assert_eq!(rng.next_u32(), 3831340115);
+
+        let mut rng = L128X256Mix::seed_from_u64(7);
+        let mut bytes = [0; 13];
+        rng.fill_bytes(&mut bytes);
+        assert_eq!(
+            bytes,
+            [85, 18, 41, 27, 83, 156, 93, 228, 2, 118, 198, 15, 147]
+        );
+    }
+
+    #[test]
+    fn zero_seed() {
+        let mut rng = L128X256Mix::from_seed(Seed512::default());
+        assert_ne!(rng.s, [0; 4]);
+        assert_ne!(rng.next_u64(), rng.next_u64());
+    }
+}
@@ -0,0 +1,248 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::random_support::{
+    GOLDEN_RATIO_64, SILVER_RATIO_64, convert_seed_bytes, fix_zero_lxm_state, mix_lea64,
+    mix_murmur64, mix_stafford13,
+};
+use rand_core::{RngCore, SeedableRng, utils};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const M: u64 = 0xd1342543de82ef95;
+
+/// An L64X128MixRandom random number generator.
+///
+/// This LXM generator adds the state of a 64-bit LCG to the first word of a
+/// xoroshiro128 generator and mixes the sum with Doug Lea's 64-bit mixing
+/// function. The additive parameter of the LCG selects one of 2^63
+/// independent streams, which is what makes [`split`](Self::split) cheap.
+/// It is not suitable for cryptographic purposes.
+///
+/// The implementation is bit-compatible with `L64X128MixRandom` from Java 17's
+/// `java.util.random`, including seeding and `split()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct L64X128Mix {
+    a: u64,
+    lcg: u64,
+    s0: u64,
+    s1: u64,
+}
+
+impl L64X128Mix {
+    /// Create a generator from the LCG additive parameter `a`, the LCG state
+    /// `s` and the xoroshiro128 state `x`.
+    ///
+    /// `a` is made odd. If `x` is all zero, it is derived from `s` instead.
+    /// This is the JDK's four-argument constructor.
+    pub fn new(a: u64, s: u64, mut x: [u64; 2]) -> L64X128Mix {
+        fix_zero_lxm_state(&mut x, s);
+        let [s0, s1] = x;
+        L64X128Mix {
+            a: a | 1,
+            lcg: s,
+            s0,
+            s1,
+        }
+    }
+
+    /// Create a generator from a seed of any length, like the JDK's `byte[]`
+    /// constructor.
+    ///
+    /// [`from_seed`](SeedableRng::from_seed) is this function restricted to
+    /// 32-byte seeds.
+    pub fn from_seed_bytes(seed: &[u8]) -> L64X128Mix {
+        let [a, s, x0, x1] = convert_seed_bytes(seed, 2);
+        L64X128Mix {
+            a: a | 1,
+            lcg: s,
+            s0: x0,
+            s1: x1,
+        }
+    }
+
+    /// Split off a new generator, like `split()` in the JDK.
+    ///
+    /// The first output of `self` selects the stream of the new generator and
+    /// the next three are its initial state.
+    ///
+    /// ```
+    /// use rand_xoshiro::rand_core::{RngCore, SeedableRng};
+    /// use rand_xoshiro::L64X128Mix;
+    ///
+    /// let mut rng1 = L64X128Mix::seed_from_u64(0);
+    /// let mut rng2 = rng1.split();
+    /// assert_ne!(rng1.next_u64(), rng2.next_u64());
+    /// ```
+    pub fn split(&mut self) -> L64X128Mix {
+        let brine = self.next_u64();
+        let s = self.next_u64();
+        let x0 = self.next_u64();
+        let x1 = self.next_u64();
+        L64X128Mix::new(brine << 1, s, [x0, x1])
+    }
+}
+
+impl SeedableRng for L64X128Mix {
+    /// The LCG additive parameter and state followed by the xoroshiro128
+    /// state, as big-endian words.
+    type Seed = [u8; 32];
+
+    /// Create a new `L64X128Mix`, like the JDK's `byte[]` constructor. If the
+    /// xoroshiro128 part of `seed` is all zero, it will be mapped to a
+    /// different seed.
+    fn from_seed(seed: [u8; 32]) -> L64X128Mix {
+        L64X128Mix::from_seed_bytes(&seed)
+    }
+
+    /// Seed a `L64X128Mix` from a `u64`, like the JDK's `long` constructor.
+    fn seed_from_u64(seed: u64) -> L64X128Mix {
+        let seed = seed ^ SILVER_RATIO_64;
+        L64X128Mix::new(
+            mix_murmur64(seed),
+            1,
+            [
+                mix_stafford13(seed),
+                mix_stafford13(seed.wrapping_add(GOLDEN_RATIO_64)),
+            ],
+        )
+    }
+}
+
+impl RngCore for L64X128Mix {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let result = mix_lea64(self.lcg.wrapping_add(self.s0));
+        self.lcg = M.wrapping_mul(self.lcg).wrapping_add(self.a);
+        impl_xoroshiro_u64!(self);
+        result
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        utils::fill_bytes_via_next_word(dest, || self.next_u64());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // These values were produced with OpenJDK 17 using
+    // `RandomGeneratorFactory.of("L64X128MixRandom")`.
+
+    #[test]
+    fn reference() {
+        let mut rng = L64X128Mix::seed_from_u64(0);
+        let expected = [
+            5462611082047578805,
+            6542559582828032015,
+            3684453750024441887,
+            9858913235383984852,
+            4095285899639789136,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+
+        let mut rng = L64X128Mix::seed_from_u64(12345);
+        let expected = [
+            2199008777173523236,
+            10259011114038088897,
+            15247578246912585890,
+            6108207583486766186,
+            10063145540329050868,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn seed_bytes() {
+        let mut seed = [0; 32];
+        for (i, b) in seed.iter_mut().enumerate() {
+            *b = (i as u8).wrapping_mul(37).wrapping_add(1);
+        }
+        let mut rng = L64X128Mix::from_seed(seed);
+        let expected = [
+            6902453056155121825,
+            10955933939231477685,
+            5729735874693870768,
+            8801741034877000127,
+            605617242567706681,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+
+        let mut rng = L64X128Mix::from_seed_bytes(&[1, 2, 3, 200, 5]);
+        let expected = [
+            11545336235688548120,
+            12008462612800811995,
+            1991404596060086453,
+            7141431587756587542,
+            12488191551707262899,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+
+        let mut rng = L64X128Mix::from_seed([0; 32]);
+        for &e in &[
+            11542037245503486978,
+            10976903068004034360,
+            11098327418378257106,
+        ] {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn split() {
+        let mut parent = L64X128Mix::seed_from_u64(42);
+        let mut child = parent.split();
+        let expected = [
+            3235922438714603185,
+            15423877591027676247,
+            4459269925504001158,
+            8858431750533032459,
+            12592085789644450296,
+        ];
+        for &e in &expected {
+            assert_eq!(child.next_u64(), e);
+        }
+        for &e in &[
+            9812019905386609802,
+            2042853296061895294,
+            9958808076851885519,
+        ] {
+            assert_eq!(parent.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn next_u32_and_bytes() {
+        let mut rng = L64X128Mix::seed_from_u64(7);
+        assert_eq!(rng.next_u32(), 1983493593);
+
+        let mut rng = L64X128Mix::seed_from_u64(7);
+        let mut bytes = [0; 13];
+        rng.fill_bytes(&mut bytes);
+        assert_eq!(
+            bytes,
+            [152, 66, 67, 244, 217, 181, 57, 118, 229, 100, 72, 223, 72]
+        );
+    }
+}
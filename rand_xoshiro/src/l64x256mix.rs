@@ -0,0 +1,234 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::Seed512;
+use crate::random_support::{
+    GOLDEN_RATIO_64, SILVER_RATIO_64, convert_seed_bytes, fix_zero_lxm_state, mix_lea64,
+    mix_murmur64, mix_stafford13,
+};
+use rand_core::{RngCore, SeedableRng, utils};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const M: u64 = 0xd1342543de82ef95;
+
+/// An L64X256MixRandom random number generator.
+///
+/// Like [`L64X128Mix`], but with a xoshiro256 generator in place of
+/// xoroshiro128. The larger xorshift state gives the output 4-dimensional
+/// equidistribution. It is not suitable for cryptographic purposes.
+///
+/// The implementation is bit-compatible with `L64X256MixRandom` from Java 17's
+/// `java.util.random`, including seeding and `split()`.
+///
+/// [`L64X128Mix`]: crate::L64X128Mix
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct L64X256Mix {
+    a: u64,
+    lcg: u64,
+    s: [u64; 4],
+}
+
+impl L64X256Mix {
+    /// Create a generator from the LCG additive parameter `a`, the LCG state
+    /// `s` and the xoshiro256 state `x`.
+    ///
+    /// `a` is made odd. If `x` is all zero, it is derived from `s` instead.
+    /// This is the JDK's six-argument constructor.
+    pub fn new(a: u64, s: u64, mut x: [u64; 4]) -> L64X256Mix {
+        fix_zero_lxm_state(&mut x, s);
+        L64X256Mix {
+            a: a | 1,
+            lcg: s,
+            s: x,
+        }
+    }
+
+    /// Create a generator from a seed of any length, like the JDK's `byte[]`
+    /// constructor. Only the first 48 bytes are used.
+    pub fn from_seed_bytes(seed: &[u8]) -> L64X256Mix {
+        let [a, s, x0, x1, x2, x3] = convert_seed_bytes(seed, 4);
+        L64X256Mix {
+            a: a | 1,
+            lcg: s,
+            s: [x0, x1, x2, x3],
+        }
+    }
+
+    /// Split off a new generator, like `split()` in the JDK.
+    ///
+    /// The first output of `self` selects the stream of the new generator and
+    /// the next five are its initial state.
+    ///
+    /// ```
+    /// use rand_xoshiro::rand_core::{RngCore, SeedableRng};
+    /// use rand_xoshiro::L64X256Mix;
+    ///
+    /// let mut rng1 = L64X256Mix::seed_from_u64(0);
+    /// let mut rng2 = rng1.split();
+    /// assert_ne!(rng1.next_u64(), rng2.next_u64());
+    /// ```
+    pub fn split(&mut self) -> L64X256Mix {
+        let brine = self.next_u64();
+        let s = self.next_u64();
+        let x = [(); 4].map(|()| self.next_u64());
+        L64X256Mix::new(brine << 1, s, x)
+    }
+}
+
+impl SeedableRng for L64X256Mix {
+    /// The LCG additive parameter and state followed by the xoshiro256 state,
+    /// as big-endian words. The last 16 bytes are ignored.
+    type Seed = Seed512;
+
+    /// Create a new `L64X256Mix`, like the JDK's `byte[]` constructor. If the
+    /// xoshiro256 part of `seed` is all zero, it will be mapped to a different
+    /// seed.
+    fn from_seed(seed: Seed512) -> L64X256Mix {
+        L64X256Mix::from_seed_bytes(&seed.0)
+    }
+
+    /// Seed a `L64X256Mix` from a `u64`, like the JDK's `long` constructor.
+    fn seed_from_u64(seed: u64) -> L64X256Mix {
+        let seed = seed ^ SILVER_RATIO_64;
+        let mut x = [0; 4];
+        for (i, w) in x.iter_mut().enumerate() {
+            *w = mix_stafford13(seed.wrapping_add(GOLDEN_RATIO_64.wrapping_mul(i as u64)));
+        }
+        L64X256Mix::new(mix_murmur64(seed), 1, x)
+    }
+}
+
+impl RngCore for L64X256Mix {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let result = mix_lea64(self.lcg.wrapping_add(self.s[0]));
+        self.lcg = M.wrapping_mul(self.lcg).wrapping_add(self.a);
+        impl_xoshiro_u64!(self);
+        result
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        utils::fill_bytes_via_next_word(dest, || self.next_u64());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // These values were produced with OpenJDK 17 using
+    // `RandomGeneratorFactory.of("L64X256MixRandom")`.
+
+    #[test]
+    fn reference() {
+        let mut rng = L64X256Mix::seed_from_u64(0);
+        let expected = [
+            5462611082047578805,
+            17095950887328919928,
+            15680017532028854295,
+            13839003410276510397,
+            15619666657875160355,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+
+        let mut rng = L64X256Mix::seed_from_u64(12345);
+        let expected = [
+            2199008777173523236,
+            16775505001087804740,
+            14383106257260544061,
+            4874915235189641265,
+            2576570266124011713,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn seed_bytes() {
+        let mut seed = Seed512::default();
+        for (i, b) in seed.0.iter_mut().enumerate() {
+            *b = (i as u8).wrapping_mul(37).wrapping_add(1);
+        }
+        let mut rng = L64X256Mix::from_seed(seed);
+        let expected = [
+            6902453056155121825,
+            7999570320465906809,
+            4806337471259122534,
+            10183961876530069516,
+            16577341168165067902,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+
+        let mut rng = L64X256Mix::from_seed_bytes(&[1, 2, 3, 200, 5]);
+        let expected = [
+            11545336235688548120,
+            4317875568722791960,
+            13469383184944238111,
+            15044082074122323078,
+            8259813228833263116,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+
+        let mut rng = L64X256Mix::from_seed_bytes(&[0; 32]);
+        for &e in &[0, 16281770370209497359, 5135379239902924091] {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn split() {
+        let mut parent = L64X256Mix::seed_from_u64(42);
+        let mut child = parent.split();
+        let expected = [
+            3659942515198825995,
+            2448992714192583910,
+            4683391955336968457,
+            1146665093021380336,
+            2667005315842049807,
+        ];
+        for &e in &expected {
+            assert_eq!(child.next_u64(), e);
+        }
+        for &e in &[
+            12657964210560404825,
+            8211787831419787295,
+            17617801845364855842,
+        ] {
+            assert_eq!(parent.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn next_u32_and_bytes() {
+        let mut rng = L64X256Mix::seed_from_u64(7);
+        assert_eq!(rng.next_u32(), 1983493593);
+
+        let mut rng = L64X256Mix::seed_from_u64(7);
+        let mut bytes = [0; 13];
+        rng.fill_bytes(&mut bytes);
+        assert_eq!(
+            bytes,
+            [152, 66, 67, 244, 217, 181, 57, 118, 102, 58, 76, 207, 167]
+        );
+    }
+}
@@ -0,0 +1,217 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::Mcg128;
+use crate::common::lcg128_jump;
+use rand_core::{RngCore, SeedableRng, utils};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A 128-bit linear congruential random number generator.
+///
+/// Like [`Mcg128`], but every step also adds an odd increment to the state,
+/// which gives a period of 2^128 for any state, and 2^127 different streams.
+/// It returns the high 64 bits of the new state. It is not suitable for
+/// cryptographic purposes.
+///
+/// [`new`] uses the multiplier [`MULTIPLIER_64`]. The low bits of the state
+/// of an LCG have short periods, which is why only the high bits are
+/// returned.
+///
+/// [`new`]: Lcg128::new
+/// [`MULTIPLIER_64`]: Lcg128::MULTIPLIER_64
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Lcg128State"))]
+pub struct Lcg128 {
+    state: u128,
+    multiplier: u128,
+    increment: u128,
+}
+
+// The fields of a deserialized `Lcg128`, before checking them
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct Lcg128State {
+    state: u128,
+    multiplier: u128,
+    increment: u128,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<Lcg128State> for Lcg128 {
+    type Error = &'static str;
+
+    fn try_from(
+        Lcg128State {
+            state,
+            multiplier,
+            increment,
+        }: Lcg128State,
+    ) -> Result<Self, Self::Error> {
+        if multiplier % 4 != 1 {
+            return Err(INVALID_MULTIPLIER);
+        }
+        if increment % 2 == 0 {
+            return Err("increment must be odd");
+        }
+        Ok(Lcg128 {
+            state,
+            multiplier,
+            increment,
+        })
+    }
+}
+
+const INVALID_MULTIPLIER: &str = "multiplier must be 1 modulo 4";
+
+impl Lcg128 {
+    /// The 64-bit multiplier [`Mcg128::MULTIPLIER_64`].
+    pub const MULTIPLIER_64: u128 = Mcg128::MULTIPLIER_64;
+
+    /// The 65-bit multiplier [`Mcg128::MULTIPLIER_65`].
+    pub const MULTIPLIER_65: u128 = Mcg128::MULTIPLIER_65;
+
+    /// The 128-bit multiplier [`Mcg128::MULTIPLIER_128`].
+    pub const MULTIPLIER_128: u128 = Mcg128::MULTIPLIER_128;
+
+    /// Create a generator with the given state, the increment `increment |
+    /// 1` and the multiplier [`MULTIPLIER_64`](Self::MULTIPLIER_64).
+    pub fn new(state: u128, increment: u128) -> Self {
+        Lcg128::with_multiplier(state, increment, Self::MULTIPLIER_64)
+    }
+
+    /// Create a generator with the given state, the increment `increment |
+    /// 1` and the given multiplier.
+    ///
+    /// # Panics
+    ///
+    /// If `multiplier` is not 1 modulo 4, as the period would be shorter
+    /// than 2^128.
+    pub fn with_multiplier(state: u128, increment: u128, multiplier: u128) -> Self {
+        assert!(multiplier % 4 == 1, "{}", INVALID_MULTIPLIER);
+        Lcg128 {
+            state,
+            multiplier,
+            increment: increment | 1,
+        }
+    }
+
+    /// Jump forward `delta` steps in O(log `delta`) time, equivalently to
+    /// `delta` calls to `next_u64()`.
+    ///
+    /// Going backwards is possible with `delta.wrapping_neg()`, the long way
+    /// round.
+    pub fn advance(&mut self, delta: u128) {
+        let (mult, plus) = lcg128_jump(self.multiplier, self.increment, delta);
+        self.state = self.state.wrapping_mul(mult).wrapping_add(plus);
+    }
+}
+
+impl SeedableRng for Lcg128 {
+    /// The state followed by the increment, as little-endian numbers.
+    type Seed = [u8; 32];
+
+    /// Create a new `Lcg128`. The lowest bit of the increment is set, as it
+    /// must be odd.
+    fn from_seed(seed: [u8; 32]) -> Lcg128 {
+        let [s0, s1, c0, c1]: [u64; 4] = utils::read_words(&seed);
+        let state = u128::from(s0) | (u128::from(s1) << 64);
+        let increment = u128::from(c0) | (u128::from(c1) << 64);
+        Lcg128::new(state, increment)
+    }
+
+    /// Seed a `Lcg128` from a `u64` using `SplitMix64`.
+    fn seed_from_u64(seed: u64) -> Lcg128 {
+        from_splitmix!(seed)
+    }
+}
+
+impl RngCore for Lcg128 {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        // The upper bits have the best statistical quality.
+        (self.next_u64() >> 32) as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.state = self
+            .state
+            .wrapping_mul(self.multiplier)
+            .wrapping_add(self.increment);
+        (self.state >> 64) as u64
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        utils::fill_bytes_via_next_word(dest, || self.next_u64());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STATE: u128 = 0x0123456789abcdef_fedcba9876543210;
+    const INCREMENT: u128 = 0x1111111111111111_2222222222222223;
+
+    #[test]
+    fn reference() {
+        let mut rng = Lcg128::with_multiplier(STATE, INCREMENT, Lcg128::MULTIPLIER_65);
+        // These values were produced by a C implementation with `__uint128_t`.
+        let expected = [
+            8591064816378982204,
+            5959427858634475994,
+            5592840447130521168,
+            6625750880750917173,
+            1458769095272764086,
+            14644075036468933837,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn seed() {
+        let mut seed = [0; 32];
+        seed[..16].copy_from_slice(&STATE.to_le_bytes());
+        seed[16..].copy_from_slice(&(INCREMENT - 1).to_le_bytes());
+        assert_eq!(Lcg128::from_seed(seed), Lcg128::new(STATE, INCREMENT));
+    }
+
+    #[test]
+    fn advance() {
+        for multiplier in [
+            Lcg128::MULTIPLIER_64,
+            Lcg128::MULTIPLIER_65,
+            Lcg128::MULTIPLIER_128,
+        ] {
+            let mut rng = Lcg128::with_multiplier(STATE, INCREMENT, multiplier);
+            let mut skipped = rng.clone();
+            for _ in 0..1000 {
+                rng.next_u64();
+            }
+            skipped.advance(1000);
+            assert_eq!(rng, skipped);
+
+            skipped.advance(1000u128.wrapping_neg());
+            assert_eq!(
+                skipped,
+                Lcg128::with_multiplier(STATE, INCREMENT, multiplier)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_multiplier() {
+        Lcg128::with_multiplier(STATE, INCREMENT, 0xda942042e4dd58b7);
+    }
+}
@@ -45,6 +45,10 @@
 //!   tests. This is unlikely to have any impact in practise.
 //! - [`SplitMix64`]: Recommended for initializing generators of the xoshiro
 //!   family from a 64-bit seed. Used for implementing `seed_from_u64`.
+//! - [`SplittableMix64`]: A variant of `SplitMix64` that can be split into
+//!   independent generators, compatible with Java's `SplittableRandom`.
+//! - [`WyRand`]: Wang Yi's wyrand, compatible with `wyhash.h`. Very fast, with
+//!   only 64 bits of state, for hash tables and sampling in hot loops.
 //!
 //! # 32-bit generators
 //! - [`Xoshiro128StarStar`]: Recommended for all purposes. Excellent speed.
@@ -60,10 +64,37 @@
 //!   same speed but using half the state. Has a [low linear complexity] in the
 //!   lowest bits (which are discarded when generating floats), making it fail
 //!   linearity tests. This is unlikely to have any impact in practise.
+//! - [`SplitMix32`]: Recommended for initializing the 32-bit generators from a
+//!   32-bit seed using only 32-bit arithmetic.
 //!
 //! The `*PlusPlus` generators perform similarly to the `*StarStar` generators.
 //! See the [xoshiro paper], where the differences are discussed in detail.
 //!
+//! # Multiply-with-carry generators
+//! - [`Mwc128`]: A very fast generator with 128 bits of state, based on
+//!   multiplication rather than linear operations, so it does not fail
+//!   linearity tests.
+//! - [`Mwc192`]: Slightly slower than `Mwc128`, but with 192 bits of state and
+//!   better statistical quality.
+//! - [`Mwc256`]: An alternative to `Mwc192` with 256 bits of state, for
+//!   massively parallel applications.
+//!
+//! # Congruential generators
+//! - [`Mcg128`]: A 128-bit multiplicative congruential generator returning
+//!   the high 64 bits of its state, equal to Lemire's `lehmer64`. Extremely
+//!   fast, and it passes BigCrush.
+//! - [`Lcg128`]: A 128-bit linear congruential generator returning the high
+//!   64 bits of its state, with 2^127 streams.
+//!
+//! Both can jump ahead with `advance` in O(log n) steps, and their multiplier
+//! can be chosen.
+//!
+//! # LXM generators
+//! - [`L64X128Mix`], [`L64X256Mix`] and [`L128X256Mix`]: Splittable
+//!   generators combining an LCG with a xoroshiro or xoshiro generator. They
+//!   are bit-compatible with the generators of the same name in Java 17's
+//!   `java.util.random`, for sharing reproducible streams with the JVM.
+//!
 //! # Example
 //!
 //! To initialize a generator, use the [`SeedableRng`][rand_core::SeedableRng] trait:
@@ -92,7 +123,19 @@
 
 #[macro_use]
 mod common;
+mod l128x256mix;
+mod l64x128mix;
+mod l64x256mix;
+mod lcg128;
+mod mcg128;
+mod mwc128;
+mod mwc192;
+mod mwc256;
+mod random_support;
+mod splitmix32;
 mod splitmix64;
+mod splittablemix64;
+mod wyrand;
 mod xoroshiro128plus;
 mod xoroshiro128plusplus;
 mod xoroshiro128starstar;
@@ -109,8 +152,19 @@ mod xoshiro512plusplus;
 mod xoshiro512starstar;
 
 pub use common::Seed512;
+pub use l64x128mix::L64X128Mix;
+pub use l64x256mix::L64X256Mix;
+pub use l128x256mix::L128X256Mix;
+pub use lcg128::Lcg128;
+pub use mcg128::Mcg128;
+pub use mwc128::Mwc128;
+pub use mwc192::Mwc192;
+pub use mwc256::Mwc256;
 pub use rand_core;
+pub use splitmix32::SplitMix32;
 pub use splitmix64::SplitMix64;
+pub use splittablemix64::SplittableMix64;
+pub use wyrand::WyRand;
 pub use xoroshiro64star::Xoroshiro64Star;
 pub use xoroshiro64starstar::Xoroshiro64StarStar;
 pub use xoroshiro128plus::Xoroshiro128Plus;
@@ -0,0 +1,209 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::common::lcg128_jump;
+use rand_core::{RngCore, SeedableRng, utils};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A 128-bit multiplicative congruential random number generator.
+///
+/// This is a Lehmer generator: every step multiplies the 128-bit state by a
+/// constant modulo 2^128, and returns the high 64 bits of the new state. With
+/// a 64-bit multiplier, this takes little more than one 64-bit
+/// multiplication, which makes it one of the fastest generators that pass
+/// BigCrush. The state must be odd, and the period is 2^126. It is not
+/// suitable for cryptographic purposes.
+///
+/// [`new`] uses [`MULTIPLIER_64`], which makes the generator equal to
+/// `lehmer64` by Daniel Lemire, given the same odd state. Other multipliers
+/// can be chosen with [`with_multiplier`]. The associated constants are
+/// multipliers in use by other generators; Steele and Vigna[^1] publish
+/// tables of multipliers of every size with good spectral scores.
+///
+/// [^1]: Guy L. Steele Jr. and Sebastiano Vigna (2022). ["Computationally
+///       easy, spectrally good multipliers for congruential pseudorandom
+///       number generators"](https://doi.org/10.1002/spe.3030). *Software:
+///       Practice and Experience* 52(2), pp. 443-458.
+///
+/// [`new`]: Mcg128::new
+/// [`with_multiplier`]: Mcg128::with_multiplier
+/// [`MULTIPLIER_64`]: Mcg128::MULTIPLIER_64
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Mcg128State"))]
+pub struct Mcg128 {
+    state: u128,
+    multiplier: u128,
+}
+
+// The fields of a deserialized `Mcg128`, before checking them
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct Mcg128State {
+    state: u128,
+    multiplier: u128,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<Mcg128State> for Mcg128 {
+    type Error = &'static str;
+
+    fn try_from(Mcg128State { state, multiplier }: Mcg128State) -> Result<Self, Self::Error> {
+        if state % 2 == 0 {
+            return Err("state must be odd");
+        }
+        if !valid_multiplier(multiplier) {
+            return Err(INVALID_MULTIPLIER);
+        }
+        Ok(Mcg128 { state, multiplier })
+    }
+}
+
+const INVALID_MULTIPLIER: &str = "multiplier must be 3 or 5 modulo 8";
+
+fn valid_multiplier(multiplier: u128) -> bool {
+    multiplier % 8 == 3 || multiplier % 8 == 5
+}
+
+impl Mcg128 {
+    /// The 64-bit multiplier `0xda942042e4dd58b5`, of Lemire's `lehmer64` and
+    /// of NumPy's `PCG64DXSM`. It is the fastest of these multipliers.
+    pub const MULTIPLIER_64: u128 = 0xda942042e4dd58b5;
+
+    /// The 65-bit multiplier `2^64 + 0xd605bbb58c8abbfd` by Steele and
+    /// Vigna, of the 128-bit LCG of Java 17's LXM generators.
+    pub const MULTIPLIER_65: u128 = (1 << 64) | 0xd605bbb58c8abbfd;
+
+    /// The full 128-bit multiplier of the PCG generators with 128 bits of
+    /// state, `0x2360ed051fc65da44385df649fccf645`.
+    pub const MULTIPLIER_128: u128 = 0x2360ed051fc65da44385df649fccf645;
+
+    /// Create a generator with the state `state | 1` and the multiplier
+    /// [`MULTIPLIER_64`](Self::MULTIPLIER_64).
+    pub fn new(state: u128) -> Self {
+        Mcg128::with_multiplier(state, Self::MULTIPLIER_64)
+    }
+
+    /// Create a generator with the state `state | 1` and the given
+    /// multiplier.
+    ///
+    /// # Panics
+    ///
+    /// If `multiplier` is not 3 or 5 modulo 8, as the period would be
+    /// shorter than 2^126.
+    pub fn with_multiplier(state: u128, multiplier: u128) -> Self {
+        assert!(valid_multiplier(multiplier), "{}", INVALID_MULTIPLIER);
+        Mcg128 {
+            state: state | 1,
+            multiplier,
+        }
+    }
+
+    /// Jump forward `delta` steps in O(log `delta`) time, equivalently to
+    /// `delta` calls to `next_u64()`.
+    ///
+    /// As the period is 2^126, this goes backwards by `2^126 - delta` steps.
+    pub fn advance(&mut self, delta: u128) {
+        let (mult, _) = lcg128_jump(self.multiplier, 0, delta);
+        self.state = self.state.wrapping_mul(mult);
+    }
+}
+
+impl SeedableRng for Mcg128 {
+    type Seed = [u8; 16];
+
+    /// Create a new `Mcg128`, using the seed as a little-endian state. The
+    /// lowest bit of the state is set, as it must be odd.
+    fn from_seed(seed: [u8; 16]) -> Mcg128 {
+        Mcg128::new(u128::from_le_bytes(seed))
+    }
+
+    /// Seed a `Mcg128` from a `u64` using `SplitMix64`.
+    fn seed_from_u64(seed: u64) -> Mcg128 {
+        from_splitmix!(seed)
+    }
+}
+
+impl RngCore for Mcg128 {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        // The upper bits have the best statistical quality.
+        (self.next_u64() >> 32) as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_mul(self.multiplier);
+        (self.state >> 64) as u64
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        utils::fill_bytes_via_next_word(dest, || self.next_u64());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STATE: u128 = 0x0123456789abcdef_fedcba9876543211;
+
+    #[test]
+    fn reference() {
+        let mut rng = Mcg128::new(STATE);
+        // These values were produced with Lemire's `lehmer64`.
+        let expected = [
+            8402288084708883056,
+            16567443607601907502,
+            17461985860074653223,
+            15862343962365696019,
+            14709581811086914210,
+            16272291711644381069,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn odd_state() {
+        assert_eq!(Mcg128::new(STATE - 1), Mcg128::new(STATE));
+        let mut seed = STATE.to_le_bytes();
+        seed[0] &= !1;
+        assert_eq!(Mcg128::from_seed(seed), Mcg128::new(STATE));
+    }
+
+    #[test]
+    fn advance() {
+        for multiplier in [
+            Mcg128::MULTIPLIER_64,
+            Mcg128::MULTIPLIER_65,
+            Mcg128::MULTIPLIER_128,
+        ] {
+            let mut rng = Mcg128::with_multiplier(STATE, multiplier);
+            let mut skipped = rng.clone();
+            for _ in 0..1000 {
+                rng.next_u64();
+            }
+            skipped.advance(1000);
+            assert_eq!(rng, skipped);
+
+            // The period is 2^126.
+            skipped.advance((1 << 126) - 1000);
+            assert_eq!(skipped, Mcg128::with_multiplier(STATE, multiplier));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_multiplier() {
+        Mcg128::with_multiplier(STATE, 0xda942042e4dd58b1);
+    }
+}
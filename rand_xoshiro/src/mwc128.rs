@@ -0,0 +1,174 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::common::{mwc_carry, mwc_jump};
+use rand_core::{RngCore, SeedableRng, utils};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const MWC_A1: u64 = 0xffebb71d94fcdaf9;
+
+/// A MWC128 random number generator.
+///
+/// MWC128 is a multiply-with-carry generator with 64 bits of lag and a 64-bit
+/// carry, which is equivalent to a multiplicative congruential generator with
+/// a prime modulus close to 2^128. The multiplier is chosen so that the most
+/// significant bits pass all statistical tests, and the output is used
+/// without any scrambling. It is not suitable for cryptographic purposes.
+///
+/// The algorithm used here is translated from [the `MWC128.c` reference source
+/// code](https://prng.di.unimi.it/MWC128.c) by Sebastiano Vigna.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Mwc128 {
+    x: u64,
+    c: u64,
+}
+
+impl Mwc128 {
+    /// Jump forward, equivalently to 2^64 calls to `next_u64()`.
+    ///
+    /// This can be used to generate 2^64 non-overlapping subsequences for
+    /// parallel computations.
+    ///
+    /// ```
+    /// use rand_xoshiro::rand_core::SeedableRng;
+    /// use rand_xoshiro::Mwc128;
+    ///
+    /// let rng1 = Mwc128::seed_from_u64(0);
+    /// let mut rng2 = rng1.clone();
+    /// rng2.jump();
+    /// let mut rng3 = rng2.clone();
+    /// rng3.jump();
+    /// ```
+    pub fn jump(&mut self) {
+        self.jump_by(&[0xa72f9a3547208003, 0x2f65fed2e8400983]);
+    }
+
+    /// Jump forward, equivalently to 2^96 calls to `next_u64()`.
+    ///
+    /// This can be used to generate 2^32 starting points, from each of which
+    /// `jump()` will generate 2^32 non-overlapping subsequences for parallel
+    /// distributed computations.
+    pub fn long_jump(&mut self) {
+        self.jump_by(&[0xe6f7814467f3fcdd, 0x394649cfd6769c91]);
+    }
+
+    fn jump_by(&mut self, jump: &[u64; 2]) {
+        let mut state = [self.x, self.c];
+        mwc_jump(&mut state, MWC_A1, jump);
+        [self.x, self.c] = state;
+    }
+}
+
+impl SeedableRng for Mwc128 {
+    /// The lag followed by the carry, as little-endian words.
+    type Seed = [u8; 16];
+
+    /// Create a new `Mwc128`. If `seed` is entirely 0, it will be mapped to a
+    /// different seed; a carry outside of the valid range is mapped into it.
+    #[inline]
+    fn from_seed(seed: [u8; 16]) -> Mwc128 {
+        deal_with_zero_seed!(seed, Self, 16);
+        let [x, c] = utils::read_words(&seed);
+        Mwc128 {
+            x,
+            c: mwc_carry(c, MWC_A1),
+        }
+    }
+
+    /// Seed a `Mwc128` from a `u64` using `SplitMix64`.
+    fn seed_from_u64(seed: u64) -> Mwc128 {
+        from_splitmix!(seed)
+    }
+}
+
+impl RngCore for Mwc128 {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        // The upper bits have the best statistical quality.
+        (self.next_u64() >> 32) as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let result = self.x;
+        let t = u128::from(MWC_A1) * u128::from(self.x) + u128::from(self.c);
+        self.x = t as u64;
+        self.c = (t >> 64) as u64;
+        result
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        utils::fill_bytes_via_next_word(dest, || self.next_u64());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seeded() -> Mwc128 {
+        Mwc128::from_seed([1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0])
+    }
+
+    #[test]
+    fn reference() {
+        let mut rng = seeded();
+        // These values were produced with the reference implementation:
+        // https://prng.di.unimi.it/MWC128.c
+        let expected = [
+            1,
+            18441034436880161531,
+            12397388967387773987,
+            8117649586375974639,
+            14645376333838546729,
+            4192571890408722461,
+            10083023984062136694,
+            14217741928138107059,
+            11111442864307152614,
+            7100173186866121831,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn jump() {
+        let mut rng = seeded();
+        rng.jump();
+        for &e in &[433220090598623863, 569145667370621557, 858732422352827673] {
+            assert_eq!(rng.next_u64(), e);
+        }
+
+        let mut rng = seeded();
+        rng.long_jump();
+        for &e in &[
+            6452859147139782926,
+            12662515547532933953,
+            11687422670003180346,
+        ] {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn invalid_seed() {
+        assert_eq!(Mwc128::from_seed([0; 16]), Mwc128::seed_from_u64(0));
+
+        let mut seed = [0; 16];
+        seed[0] = 1;
+        let rng = Mwc128::from_seed(seed);
+        assert!(rng.c != 0 && rng.c < MWC_A1 - 1);
+        seed[8..].copy_from_slice(&(MWC_A1 - 1).to_le_bytes());
+        let rng = Mwc128::from_seed(seed);
+        assert!(rng.c != 0 && rng.c < MWC_A1 - 1);
+    }
+}
@@ -0,0 +1,181 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::common::{mwc_carry, mwc_jump};
+use rand_core::{RngCore, SeedableRng, utils};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const MWC_A2: u64 = 0xffa04e67b3c95d86;
+
+/// A MWC192 random number generator.
+///
+/// MWC192 is a multiply-with-carry generator with 128 bits of lag and a
+/// 64-bit carry. Its period is about 2^191 and, like [`Mwc128`], it outputs
+/// the state directly; the larger lag gives it better statistical quality at
+/// a small cost in speed. It is not suitable for cryptographic purposes.
+///
+/// The algorithm used here is translated from [the `MWC192.c` reference source
+/// code](https://prng.di.unimi.it/MWC192.c) by Sebastiano Vigna.
+///
+/// [`Mwc128`]: crate::Mwc128
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Mwc192 {
+    x: u64,
+    y: u64,
+    c: u64,
+}
+
+impl Mwc192 {
+    /// Jump forward, equivalently to 2^96 calls to `next_u64()`.
+    ///
+    /// This can be used to generate 2^96 non-overlapping subsequences for
+    /// parallel computations.
+    ///
+    /// ```
+    /// use rand_xoshiro::rand_core::SeedableRng;
+    /// use rand_xoshiro::Mwc192;
+    ///
+    /// let rng1 = Mwc192::seed_from_u64(0);
+    /// let mut rng2 = rng1.clone();
+    /// rng2.jump();
+    /// let mut rng3 = rng2.clone();
+    /// rng3.jump();
+    /// ```
+    pub fn jump(&mut self) {
+        self.jump_by(&[0xd94fb8d87c7c6437, 0xafc217e3b9edf985, 0xdc2be36e4bd21a2]);
+    }
+
+    /// Jump forward, equivalently to 2^144 calls to `next_u64()`.
+    ///
+    /// This can be used to generate 2^48 starting points, from each of which
+    /// `jump()` will generate 2^48 non-overlapping subsequences for parallel
+    /// distributed computations.
+    pub fn long_jump(&mut self) {
+        self.jump_by(&[0xd0e7cedd16a0758e, 0xec956c3909137b2d, 0x3c6528aaead6bbdd]);
+    }
+
+    fn jump_by(&mut self, jump: &[u64; 3]) {
+        let mut state = [self.x, self.y, self.c];
+        mwc_jump(&mut state, MWC_A2, jump);
+        [self.x, self.y, self.c] = state;
+    }
+}
+
+impl SeedableRng for Mwc192 {
+    /// The two lag words, oldest first, followed by the carry, as
+    /// little-endian words.
+    type Seed = [u8; 24];
+
+    /// Create a new `Mwc192`. If `seed` is entirely 0, it will be mapped to a
+    /// different seed; a carry outside of the valid range is mapped into it.
+    #[inline]
+    fn from_seed(seed: [u8; 24]) -> Mwc192 {
+        deal_with_zero_seed!(seed, Self, 24);
+        let [x, y, c] = utils::read_words(&seed);
+        Mwc192 {
+            x,
+            y,
+            c: mwc_carry(c, MWC_A2),
+        }
+    }
+
+    /// Seed a `Mwc192` from a `u64` using `SplitMix64`.
+    fn seed_from_u64(seed: u64) -> Mwc192 {
+        from_splitmix!(seed)
+    }
+}
+
+impl RngCore for Mwc192 {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        // The upper bits have the best statistical quality.
+        (self.next_u64() >> 32) as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let result = self.y;
+        let t = u128::from(MWC_A2) * u128::from(self.x) + u128::from(self.c);
+        self.x = self.y;
+        self.y = t as u64;
+        self.c = (t >> 64) as u64;
+        result
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        utils::fill_bytes_via_next_word(dest, || self.next_u64());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seeded() -> Mwc192 {
+        Mwc192::from_seed([
+            1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0,
+        ])
+    }
+
+    #[test]
+    fn reference() {
+        let mut rng = seeded();
+        // These values were produced with the reference implementation:
+        // https://prng.di.unimi.it/MWC192.c
+        let expected = [
+            2,
+            18419808683250245001,
+            18392873292790938380,
+            13378800554804951735,
+            8418637927998587873,
+            518648260616149106,
+            10330308065885293119,
+            4288998360266875595,
+            8322887768620187050,
+            6498798026578505045,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn jump() {
+        let mut rng = seeded();
+        rng.jump();
+        for &e in &[
+            10068140502048479891,
+            10957621764292718877,
+            9185986434410918877,
+        ] {
+            assert_eq!(rng.next_u64(), e);
+        }
+
+        let mut rng = seeded();
+        rng.long_jump();
+        for &e in &[4888753733582924656, 238001347705647416, 7158171574867653038] {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn invalid_seed() {
+        assert_eq!(Mwc192::from_seed([0; 24]), Mwc192::seed_from_u64(0));
+
+        let mut seed = [0; 24];
+        seed[0] = 1;
+        let rng = Mwc192::from_seed(seed);
+        assert!(rng.c != 0 && rng.c < MWC_A2 - 1);
+        seed[16..].copy_from_slice(&(MWC_A2 - 1).to_le_bytes());
+        let rng = Mwc192::from_seed(seed);
+        assert!(rng.c != 0 && rng.c < MWC_A2 - 1);
+    }
+}
@@ -0,0 +1,200 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::common::{mwc_carry, mwc_jump};
+use rand_core::{RngCore, SeedableRng, utils};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const MWC_A3: u64 = 0xfff62cf2ccc0cdaf;
+
+/// A MWC256 random number generator.
+///
+/// MWC256 is a multiply-with-carry generator with 192 bits of lag and a
+/// 64-bit carry, giving a period of about 2^255. Its state is large enough
+/// for massively parallel applications; otherwise [`Mwc128`] or [`Mwc192`]
+/// are faster. It is not suitable for cryptographic purposes.
+///
+/// The algorithm used here is translated from [the `MWC256.c` reference source
+/// code](https://prng.di.unimi.it/MWC256.c) by Sebastiano Vigna.
+///
+/// [`Mwc128`]: crate::Mwc128
+/// [`Mwc192`]: crate::Mwc192
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Mwc256 {
+    x: u64,
+    y: u64,
+    z: u64,
+    c: u64,
+}
+
+impl Mwc256 {
+    /// Jump forward, equivalently to 2^128 calls to `next_u64()`.
+    ///
+    /// This can be used to generate 2^128 non-overlapping subsequences for
+    /// parallel computations.
+    ///
+    /// ```
+    /// use rand_xoshiro::rand_core::SeedableRng;
+    /// use rand_xoshiro::Mwc256;
+    ///
+    /// let rng1 = Mwc256::seed_from_u64(0);
+    /// let mut rng2 = rng1.clone();
+    /// rng2.jump();
+    /// let mut rng3 = rng2.clone();
+    /// rng3.jump();
+    /// ```
+    pub fn jump(&mut self) {
+        self.jump_by(&[
+            0x28c3ff11313847eb,
+            0xfe88c291203b2254,
+            0xf6f8c3fd02ec98fb,
+            0x4b89aa2cd51c37b9,
+        ]);
+    }
+
+    /// Jump forward, equivalently to 2^192 calls to `next_u64()`.
+    ///
+    /// This can be used to generate 2^64 starting points, from each of which
+    /// `jump()` will generate 2^64 non-overlapping subsequences for parallel
+    /// distributed computations.
+    pub fn long_jump(&mut self) {
+        self.jump_by(&[
+            0x64c6e39cf92f77a4,
+            0xf95382f758ac9877,
+            0x6c40ce860e0d702,
+            0xaf5ca22408cdc83,
+        ]);
+    }
+
+    fn jump_by(&mut self, jump: &[u64; 4]) {
+        let mut state = [self.x, self.y, self.z, self.c];
+        mwc_jump(&mut state, MWC_A3, jump);
+        [self.x, self.y, self.z, self.c] = state;
+    }
+}
+
+impl SeedableRng for Mwc256 {
+    /// The three lag words, oldest first, followed by the carry, as
+    /// little-endian words.
+    type Seed = [u8; 32];
+
+    /// Create a new `Mwc256`. If `seed` is entirely 0, it will be mapped to a
+    /// different seed; a carry outside of the valid range is mapped into it.
+    #[inline]
+    fn from_seed(seed: [u8; 32]) -> Mwc256 {
+        deal_with_zero_seed!(seed, Self);
+        let [x, y, z, c] = utils::read_words(&seed);
+        Mwc256 {
+            x,
+            y,
+            z,
+            c: mwc_carry(c, MWC_A3),
+        }
+    }
+
+    /// Seed a `Mwc256` from a `u64` using `SplitMix64`.
+    fn seed_from_u64(seed: u64) -> Mwc256 {
+        from_splitmix!(seed)
+    }
+}
+
+impl RngCore for Mwc256 {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        // The upper bits have the best statistical quality.
+        (self.next_u64() >> 32) as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let result = self.z;
+        let t = u128::from(MWC_A3) * u128::from(self.x) + u128::from(self.c);
+        self.x = self.y;
+        self.y = self.z;
+        self.z = t as u64;
+        self.c = (t >> 64) as u64;
+        result
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        utils::fill_bytes_via_next_word(dest, || self.next_u64());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seeded() -> Mwc256 {
+        Mwc256::from_seed([
+            1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0,
+            0, 0, 0,
+        ])
+    }
+
+    #[test]
+    fn reference() {
+        let mut rng = seeded();
+        // These values were produced with the reference implementation:
+        // https://prng.di.unimi.it/MWC256.c
+        let expected = [
+            3,
+            18443978745271340467,
+            18441213416833129310,
+            18438448088394918158,
+            17665813377173484639,
+            16901475065813627965,
+            16126075440700926675,
+            6041626105387642748,
+            12638286674594123081,
+            16174826182579161028,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn jump() {
+        let mut rng = seeded();
+        rng.jump();
+        for &e in &[
+            11279414720790708000,
+            1000286153344567552,
+            7688499931098284511,
+        ] {
+            assert_eq!(rng.next_u64(), e);
+        }
+
+        let mut rng = seeded();
+        rng.long_jump();
+        for &e in &[
+            6023728314042984587,
+            10890345046168784795,
+            11546838634414908162,
+        ] {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn invalid_seed() {
+        assert_eq!(Mwc256::from_seed([0; 32]), Mwc256::seed_from_u64(0));
+
+        let mut seed = [0; 32];
+        seed[0] = 1;
+        let rng = Mwc256::from_seed(seed);
+        assert!(rng.c != 0 && rng.c < MWC_A3 - 1);
+        seed[24..].copy_from_slice(&(MWC_A3 - 1).to_le_bytes());
+        let rng = Mwc256::from_seed(seed);
+        assert!(rng.c != 0 && rng.c < MWC_A3 - 1);
+    }
+}
@@ -0,0 +1,82 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Ports of the seeding and mixing helpers in the JDK's
+//! `jdk.internal.util.random.RandomSupport`, which the Java-compatible
+//! generators need to reproduce the JDK bit for bit.
+
+/// The golden ratio scaled to 64 bits, used as a SplitMix increment.
+pub(crate) const GOLDEN_RATIO_64: u64 = 0x9e3779b97f4a7c15;
+/// The silver ratio scaled to 64 bits, used as a SplitMix increment.
+pub(crate) const SILVER_RATIO_64: u64 = 0x6a09e667f3bcc909;
+
+/// Doug Lea's 64-bit mixing function, used as the LXM output function.
+#[inline]
+pub(crate) fn mix_lea64(mut z: u64) -> u64 {
+    z = (z ^ (z >> 32)).wrapping_mul(0xdaba0b6eb09322e3);
+    z = (z ^ (z >> 32)).wrapping_mul(0xdaba0b6eb09322e3);
+    z ^ (z >> 32)
+}
+
+/// David Stafford's "Mix13" variant of the MurmurHash3 finalizer.
+#[inline]
+pub(crate) fn mix_stafford13(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+/// The 64-bit finalizer of MurmurHash3.
+#[inline]
+pub(crate) fn mix_murmur64(mut z: u64) -> u64 {
+    z = (z ^ (z >> 33)).wrapping_mul(0xff51afd7ed558ccd);
+    z = (z ^ (z >> 33)).wrapping_mul(0xc4ceb9fe1a85ec53);
+    z ^ (z >> 33)
+}
+
+/// Convert seed bytes to `N` words such that the last `z` are not all zero,
+/// like `RandomSupport.convertSeedBytesToLongs`.
+///
+/// Bytes are consumed big-endian. Like the JDK, each byte is sign-extended
+/// before it is or-ed into its word, so a byte `>= 0x80` sets all of the bits
+/// above it. Missing words are derived from the first word.
+pub(crate) fn convert_seed_bytes<const N: usize>(seed: &[u8], z: usize) -> [u64; N] {
+    let mut result = [0u64; N];
+    let m = seed.len().min(N * 8);
+    for (j, &b) in seed[..m].iter().enumerate() {
+        result[j >> 3] = (result[j >> 3] << 8) | (b as i8 as u64);
+    }
+
+    let mut v = result[0];
+    for word in &mut result[m.div_ceil(8)..] {
+        v = v.wrapping_add(SILVER_RATIO_64);
+        *word = mix_murmur64(v);
+    }
+
+    if result[N - z..].iter().all(|&w| w == 0) {
+        // Clearing the lowest bit guarantees that the first derived word is
+        // non-zero.
+        let mut w = result[0] & !1;
+        for word in &mut result[N - z..] {
+            w = w.wrapping_add(SILVER_RATIO_64);
+            *word = mix_murmur64(w);
+        }
+    }
+    result
+}
+
+/// Derive the xorshift part of the state of an LXM generator from `v` if it is
+/// all zero, like the JDK's LXM constructors.
+pub(crate) fn fix_zero_lxm_state<const N: usize>(x: &mut [u64; N], mut v: u64) {
+    if x.iter().all(|&w| w == 0) {
+        for w in x {
+            v = v.wrapping_add(GOLDEN_RATIO_64);
+            *w = mix_stafford13(v);
+        }
+    }
+}
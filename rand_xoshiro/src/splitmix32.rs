@@ -0,0 +1,106 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use rand_core::{RngCore, SeedableRng, utils};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const PHI: u32 = 0x9e3779b9;
+
+/// A splitmix32 random number generator.
+///
+/// The 32-bit counterpart of [`SplitMix64`]: a Weyl sequence with the golden
+/// ratio as increment, mixed with the 32-bit finalizer of Austin Appleby's
+/// MurmurHash3. It only uses 32-bit arithmetic, which makes it a cheap way to
+/// initialize the 32-bit xoshiro generators on 32-bit processors:
+///
+/// ```
+/// use rand_xoshiro::rand_core::SeedableRng;
+/// use rand_xoshiro::{SplitMix32, Xoshiro128PlusPlus};
+///
+/// let mut seeder = SplitMix32::new(12345);
+/// let rng = Xoshiro128PlusPlus::from_rng(&mut seeder);
+/// ```
+///
+/// Its state is only 32 bits, so it should not be used as a general purpose
+/// generator. It is not suitable for cryptographic purposes.
+///
+/// [`SplitMix64`]: crate::SplitMix64
+#[allow(missing_copy_implementations)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SplitMix32 {
+    x: u32,
+}
+
+impl SplitMix32 {
+    /// Create a new `SplitMix32` with the given state.
+    pub fn new(seed: u32) -> SplitMix32 {
+        SplitMix32 { x: seed }
+    }
+}
+
+impl RngCore for SplitMix32 {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.x = self.x.wrapping_add(PHI);
+        let mut z = self.x;
+        z = (z ^ (z >> 16)).wrapping_mul(0x85ebca6b);
+        z = (z ^ (z >> 13)).wrapping_mul(0xc2b2ae35);
+        z ^ (z >> 16)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        utils::next_u64_via_u32(self)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        utils::fill_bytes_via_next_word(dest, || self.next_u32());
+    }
+}
+
+impl SeedableRng for SplitMix32 {
+    type Seed = [u8; 4];
+
+    /// Create a new `SplitMix32`.
+    fn from_seed(seed: [u8; 4]) -> SplitMix32 {
+        SplitMix32::new(u32::from_le_bytes(seed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference() {
+        let mut rng = SplitMix32::new(0);
+        // There is no reference implementation of splitmix32. These values
+        // were produced with a C program that adds 0x9e3779b9 to the state
+        // and applies `fmix32` of the MurmurHash3 reference implementation:
+        // https://github.com/aappleby/smhasher/blob/master/src/MurmurHash3.cpp
+        let expected = [
+            2462723854, 1020716019, 454327756, 1275600319, 1215922603, 3678440605, 2025593743,
+            3627053797,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u32(), e);
+        }
+
+        let mut rng = SplitMix32::from_seed(0xdeadbeef_u32.to_le_bytes());
+        let expected = [
+            3553530007, 1645313261, 4073195585, 2760393073, 3082008752, 3187377903, 3083087473,
+            3593729586,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u32(), e);
+        }
+    }
+}
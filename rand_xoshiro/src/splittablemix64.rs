@@ -0,0 +1,192 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::random_support::{GOLDEN_RATIO_64, mix_stafford13};
+use rand_core::{RngCore, SeedableRng, utils};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Derive the gamma of a split-off generator, like `SplittableRandom.mixGamma`.
+///
+/// The result is odd and has at least 24 bit transitions, so that the
+/// increments of the Weyl sequence are never too regular.
+fn mix_gamma(mut z: u64) -> u64 {
+    z = (z ^ (z >> 33)).wrapping_mul(0xff51afd7ed558ccd);
+    z = (z ^ (z >> 33)).wrapping_mul(0xc4ceb9fe1a85ec53);
+    z = (z ^ (z >> 33)) | 1;
+    if (z ^ (z >> 1)).count_ones() < 24 {
+        z ^ 0xaaaaaaaaaaaaaaaa
+    } else {
+        z
+    }
+}
+
+/// A splittable splitmix64 random number generator.
+///
+/// Unlike [`SplitMix64`], which always increments its state by the golden
+/// ratio, each `SplittableMix64` has its own odd increment (gamma).
+/// [`split`](Self::split) derives a new seed and gamma from the generator,
+/// giving a new generator whose output is statistically independent of its
+/// parent's. It is not suitable for cryptographic purposes.
+///
+/// The implementation reproduces `java.util.SplittableRandom` bit for bit:
+/// `next_u64` and `next_u32` are its `nextLong` and `nextInt`, and `split` is
+/// its `split()`.
+///
+/// [`SplitMix64`]: crate::SplitMix64
+#[allow(missing_copy_implementations)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SplittableMix64 {
+    seed: u64,
+    gamma: u64,
+}
+
+impl SplittableMix64 {
+    /// Create a generator like `new SplittableRandom(seed)`.
+    pub fn new(seed: u64) -> SplittableMix64 {
+        SplittableMix64 {
+            seed,
+            gamma: GOLDEN_RATIO_64,
+        }
+    }
+
+    /// Split off a new generator, like `SplittableRandom.split()`.
+    ///
+    /// ```
+    /// use rand_xoshiro::rand_core::RngCore;
+    /// use rand_xoshiro::SplittableMix64;
+    ///
+    /// let mut rng1 = SplittableMix64::new(0);
+    /// let mut rng2 = rng1.split();
+    /// assert_ne!(rng1.next_u64(), rng2.next_u64());
+    /// ```
+    pub fn split(&mut self) -> SplittableMix64 {
+        let seed = self.next_u64();
+        let gamma = mix_gamma(self.next_seed());
+        SplittableMix64 { seed, gamma }
+    }
+
+    #[inline]
+    fn next_seed(&mut self) -> u64 {
+        self.seed = self.seed.wrapping_add(self.gamma);
+        self.seed
+    }
+}
+
+impl RngCore for SplittableMix64 {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        // The same mixing function as `SplitMix64::next_u32`.
+        let mut z = self.next_seed();
+        z = (z ^ (z >> 33)).wrapping_mul(0x62a9d9ed799705f5);
+        z = (z ^ (z >> 28)).wrapping_mul(0xcb24d0a5c88c35b3);
+        (z >> 32) as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        mix_stafford13(self.next_seed())
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        utils::fill_bytes_via_next_word(dest, || self.next_u64());
+    }
+}
+
+impl SeedableRng for SplittableMix64 {
+    type Seed = [u8; 8];
+
+    /// Create a new `SplittableMix64`, like [`SplittableMix64::new`] with the
+    /// little-endian `seed`.
+    fn from_seed(seed: [u8; 8]) -> SplittableMix64 {
+        SplittableMix64::new(u64::from_le_bytes(seed))
+    }
+
+    /// Seed a `SplittableMix64` from a `u64`, like [`SplittableMix64::new`].
+    fn seed_from_u64(seed: u64) -> SplittableMix64 {
+        SplittableMix64::new(seed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SplitMix64;
+
+    // These values were produced with OpenJDK 17's `java.util.SplittableRandom`.
+
+    #[test]
+    fn reference() {
+        let mut rng = SplittableMix64::new(12345);
+        let expected = [
+            2454886589211414944,
+            3778200017661327597,
+            2205171434679333405,
+            3248800117070709450,
+            9350289611492784363,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+
+        let mut rng = SplittableMix64::new(42);
+        for &e in &[3803690062, 909395113, 2417644962, 3270406344, 2753973563] {
+            assert_eq!(rng.next_u32(), e);
+        }
+
+        let mut rng = SplittableMix64::new(7);
+        let mut bytes = [0; 13];
+        rng.fill_bytes(&mut bytes);
+        assert_eq!(
+            bytes,
+            [215, 13, 50, 89, 228, 225, 203, 99, 28, 102, 60, 244, 215]
+        );
+    }
+
+    #[test]
+    fn split() {
+        let mut parent = SplittableMix64::new(42);
+        let mut child = parent.split();
+        let mut grandchild = child.split();
+        let expected = [
+            1172002037136309321,
+            11491879662333683734,
+            17016127901299674051,
+            12338419692931607833,
+            8703639483262054160,
+        ];
+        for &e in &expected {
+            assert_eq!(child.next_u64(), e);
+        }
+        let expected = [
+            15905106113998148183,
+            8380476641419707743,
+            11125570449564665336,
+            2340141244154980316,
+            11839198697257194951,
+        ];
+        for &e in &expected {
+            assert_eq!(grandchild.next_u64(), e);
+        }
+        for &e in &[5139283748462763858, 6349198060258255764, 701532786141963250] {
+            assert_eq!(parent.next_u64(), e);
+        }
+    }
+
+    #[test]
+    fn matches_splitmix64() {
+        let mut rng1 = SplittableMix64::new(10);
+        let mut rng2 = SplitMix64::seed_from_u64(10);
+        for _ in 0..16 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+            assert_eq!(rng1.next_u32(), rng2.next_u32());
+        }
+    }
+}
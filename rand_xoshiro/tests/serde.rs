@@ -2,9 +2,10 @@
 
 use rand_core::{RngCore, SeedableRng};
 use rand_xoshiro::{
-    SplitMix64, Xoroshiro64Star, Xoroshiro64StarStar, Xoroshiro128Plus, Xoroshiro128StarStar,
-    Xoshiro128Plus, Xoshiro128StarStar, Xoshiro256Plus, Xoshiro256StarStar, Xoshiro512Plus,
-    Xoshiro512StarStar,
+    L64X128Mix, L64X256Mix, L128X256Mix, Lcg128, Mcg128, Mwc128, Mwc192, Mwc256, SplitMix32,
+    SplitMix64, SplittableMix64, WyRand, Xoroshiro64Star, Xoroshiro64StarStar, Xoroshiro128Plus,
+    Xoroshiro128StarStar, Xoshiro128Plus, Xoshiro128StarStar, Xoshiro256Plus, Xoshiro256StarStar,
+    Xoshiro512Plus, Xoshiro512StarStar,
 };
 
 macro_rules! serde_rng {
@@ -26,6 +27,63 @@ fn test_splitmix64() {
     serde_rng!(SplitMix64);
 }
 
+#[test]
+fn test_mcg128() {
+    serde_rng!(Mcg128);
+}
+
+#[test]
+fn test_mcg128_invalid() {
+    #[derive(serde::Serialize)]
+    struct Raw {
+        state: u128,
+        multiplier: u128,
+    }
+    let check = |state, multiplier| {
+        let buf = postcard::to_allocvec(&Raw { state, multiplier }).unwrap();
+        postcard::from_bytes::<Mcg128>(&buf).is_ok()
+    };
+    assert!(check(1, Mcg128::MULTIPLIER_64));
+    // An even state, in particular zero, would get stuck.
+    assert!(!check(0, Mcg128::MULTIPLIER_64));
+    assert!(!check(2, Mcg128::MULTIPLIER_64));
+    assert!(!check(1, 0));
+    assert!(!check(1, Mcg128::MULTIPLIER_64 - 4));
+}
+
+#[test]
+fn test_lcg128() {
+    serde_rng!(Lcg128);
+}
+
+#[test]
+fn test_lcg128_invalid() {
+    #[derive(serde::Serialize)]
+    struct Raw {
+        state: u128,
+        multiplier: u128,
+        increment: u128,
+    }
+    let check = |multiplier, increment| {
+        let buf = postcard::to_allocvec(&Raw {
+            state: 0,
+            multiplier,
+            increment,
+        })
+        .unwrap();
+        postcard::from_bytes::<Lcg128>(&buf).is_ok()
+    };
+    assert!(check(Lcg128::MULTIPLIER_64, 1));
+    assert!(!check(Lcg128::MULTIPLIER_64, 2));
+    assert!(!check(0, 1));
+    assert!(!check(Lcg128::MULTIPLIER_64 + 2, 1));
+}
+
+#[test]
+fn test_wyrand() {
+    serde_rng!(WyRand);
+}
+
 #[test]
 fn test_xoroshiro64starstar() {
     serde_rng!(Xoroshiro64StarStar);
@@ -75,3 +133,43 @@ fn test_xoshiro512starstar() {
 fn test_xoshiro512plus() {
     serde_rng!(Xoshiro512Plus);
 }
+
+#[test]
+fn test_mwc128() {
+    serde_rng!(Mwc128);
+}
+
+#[test]
+fn test_mwc192() {
+    serde_rng!(Mwc192);
+}
+
+#[test]
+fn test_mwc256() {
+    serde_rng!(Mwc256);
+}
+
+#[test]
+fn test_l64x128mix() {
+    serde_rng!(L64X128Mix);
+}
+
+#[test]
+fn test_l64x256mix() {
+    serde_rng!(L64X256Mix);
+}
+
+#[test]
+fn test_l128x256mix() {
+    serde_rng!(L128X256Mix);
+}
+
+#[test]
+fn test_splitmix32() {
+    serde_rng!(SplitMix32);
+}
+
+#[test]
+fn test_splittablemix64() {
+    serde_rng!(SplittableMix64);
+}